@@ -0,0 +1,629 @@
+//! Architecture Decision Record collection validation.
+//!
+//! Checks a directory of numbered ADRs as a whole: numbering has no
+//! duplicates or gaps, supersede links point at ADRs that exist and are
+//! reciprocated, and each record's status is one of the allowed values and
+//! agrees with its links.
+//!
+//! Status is read from a frontmatter `status` key, a `Status:` line, or the
+//! first word under a `## Status` heading. Links are read from frontmatter
+//! `supersedes` / `superseded_by` keys and from body lines mentioning
+//! "Supersedes" or "Superseded by"; targets are recognized as markdown links
+//! to numbered files or as `ADR-N` references.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::LazyLock;
+
+use camino::Utf8Path;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::config::AdrConfig;
+use crate::error::{AnalysisError, AnalysisResult};
+use crate::markdown;
+use crate::scaffold;
+
+/// Statuses allowed when the config does not list its own.
+pub const DEFAULT_STATUSES: &[&str] = &[
+    "proposed",
+    "accepted",
+    "rejected",
+    "deprecated",
+    "superseded",
+];
+
+static LINK_TARGET: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\]\(([^)\s]+)").expect("valid link pattern"));
+
+static ADR_REF: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\badr[-_ ]?(\d+)\b").expect("valid ADR reference pattern"));
+
+static BARE_NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*#?(\d+)\b").expect("valid number pattern"));
+
+static STATUS_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\s*[-*_]*\s*[*_]*status[*_]*\s*:[*_\s]*(.+)$").expect("valid status pattern")
+});
+
+static SUPERSEDE_MENTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(supersedes|superseded[ _-]by)\b[*_:\s]*(.*)$")
+        .expect("valid supersede pattern")
+});
+
+/// Result of validating an ADR directory.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdrReport {
+    /// Directory that was checked.
+    pub dir: String,
+    /// Number of ADRs found.
+    pub count: usize,
+    /// Per-file results, in file name order.
+    pub files: Vec<AdrFileReport>,
+    /// Whether every ADR passed.
+    pub pass: bool,
+}
+
+/// Validation result for a single ADR.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdrFileReport {
+    /// File name within the directory.
+    pub file: String,
+    /// ADR number taken from the file name.
+    pub number: u32,
+    /// Normalized (lowercase) status, if one was found.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// ADR numbers this record supersedes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub supersedes: Vec<u32>,
+    /// ADR numbers this record is superseded by.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub superseded_by: Vec<u32>,
+    /// Problems found.
+    pub issues: Vec<AdrIssue>,
+}
+
+/// A single ADR collection problem.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AdrIssue {
+    /// Kind of problem.
+    pub kind: AdrIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kinds of ADR collection problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AdrIssueKind {
+    /// Duplicate number or gap in the sequence.
+    Numbering,
+    /// A supersede link points at an ADR that does not exist.
+    BrokenLink,
+    /// A supersede link is not matched by the opposite link.
+    NotReciprocal,
+    /// Status is missing, not allowed, or disagrees with the links.
+    Status,
+}
+
+/// Validate every numbered ADR in a directory.
+///
+/// Only `*.md` files whose names start with a number are considered, so a
+/// `README.md` or template alongside the records is ignored. The directory
+/// is not searched recursively.
+#[tracing::instrument(skip(config), fields(dir = %dir))]
+pub fn check_adr_dir(dir: &Utf8Path, config: &AdrConfig) -> AnalysisResult<AdrReport> {
+    let dir_error = |source| AnalysisError::AdrDirectory {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir.as_std_path()).map_err(dir_error)? {
+        let entry = entry.map_err(dir_error)?;
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        if !name.to_lowercase().ends_with(".md")
+            || scaffold::file_number(&name).is_none()
+            || !entry.path().is_file()
+        {
+            continue;
+        }
+        let content = std::fs::read_to_string(entry.path()).map_err(dir_error)?;
+        files.push((name, content));
+    }
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+
+    Ok(check_adrs(
+        dir.as_str(),
+        &files,
+        config.statuses.as_deref(),
+        config.first_number,
+    ))
+}
+
+/// Validate a collection of ADRs given as `(file name, content)` pairs.
+///
+/// Files without a leading number are skipped. `statuses` overrides
+/// [`DEFAULT_STATUSES`]. `first_number` is the number the sequence must
+/// start at; without it, 0 and 1 are both accepted.
+pub fn check_adrs(
+    dir: &str,
+    files: &[(String, String)],
+    statuses: Option<&[String]>,
+    first_number: Option<u32>,
+) -> AdrReport {
+    let allowed: Vec<String> = statuses.map_or_else(
+        || DEFAULT_STATUSES.iter().map(|s| (*s).to_string()).collect(),
+        |s| s.iter().map(|s| s.to_lowercase()).collect(),
+    );
+
+    let mut reports: Vec<AdrFileReport> = files
+        .iter()
+        .filter_map(|(name, content)| {
+            let number = scaffold::file_number(name)?;
+            let parsed = parse_adr(content);
+            Some(AdrFileReport {
+                file: name.clone(),
+                number,
+                status: parsed.status,
+                supersedes: without(parsed.supersedes, number),
+                superseded_by: without(parsed.superseded_by, number),
+                issues: Vec::new(),
+            })
+        })
+        .collect();
+
+    let width = files
+        .iter()
+        .find_map(|(name, _)| number_width(name))
+        .unwrap_or(4);
+    let id = |n: u32| format!("{n:0width$}");
+
+    let mut by_number: BTreeMap<u32, Vec<usize>> = BTreeMap::new();
+    for (i, report) in reports.iter().enumerate() {
+        by_number.entry(report.number).or_default().push(i);
+    }
+
+    // Numbering: duplicates on every file sharing a number, gaps on the
+    // first file after the gap, a wrong start on the lowest-numbered file.
+    let numbers: Vec<u32> = by_number.keys().copied().collect();
+    for indices in by_number.values().filter(|v| v.len() > 1) {
+        for &i in indices {
+            let others: Vec<&str> = indices
+                .iter()
+                .filter(|&&j| j != i)
+                .map(|&j| reports[j].file.as_str())
+                .collect();
+            let message = format!(
+                "number {} is also used by {}",
+                id(reports[i].number),
+                others.join(", ")
+            );
+            push(&mut reports[i], AdrIssueKind::Numbering, message);
+        }
+    }
+    if let Some(&lowest) = numbers.first() {
+        let expected = first_number.unwrap_or(if lowest == 0 { 0 } else { 1 });
+        if lowest != expected {
+            let message = if lowest < expected {
+                format!(
+                    "numbering starts at {}, expected {}",
+                    id(lowest),
+                    id(expected)
+                )
+            } else if lowest == expected + 1 {
+                format!(
+                    "numbering starts at {}: ADR {} is missing",
+                    id(lowest),
+                    id(expected)
+                )
+            } else {
+                format!(
+                    "numbering starts at {}: ADRs {} to {} are missing",
+                    id(lowest),
+                    id(expected),
+                    id(lowest - 1)
+                )
+            };
+            let i = by_number[&lowest][0];
+            push(&mut reports[i], AdrIssueKind::Numbering, message);
+        }
+    }
+    for pair in numbers.windows(2) {
+        let (prev, next) = (pair[0], pair[1]);
+        if next > prev + 1 {
+            let missing = if next == prev + 2 {
+                format!("ADR {} is missing", id(prev + 1))
+            } else {
+                format!("ADRs {} to {} are missing", id(prev + 1), id(next - 1))
+            };
+            let i = by_number[&next][0];
+            let message = format!("numbering gap after {}: {missing}", id(prev));
+            push(&mut reports[i], AdrIssueKind::Numbering, message);
+        }
+    }
+
+    // Links: targets exist and are reciprocated.
+    for i in 0..reports.len() {
+        let number = reports[i].number;
+        let file = reports[i].file.clone();
+        for (links, label, back_label) in [
+            (reports[i].supersedes.clone(), "supersedes", "Superseded by"),
+            (
+                reports[i].superseded_by.clone(),
+                "is superseded by",
+                "Supersedes",
+            ),
+        ] {
+            let forward = label == "supersedes";
+            for target in links {
+                let Some(target_indices) = by_number.get(&target) else {
+                    let message = format!("{label} ADR {}, which does not exist", id(target));
+                    push(&mut reports[i], AdrIssueKind::BrokenLink, message);
+                    continue;
+                };
+                for &j in target_indices {
+                    let back = if forward {
+                        &reports[j].superseded_by
+                    } else {
+                        &reports[j].supersedes
+                    };
+                    if !back.contains(&number) {
+                        let message = format!(
+                            "{file} says it {label} this ADR, but this ADR has no \
+                             '{back_label}' link back to it"
+                        );
+                        push(&mut reports[j], AdrIssueKind::NotReciprocal, message);
+                    }
+                }
+            }
+        }
+    }
+
+    // Status: present, allowed, and consistent with the links.
+    for report in &mut reports {
+        let message = match report.status.as_deref() {
+            None => Some("no status found".to_string()),
+            Some(status) if !allowed.iter().any(|a| a == status) => Some(format!(
+                "status '{status}' is not one of: {}",
+                allowed.join(", ")
+            )),
+            Some(status) if status != "superseded" && !report.superseded_by.is_empty() => {
+                Some(format!(
+                    "superseded by ADR {} but status is '{status}'",
+                    id(report.superseded_by[0])
+                ))
+            }
+            Some("superseded") if report.superseded_by.is_empty() => {
+                Some("status is 'superseded' but no 'Superseded by' link was found".to_string())
+            }
+            Some(_) => None,
+        };
+        if let Some(message) = message {
+            push(report, AdrIssueKind::Status, message);
+        }
+    }
+
+    AdrReport {
+        dir: dir.to_string(),
+        count: reports.len(),
+        pass: reports.iter().all(|r| r.issues.is_empty()),
+        files: reports,
+    }
+}
+
+fn push(report: &mut AdrFileReport, kind: AdrIssueKind, message: String) {
+    report.issues.push(AdrIssue { kind, message });
+}
+
+fn without(numbers: BTreeSet<u32>, own: u32) -> Vec<u32> {
+    numbers.into_iter().filter(|&n| n != own).collect()
+}
+
+/// Digit count of a file name's leading number (`0007-x.md` → 4).
+fn number_width(file_name: &str) -> Option<usize> {
+    let digits = file_name
+        .trim_start_matches(|c: char| !c.is_ascii_digit())
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .count();
+    (digits > 0).then_some(digits)
+}
+
+/// Status and supersede links extracted from one ADR.
+#[derive(Debug, Default)]
+struct ParsedAdr {
+    status: Option<String>,
+    supersedes: BTreeSet<u32>,
+    superseded_by: BTreeSet<u32>,
+}
+
+fn parse_adr(content: &str) -> ParsedAdr {
+    let mut parsed = ParsedAdr::default();
+
+    if let Ok(Some(frontmatter)) = markdown::parse_frontmatter(content) {
+        for (key, value) in &frontmatter {
+            let key: String = key
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .collect::<String>()
+                .to_lowercase();
+            match key.as_str() {
+                "status" => parsed.status = value.as_str().and_then(normalize_status),
+                "supersedes" => parsed.supersedes.extend(refs_from_value(value)),
+                "supersededby" => parsed.superseded_by.extend(refs_from_value(value)),
+                _ => {}
+            }
+        }
+    }
+
+    let body = markdown::strip_frontmatter(content);
+    let mut in_fence = false;
+    let mut in_status_section = false;
+    let mut section_status = None;
+    let mut line_status = None;
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if trimmed.starts_with('#') {
+            let title = trimmed.trim_start_matches('#').trim();
+            in_status_section = title.eq_ignore_ascii_case("status");
+        } else if in_status_section && !trimmed.is_empty() {
+            if section_status.is_none() {
+                section_status = normalize_status(trimmed);
+            }
+            in_status_section = false;
+        }
+
+        if line_status.is_none()
+            && let Some(caps) = STATUS_LINE.captures(line)
+        {
+            line_status = normalize_status(&caps[1]);
+        }
+
+        if let Some(caps) = SUPERSEDE_MENTION.captures(line) {
+            let refs = refs_from_text(&caps[2]);
+            if caps[1].to_lowercase().starts_with("supersedes") {
+                parsed.supersedes.extend(refs);
+            } else {
+                parsed.superseded_by.extend(refs);
+            }
+        }
+    }
+
+    if parsed.status.is_none() {
+        parsed.status = line_status.or(section_status);
+    }
+    parsed
+}
+
+/// Lowercase first word of a status value (`**Accepted** (2026-03-01)` → `accepted`).
+fn normalize_status(text: &str) -> Option<String> {
+    let word: String = text
+        .trim_start_matches(|c: char| !c.is_alphabetic())
+        .chars()
+        .take_while(|c| c.is_alphabetic())
+        .collect();
+    (!word.is_empty()).then(|| word.to_lowercase())
+}
+
+fn refs_from_value(value: &Value) -> Vec<u32> {
+    match value {
+        Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .into_iter()
+            .collect(),
+        Value::String(s) => refs_from_text(s),
+        Value::Array(items) => items.iter().flat_map(refs_from_value).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// ADR numbers referenced in a fragment of text.
+///
+/// Markdown links to numbered files and `ADR-N` mentions count; a bare
+/// leading number (`7`, `#7`) is used only when neither is present.
+fn refs_from_text(text: &str) -> Vec<u32> {
+    let mut refs: Vec<u32> = LINK_TARGET
+        .captures_iter(text)
+        .filter_map(|caps| {
+            let target = caps[1].split('#').next().unwrap_or_default();
+            let file_name = target.rsplit('/').next().unwrap_or_default();
+            scaffold::file_number(file_name)
+        })
+        .collect();
+    refs.extend(
+        ADR_REF
+            .captures_iter(text)
+            .filter_map(|caps| caps[1].parse::<u32>().ok()),
+    );
+    if refs.is_empty()
+        && let Some(caps) = BARE_NUMBER.captures(text)
+        && let Ok(n) = caps[1].parse()
+    {
+        refs.push(n);
+    }
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adr(name: &str, content: &str) -> (String, String) {
+        (name.to_string(), content.to_string())
+    }
+
+    fn issues_of(report: &AdrReport, file: &str) -> Vec<AdrIssueKind> {
+        report
+            .files
+            .iter()
+            .find(|f| f.file == file)
+            .unwrap()
+            .issues
+            .iter()
+            .map(|i| i.kind)
+            .collect()
+    }
+
+    #[test]
+    fn consistent_collection_passes() {
+        let files = vec![
+            adr(
+                "0001-use-mysql.md",
+                "# 1. Use MySQL\n\n## Status\n\nSuperseded by [ADR-0002](0002-use-postgres.md)\n",
+            ),
+            adr(
+                "0002-use-postgres.md",
+                "---\nstatus: accepted\nsupersedes: 1\n---\n\n# 2. Use Postgres\n",
+            ),
+            adr("README.md", "# Decisions\n"),
+        ];
+        let report = check_adrs("docs/decisions", &files, None, None);
+        assert!(report.pass, "{report:#?}");
+        assert_eq!(report.count, 2);
+        assert_eq!(report.files[0].status.as_deref(), Some("superseded"));
+        assert_eq!(report.files[0].superseded_by, vec![2]);
+        assert_eq!(report.files[1].supersedes, vec![1]);
+    }
+
+    #[test]
+    fn duplicates_and_gaps_reported() {
+        let files = vec![
+            adr("0001-a.md", "Status: accepted\n"),
+            adr("0003-b.md", "Status: accepted\n"),
+            adr("0003-c.md", "Status: accepted\n"),
+        ];
+        let report = check_adrs("d", &files, None, None);
+        assert_eq!(
+            issues_of(&report, "0003-b.md"),
+            vec![AdrIssueKind::Numbering, AdrIssueKind::Numbering]
+        );
+        assert_eq!(
+            issues_of(&report, "0003-c.md"),
+            vec![AdrIssueKind::Numbering]
+        );
+        let gap = &report.files[1].issues[1].message;
+        assert!(gap.contains("ADR 0002 is missing"), "{gap}");
+    }
+
+    #[test]
+    fn missing_start_of_sequence_reported() {
+        let files = vec![
+            adr("0003-a.md", "Status: accepted\n"),
+            adr("0004-b.md", "Status: accepted\n"),
+        ];
+        let report = check_adrs("d", &files, None, None);
+        assert_eq!(
+            issues_of(&report, "0003-a.md"),
+            vec![AdrIssueKind::Numbering]
+        );
+        let start = &report.files[0].issues[0].message;
+        assert!(start.contains("ADRs 0001 to 0002 are missing"), "{start}");
+
+        assert!(check_adrs("d", &files, None, Some(3)).pass);
+        let zero = vec![adr("0000-record-decisions.md", "Status: accepted\n")];
+        assert!(check_adrs("d", &zero, None, None).pass);
+        assert!(!check_adrs("d", &zero, None, Some(1)).pass);
+    }
+
+    #[test]
+    fn broken_and_one_sided_links_reported() {
+        let files = vec![
+            adr("0001-a.md", "Status: accepted\n"),
+            adr(
+                "0002-b.md",
+                "Status: accepted\n\nSupersedes ADR-1 and ADR-9.\n",
+            ),
+        ];
+        let report = check_adrs("d", &files, None, None);
+        assert_eq!(
+            issues_of(&report, "0002-b.md"),
+            vec![AdrIssueKind::BrokenLink]
+        );
+        assert_eq!(
+            issues_of(&report, "0001-a.md"),
+            vec![AdrIssueKind::NotReciprocal]
+        );
+    }
+
+    #[test]
+    fn status_must_be_allowed_and_match_links() {
+        let files = vec![
+            adr("0001-a.md", "Status: done\n"),
+            adr("0002-b.md", "# B\n"),
+            adr("0003-c.md", "Status: superseded\n"),
+        ];
+        let report = check_adrs("d", &files, None, None);
+        for file in ["0001-a.md", "0002-b.md", "0003-c.md"] {
+            assert_eq!(
+                issues_of(&report, file),
+                vec![AdrIssueKind::Status],
+                "{file}"
+            );
+        }
+
+        let custom = vec!["Done".to_string()];
+        let report = check_adrs("d", &files[..1], Some(&custom), None);
+        assert!(report.pass, "{report:#?}");
+    }
+
+    #[test]
+    fn superseded_by_requires_superseded_status() {
+        let files = vec![
+            adr("0001-a.md", "Status: accepted\nSuperseded by ADR-2\n"),
+            adr("0002-b.md", "Status: accepted\nSupersedes ADR-1\n"),
+        ];
+        let report = check_adrs("d", &files, None, None);
+        assert_eq!(issues_of(&report, "0001-a.md"), vec![AdrIssueKind::Status]);
+        assert!(issues_of(&report, "0002-b.md").is_empty());
+    }
+
+    #[test]
+    fn status_sources_are_normalized() {
+        assert_eq!(
+            parse_adr("## Status\n\n**Accepted** (2026-03-01)\n")
+                .status
+                .as_deref(),
+            Some("accepted")
+        );
+        assert_eq!(
+            parse_adr("* **Status:** Proposed\n").status.as_deref(),
+            Some("proposed")
+        );
+        assert_eq!(
+            parse_adr("```\nStatus: accepted\n```\n").status,
+            None,
+            "code blocks are ignored"
+        );
+    }
+
+    #[test]
+    fn directory_scan_skips_unnumbered_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = camino::Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        std::fs::write(path.join("0001-a.md"), "Status: accepted\n").unwrap();
+        std::fs::write(path.join("template.md"), "Status: TBD\n").unwrap();
+        std::fs::write(path.join("0002-notes.txt"), "").unwrap();
+        let config = AdrConfig {
+            dir: path.clone(),
+            statuses: None,
+            first_number: None,
+        };
+        let report = check_adr_dir(&path, &config).unwrap();
+        assert_eq!(report.count, 1);
+        assert!(report.pass);
+    }
+}
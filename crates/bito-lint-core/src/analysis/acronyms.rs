@@ -1,16 +1,26 @@
-//! Acronym frequency analysis.
+//! Acronym frequency and definition analysis.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
 use regex::Regex;
 
-use super::reports::{AcronymCount, AcronymReport};
+use crate::word_lists::WELL_KNOWN_ACRONYMS;
+
+use super::reports::{AcronymCount, AcronymReport, UndefinedAcronym};
 
 /// Regex for acronyms: two or more consecutive uppercase letters as a word.
 static ACRONYM_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\b[A-Z]{2,}\b").expect("valid regex"));
 
+/// Regex for a parenthetical, capturing its contents.
+static PAREN_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\(([^()]+)\)").expect("valid regex"));
+
+/// Regex for an acronym immediately before a parenthetical: `ADR (`.
+static TRAILING_ACRONYM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b([A-Z]{2,})\s*$").expect("valid regex"));
+
 /// Analyze acronym usage.
 #[tracing::instrument(skip_all)]
 pub fn analyze_acronyms(text: &str) -> AcronymReport {
@@ -33,5 +43,170 @@ pub fn analyze_acronyms(text: &str) -> AcronymReport {
         total_acronyms,
         unique_acronyms,
         acronym_list,
+        undefined: Vec::new(),
+    }
+}
+
+/// Find acronyms used before (or without) being defined.
+///
+/// Recognizes "Architecture Decision Record (ADR)" and
+/// "ADR (Architecture Decision Record)" as definitions. Acronyms in
+/// [`WELL_KNOWN_ACRONYMS`] or `known` never need one.
+#[tracing::instrument(skip_all, fields(sentences = sentences.len()))]
+pub fn find_undefined_acronyms(sentences: &[String], known: &[String]) -> Vec<UndefinedAcronym> {
+    let known: HashSet<&str> = known.iter().map(String::as_str).collect();
+    let mut defined_at: HashMap<String, usize> = HashMap::new();
+    let mut undefined: Vec<UndefinedAcronym> = Vec::new();
+
+    for (i, sentence) in sentences.iter().enumerate() {
+        let sentence_num = i + 1;
+        let definitions = find_definitions(sentence);
+
+        for m in ACRONYM_RE.find_iter(sentence) {
+            let acronym = m.as_str();
+            if WELL_KNOWN_ACRONYMS.contains(acronym)
+                || known.contains(acronym)
+                || defined_at.contains_key(acronym)
+            {
+                continue;
+            }
+            if definitions.contains(&m.start()) {
+                defined_at.insert(acronym.to_string(), sentence_num);
+            } else if !undefined.iter().any(|u| u.acronym == acronym) {
+                undefined.push(UndefinedAcronym {
+                    acronym: acronym.to_string(),
+                    sentence_num,
+                    defined_at: None,
+                });
+            }
+        }
+    }
+
+    for u in &mut undefined {
+        u.defined_at = defined_at.get(&u.acronym).copied();
+    }
+    undefined
+}
+
+/// Byte offsets of acronyms that are being defined in this sentence.
+fn find_definitions(sentence: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+
+    for caps in PAREN_RE.captures_iter(sentence) {
+        let (whole, inner) = (caps.get(0).expect("whole match"), &caps[1]);
+        let before = &sentence[..whole.start()];
+        let inner_trimmed = inner.trim();
+
+        // "Architecture Decision Record (ADR)"
+        if ACRONYM_RE
+            .find(inner_trimmed)
+            .is_some_and(|m| m.as_str() == inner_trimmed)
+        {
+            let words: Vec<&str> = before.split_whitespace().collect();
+            let window = (inner_trimmed.len() + 5).min(inner_trimmed.len() * 2);
+            let long_form = words[words.len().saturating_sub(window)..].join(" ");
+            if is_long_form(inner_trimmed, &long_form) {
+                let lead = inner.len() - inner.trim_start().len();
+                offsets.push(caps.get(1).expect("inner match").start() + lead);
+            }
+            continue;
+        }
+
+        // "ADR (Architecture Decision Record)"
+        if let Some(acronym) = TRAILING_ACRONYM_RE.captures(before).and_then(|c| c.get(1))
+            && is_long_form(acronym.as_str(), inner_trimmed)
+        {
+            offsets.push(acronym.start());
+        }
+    }
+
+    offsets
+}
+
+/// Whether `long_form` could expand to `acronym`.
+///
+/// Matches the acronym's letters right to left against the long form, in
+/// order, with the first letter required to start a word (after
+/// Schwartz & Hearst, 2003).
+fn is_long_form(acronym: &str, long_form: &str) -> bool {
+    let short: Vec<char> = acronym.to_lowercase().chars().collect();
+    let long: Vec<char> = long_form.to_lowercase().chars().collect();
+    if long.len() <= short.len() {
+        return false;
+    }
+
+    let mut li = long.len();
+    for (si, &c) in short.iter().enumerate().rev() {
+        loop {
+            if li == 0 {
+                return false;
+            }
+            li -= 1;
+            let word_start = li == 0 || !long[li - 1].is_alphanumeric();
+            if long[li] == c && (si > 0 || word_start) {
+                break;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentences(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| (*s).to_string()).collect()
+    }
+
+    #[test]
+    fn long_form_before_acronym_defines_it() {
+        let s = sentences(&[
+            "We record choices as an Architecture Decision Record (ADR).",
+            "Each ADR has a status.",
+        ]);
+        assert!(find_undefined_acronyms(&s, &[]).is_empty());
+    }
+
+    #[test]
+    fn acronym_before_long_form_defines_it() {
+        let s = sentences(&[
+            "Write an ADR (Architecture Decision Record) for each choice.",
+            "Then file the ADR.",
+        ]);
+        assert!(find_undefined_acronyms(&s, &[]).is_empty());
+    }
+
+    #[test]
+    fn use_before_definition_reported_with_location() {
+        let s = sentences(&[
+            "The RFC process is simple.",
+            "Start with a Request for Comments (RFC).",
+        ]);
+        let undefined = find_undefined_acronyms(&s, &[]);
+        assert_eq!(undefined.len(), 1);
+        assert_eq!(undefined[0].acronym, "RFC");
+        assert_eq!(undefined[0].sentence_num, 1);
+        assert_eq!(undefined[0].defined_at, Some(2));
+    }
+
+    #[test]
+    fn never_defined_reported_once() {
+        let s = sentences(&["The SLO is tight.", "We missed the SLO twice."]);
+        let undefined = find_undefined_acronyms(&s, &[]);
+        assert_eq!(undefined.len(), 1);
+        assert_eq!(undefined[0].defined_at, None);
+    }
+
+    #[test]
+    fn well_known_and_configured_acronyms_exempt() {
+        let s = sentences(&["The API returns JSON over HTTP.", "Ask the SRE team."]);
+        assert!(find_undefined_acronyms(&s, &["SRE".to_string()]).is_empty());
+    }
+
+    #[test]
+    fn unrelated_parenthetical_is_not_a_definition() {
+        let s = sentences(&["See the docs (SLA) for details."]);
+        assert_eq!(find_undefined_acronyms(&s, &[]).len(), 1);
     }
 }
@@ -0,0 +1,162 @@
+//! Machine-drafted prose tell detection.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::text;
+use crate::word_lists::AI_TELL_PHRASES;
+
+use super::reports::{AiTell, AiTellKind, AiTellParagraph, AiTellsReport};
+
+/// Regex matching any stock phrase as whole words, longest first.
+static PHRASE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    let mut phrases: Vec<&str> = AI_TELL_PHRASES.iter().copied().collect();
+    phrases.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    let alternation = phrases
+        .iter()
+        .map(|p| regex::escape(p))
+        .collect::<Vec<_>>()
+        .join("|");
+    Regex::new(&format!(r"\b(?:{alternation})\b")).expect("valid regex")
+});
+
+/// Regex for "not only ... but also" within one sentence.
+static NOT_ONLY_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bnot only\b[^.!?]*?\bbut also\b").expect("valid regex"));
+
+/// Regex for a one-word triad: "fast, reliable, and secure".
+static TRIAD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b[a-z]+, [a-z]+,? and [a-z]+\b").expect("valid regex"));
+
+/// Em dashes per paragraph before they count as a tell.
+const EM_DASH_LIMIT: usize = 2;
+
+/// Detect stock phrases and constructions typical of machine-drafted prose.
+///
+/// Located by paragraph, with a density (tells per 100 words) for each
+/// paragraph that has any.
+#[tracing::instrument(skip_all)]
+pub fn analyze_ai_tells(paragraphs: &[String]) -> AiTellsReport {
+    let mut tells = Vec::new();
+    let mut flagged = Vec::new();
+    let mut total_words = 0;
+
+    for (idx, paragraph) in paragraphs.iter().enumerate() {
+        let paragraph_num = idx + 1;
+        let lower = paragraph.to_lowercase().replace('\u{2019}', "'");
+        let word_count = text::extract_words(paragraph).len();
+        total_words += word_count;
+        let before = tells.len();
+
+        let mut push = |text: &str, kind| {
+            tells.push(AiTell {
+                text: text.to_string(),
+                kind,
+                paragraph_num,
+            });
+        };
+
+        for m in PHRASE_RE.find_iter(&lower) {
+            push(m.as_str(), AiTellKind::Phrase);
+        }
+        for m in NOT_ONLY_RE.find_iter(&lower) {
+            push(m.as_str(), AiTellKind::NotOnlyButAlso);
+        }
+        for m in TRIAD_RE.find_iter(&lower).skip(1) {
+            push(m.as_str(), AiTellKind::Triad);
+        }
+        let em_dashes = lower.matches('\u{2014}').count() + lower.matches(" -- ").count();
+        if em_dashes > EM_DASH_LIMIT {
+            push(&format!("{em_dashes} em dashes"), AiTellKind::EmDash);
+        }
+
+        let count = tells.len() - before;
+        if count > 0 {
+            flagged.push(AiTellParagraph {
+                paragraph_num,
+                tells: count,
+                density: per_hundred(count, word_count),
+            });
+        }
+    }
+
+    AiTellsReport {
+        total_tells: tells.len(),
+        density: per_hundred(tells.len(), total_words),
+        tells,
+        paragraphs: flagged,
+    }
+}
+
+/// `count` per 100 of `words`, rounded to one decimal place.
+fn per_hundred(count: usize, words: usize) -> f64 {
+    if words == 0 {
+        return 0.0;
+    }
+    (count as f64 / words as f64 * 1000.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(paragraphs: &[&str]) -> AiTellsReport {
+        let paragraphs: Vec<String> = paragraphs.iter().map(|s| (*s).to_string()).collect();
+        analyze_ai_tells(&paragraphs)
+    }
+
+    fn kinds(report: &AiTellsReport) -> Vec<(AiTellKind, usize)> {
+        report
+            .tells
+            .iter()
+            .map(|t| (t.kind, t.paragraph_num))
+            .collect()
+    }
+
+    #[test]
+    fn plain_prose_has_no_tells() {
+        let report = analyze(&["The parser reads one line at a time and stops at EOF."]);
+        assert_eq!(report.total_tells, 0);
+        assert!(report.paragraphs.is_empty());
+    }
+
+    #[test]
+    fn phrases_located_by_paragraph() {
+        let report = analyze(&[
+            "Install the tool.",
+            "Let us delve into the config. It\u{2019}s worth noting that defaults are safe.",
+        ]);
+        assert_eq!(
+            kinds(&report),
+            vec![(AiTellKind::Phrase, 2), (AiTellKind::Phrase, 2)]
+        );
+        assert_eq!(report.tells[1].text, "it's worth noting that");
+        assert_eq!(report.paragraphs.len(), 1);
+        assert_eq!(report.paragraphs[0].paragraph_num, 2);
+        assert!(report.paragraphs[0].density > report.density);
+    }
+
+    #[test]
+    fn constructions_detected() {
+        let report = analyze(&[
+            "It is not only fast but also safe. It is quick, clean, and simple. \
+             It is small, sharp, and cheap.",
+            "One \u{2014} two \u{2014} three \u{2014} four.",
+        ]);
+        assert_eq!(
+            kinds(&report),
+            vec![
+                (AiTellKind::NotOnlyButAlso, 1),
+                (AiTellKind::Triad, 1),
+                (AiTellKind::EmDash, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn occasional_em_dash_and_single_triad_allowed() {
+        let report = analyze(&["Read, write, and run \u{2014} then stop \u{2014} and rest."]);
+        assert_eq!(report.total_tells, 0);
+    }
+}
@@ -0,0 +1,215 @@
+//! Descriptions and default thresholds of the analysis checks.
+//!
+//! [`CHECK_INFO`] has one entry per name in [`ALL_CHECKS`](super::ALL_CHECKS),
+//! in the same order. Thresholds with no default are gates that only apply
+//! when a caller or rule sets them.
+
+use schemars::JsonSchema;
+use serde::Serialize;
+
+/// What an analysis check reports and the limits it applies.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CheckInfo {
+    /// Check name, as used in `checks` lists and directives.
+    pub name: &'static str,
+    /// What the check reports.
+    pub description: &'static str,
+    /// Limits the check applies.
+    pub thresholds: &'static [Threshold],
+}
+
+/// A limit an analysis check applies.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct Threshold {
+    /// Threshold name; configurable ones match their config key.
+    pub name: &'static str,
+    /// Default value, or `None` if the gate is off unless set.
+    pub default: Option<f64>,
+    /// What the threshold limits.
+    pub description: &'static str,
+}
+
+/// Every analysis check, in [`ALL_CHECKS`](super::ALL_CHECKS) order.
+pub const CHECK_INFO: &[CheckInfo] = &[
+    CheckInfo {
+        name: "readability",
+        description: "Flesch-Kincaid grade level",
+        thresholds: &[Threshold {
+            name: "max_grade",
+            default: None,
+            description: "highest passing grade level",
+        }],
+    },
+    CheckInfo {
+        name: "grammar",
+        description: "Grammar issues and passive voice",
+        thresholds: &[Threshold {
+            name: "passive_max",
+            default: None,
+            description: "highest passing passive voice percentage",
+        }],
+    },
+    CheckInfo {
+        name: "sticky",
+        description: "Sentences dense with glue words",
+        thresholds: &[
+            Threshold {
+                name: "sticky_glue_percentage",
+                default: Some(45.0),
+                description: "glue word percentage above which a sentence is sticky",
+            },
+            Threshold {
+                name: "semi_sticky_glue_percentage",
+                default: Some(35.0),
+                description: "glue word percentage above which a sentence is semi-sticky",
+            },
+        ],
+    },
+    CheckInfo {
+        name: "pacing",
+        description: "Distribution of fast, medium, and slow sentences",
+        thresholds: &[
+            Threshold {
+                name: "fast_max_words",
+                default: Some(9.0),
+                description: "most words in a fast sentence",
+            },
+            Threshold {
+                name: "medium_max_words",
+                default: Some(20.0),
+                description: "most words in a medium sentence",
+            },
+        ],
+    },
+    CheckInfo {
+        name: "sentence_length",
+        description: "Sentence length variety and very long sentences",
+        thresholds: &[Threshold {
+            name: "long_sentence_words",
+            default: Some(30.0),
+            description: "word count above which a sentence is very long",
+        }],
+    },
+    CheckInfo {
+        name: "transitions",
+        description: "Transition word usage",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "overused",
+        description: "Words used more often than expected",
+        thresholds: &[Threshold {
+            name: "frequency_percentage",
+            default: Some(0.5),
+            description: "share of all words above which a word is overused",
+        }],
+    },
+    CheckInfo {
+        name: "repeated",
+        description: "Phrases repeated across the text",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "echoes",
+        description: "The same word repeated close together",
+        thresholds: &[Threshold {
+            name: "distance_words",
+            default: Some(20.0),
+            description: "word distance below which a repeat is an echo",
+        }],
+    },
+    CheckInfo {
+        name: "sensory",
+        description: "Sensory vocabulary by sense",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "diction",
+        description: "Vague words",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "hedging",
+        description: "Hedge and weasel phrases",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "wordiness",
+        description: "Wordy phrases and redundancies",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "ai_tells",
+        description: "Patterns typical of machine-drafted prose",
+        thresholds: &[Threshold {
+            name: "em_dashes_per_paragraph",
+            default: Some(2.0),
+            description: "em dashes per paragraph above which they count as a tell",
+        }],
+    },
+    CheckInfo {
+        name: "cliches",
+        description: "Clichés",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "consistency",
+        description: "Mixed or wrong-dialect spellings and formatting",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "acronyms",
+        description: "Acronym frequency and undefined acronyms",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "jargon",
+        description: "Business jargon",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "complex_paragraphs",
+        description: "Paragraphs with long sentences of long words",
+        thresholds: &[
+            Threshold {
+                name: "avg_sentence_words",
+                default: Some(20.0),
+                description: "average sentence length above which a paragraph may be complex",
+            },
+            Threshold {
+                name: "avg_syllables",
+                default: Some(1.8),
+                description: "average syllables per word above which a paragraph may be complex",
+            },
+        ],
+    },
+    CheckInfo {
+        name: "conjunction_starts",
+        description: "Sentences starting with a conjunction",
+        thresholds: &[],
+    },
+    CheckInfo {
+        name: "style",
+        description: "Adverbs, hidden verbs, and a composite style score",
+        thresholds: &[],
+    },
+];
+
+/// Look up a check's description and thresholds by name.
+pub fn check_info(name: &str) -> Option<&'static CheckInfo> {
+    CHECK_INFO.iter().find(|info| info.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::ALL_CHECKS;
+
+    #[test]
+    fn catalog_matches_all_checks() {
+        let names: Vec<&str> = CHECK_INFO.iter().map(|info| info.name).collect();
+        assert_eq!(names, ALL_CHECKS);
+        assert!(check_info("echoes").is_some());
+        assert!(check_info("nope").is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! Hedge and weasel phrase detection.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::word_lists::{HEDGES, WEASEL_PHRASES};
+
+use super::reports::{HedgeFound, HedgeKind, HedgingReport};
+
+/// Regex matching any hedge or weasel phrase as whole words.
+///
+/// Longer phrases come first so "might possibly" wins over "possibly".
+static HEDGE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    let mut phrases: Vec<&str> = HEDGES
+        .iter()
+        .chain(WEASEL_PHRASES.iter())
+        .copied()
+        .collect();
+    phrases.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    let alternation = phrases
+        .iter()
+        .map(|p| regex::escape(p))
+        .collect::<Vec<_>>()
+        .join("|");
+    Regex::new(&format!(r"\b(?:{alternation})\b")).expect("valid regex")
+});
+
+/// Detect hedges and weasel phrases, located by sentence.
+#[tracing::instrument(skip_all)]
+pub fn analyze_hedging(sentences: &[String], words: &[String]) -> HedgingReport {
+    let mut hedges = Vec::new();
+
+    for (i, sentence) in sentences.iter().enumerate() {
+        let lower = sentence.to_lowercase();
+        for m in HEDGE_RE.find_iter(&lower) {
+            let phrase = m.as_str();
+            let kind = if WEASEL_PHRASES.contains(phrase) {
+                HedgeKind::Weasel
+            } else {
+                HedgeKind::Hedge
+            };
+            hedges.push(HedgeFound {
+                phrase: phrase.to_string(),
+                kind,
+                sentence_num: i + 1,
+            });
+        }
+    }
+
+    let total_hedges = hedges.len();
+    let density = if words.is_empty() {
+        0.0
+    } else {
+        total_hedges as f64 / words.len() as f64 * 100.0
+    };
+
+    HedgingReport {
+        total_hedges,
+        density,
+        hedges,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text;
+
+    fn analyze(input: &str) -> HedgingReport {
+        analyze_hedging(&text::split_sentences(input), &text::extract_words(input))
+    }
+
+    #[test]
+    fn no_hedges() {
+        let report = analyze("The parser rejects invalid input.");
+        assert_eq!(report.total_hedges, 0);
+        assert!(report.density.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn hedges_and_weasels_located_by_sentence() {
+        let report = analyze(
+            "The cache is fast. It is arguably the best option. \
+             Many people say it is somewhat fragile.",
+        );
+        let found: Vec<_> = report
+            .hedges
+            .iter()
+            .map(|h| (h.phrase.as_str(), h.kind, h.sentence_num))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("arguably", HedgeKind::Hedge, 2),
+                ("many people say", HedgeKind::Weasel, 3),
+                ("somewhat", HedgeKind::Hedge, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn longest_phrase_wins() {
+        let report = analyze("This might possibly break.");
+        assert_eq!(report.total_hedges, 1);
+        assert_eq!(report.hedges[0].phrase, "might possibly");
+    }
+
+    #[test]
+    fn matches_whole_words_only() {
+        let report = analyze("The impossibly fast path was taken.");
+        assert_eq!(report.total_hedges, 0);
+    }
+
+    #[test]
+    fn density_per_hundred_words() {
+        let report = analyze("Perhaps this works.");
+        assert!((report.density - 100.0 / 3.0).abs() < 0.01);
+    }
+}
@@ -1,18 +1,21 @@
 //! Comprehensive writing analysis.
 //!
-//! Decomposes writing quality analysis into 18 independent features,
+//! Decomposes writing quality analysis into 21 independent features,
 //! orchestrated by [`run_full_analysis`].
 //!
 //! Each feature is a pure function in its own module. Callers can also
 //! invoke features individually.
 
 pub mod acronyms;
+pub mod ai_tells;
+pub mod catalog;
 pub mod cliches;
 pub mod complex_paragraphs;
 pub mod conjunction_starts;
 pub mod consistency;
 pub mod diction;
 pub mod echoes;
+pub mod hedging;
 pub mod jargon;
 pub mod overused;
 pub mod pacing;
@@ -23,12 +26,14 @@ pub mod sentence_length;
 pub mod sticky;
 pub mod style;
 pub mod transitions;
+pub mod wordiness;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
+pub use catalog::{CHECK_INFO, CheckInfo, check_info};
 pub use reports::FullAnalysisReport;
 
-use crate::config::Dialect;
+use crate::config::{Dialect, StyleComponent, StyleScoreConfig};
 use crate::error::{AnalysisError, AnalysisResult};
 use crate::grammar;
 use crate::markdown;
@@ -48,6 +53,9 @@ pub const ALL_CHECKS: &[&str] = &[
     "echoes",
     "sensory",
     "diction",
+    "hedging",
+    "wordiness",
+    "ai_tells",
     "cliches",
     "consistency",
     "acronyms",
@@ -57,6 +65,21 @@ pub const ALL_CHECKS: &[&str] = &[
     "style",
 ];
 
+/// Settings that tune individual analysis checks.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisOptions {
+    /// Max readability grade.
+    pub max_grade: Option<f64>,
+    /// Max passive voice percentage.
+    pub passive_max: Option<f64>,
+    /// Dialect for spelling enforcement.
+    pub dialect: Option<Dialect>,
+    /// Acronyms that need no definition, beyond the built-in list.
+    pub known_acronyms: Vec<String>,
+    /// Style score formula. `None` uses the built-in formula.
+    pub style_score: Option<StyleScoreConfig>,
+}
+
 /// Run full writing analysis.
 ///
 /// # Arguments
@@ -64,17 +87,13 @@ pub const ALL_CHECKS: &[&str] = &[
 /// * `input` — The text to analyze.
 /// * `strip_md` — If `true`, strip markdown formatting before analysis.
 /// * `checks` — Optional list of check names to run. If `None`, runs all.
-/// * `max_grade` — Optional max readability grade.
-/// * `passive_max` — Optional max passive voice percentage.
-/// * `dialect` — Optional dialect for spelling enforcement.
-#[tracing::instrument(skip(input), fields(text_len = input.len(), strip_md))]
+/// * `options` — Thresholds, dialect, known acronyms, and style formula.
+#[tracing::instrument(skip(input, options), fields(text_len = input.len(), strip_md))]
 pub fn run_full_analysis(
     input: &str,
     strip_md: bool,
     checks: Option<&[String]>,
-    max_grade: Option<f64>,
-    passive_max: Option<f64>,
-    dialect: Option<Dialect>,
+    options: &AnalysisOptions,
 ) -> AnalysisResult<FullAnalysisReport> {
     let prose = if strip_md {
         markdown::strip_to_prose(input)
@@ -113,14 +132,14 @@ pub fn run_full_analysis(
 
     // Readability
     let readability_report = if enabled.contains("readability") {
-        readability::check_readability(&prose, false, max_grade).ok()
+        readability::check_readability(&prose, false, options.max_grade).ok()
     } else {
         None
     };
 
     // Grammar
     let grammar_report = if enabled.contains("grammar") {
-        grammar::check_grammar_full(&prose, false, passive_max).ok()
+        grammar::check_grammar_full(&prose, false, options.passive_max).ok()
     } else {
         None
     };
@@ -190,6 +209,29 @@ pub fn run_full_analysis(
         None
     };
 
+    // Hedges and weasel phrases
+    let hedging_report = if enabled.contains("hedging") {
+        Some(hedging::analyze_hedging(&sentences, &words))
+    } else {
+        None
+    };
+
+    // Wordy phrases
+    let wordiness_report = if enabled.contains("wordiness") {
+        let mut report = wordiness::analyze_wordiness(&sentences);
+        wordiness::locate_phrases(&mut report.phrases, input, strip_md);
+        Some(report)
+    } else {
+        None
+    };
+
+    // Machine-drafted prose tells
+    let ai_tells_report = if enabled.contains("ai_tells") {
+        Some(ai_tells::analyze_ai_tells(&paragraphs))
+    } else {
+        None
+    };
+
     // Clichés
     let cliches_report = if enabled.contains("cliches") {
         Some(cliches::analyze_cliches(&prose))
@@ -199,14 +241,16 @@ pub fn run_full_analysis(
 
     // Consistency
     let consistency_report = if enabled.contains("consistency") {
-        Some(consistency::analyze_consistency(&prose, dialect))
+        Some(consistency::analyze_consistency(&prose, options.dialect))
     } else {
         None
     };
 
     // Acronyms
     let acronyms_report = if enabled.contains("acronyms") {
-        Some(acronyms::analyze_acronyms(&prose))
+        let mut report = acronyms::analyze_acronyms(&prose);
+        report.undefined = acronyms::find_undefined_acronyms(&sentences, &options.known_acronyms);
+        Some(report)
     } else {
         None
     };
@@ -232,24 +276,51 @@ pub fn run_full_analysis(
         None
     };
 
-    // Style (depends on sticky + diction for composite score)
+    // Style (composite score draws on other checks' counts)
     let style_report = if enabled.contains("style") {
-        // Build default reports for score calculation if not already computed
-        let sticky_for_score = sticky_report
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| sticky::analyze_sticky_sentences(&sentences, &words));
-        let diction_for_score = diction_report
-            .as_ref()
-            .cloned()
-            .unwrap_or_else(|| diction::analyze_diction(&prose, &words));
+        let default_formula = StyleScoreConfig::default();
+        let formula = options.style_score.as_ref().unwrap_or(&default_formula);
+        // Use each participating check's report if it ran, else compute it.
+        let mut measured = BTreeMap::new();
+        for &component in formula.components.keys() {
+            let value = match component {
+                StyleComponent::Passive => passive_count as f64,
+                StyleComponent::Glue => sticky_report.as_ref().map_or_else(
+                    || sticky::analyze_sticky_sentences(&sentences, &words).overall_glue_index,
+                    |r| r.overall_glue_index,
+                ),
+                StyleComponent::Vague => diction_report.as_ref().map_or_else(
+                    || style::scored_vague(&diction::analyze_diction(&prose, &words), formula),
+                    |r| style::scored_vague(r, formula),
+                ) as f64,
+                StyleComponent::Hedging => hedging_report.as_ref().map_or_else(
+                    || hedging::analyze_hedging(&sentences, &words).total_hedges,
+                    |r| r.total_hedges,
+                ) as f64,
+                StyleComponent::Cliches => cliches_report.as_ref().map_or_else(
+                    || cliches::analyze_cliches(&prose).total_cliches,
+                    |r| r.total_cliches,
+                ) as f64,
+                StyleComponent::Jargon => jargon_report.as_ref().map_or_else(
+                    || jargon::analyze_business_jargon(&prose, &words).total_jargon,
+                    |r| r.total_jargon,
+                ) as f64,
+                StyleComponent::Wordiness => wordiness_report.as_ref().map_or_else(
+                    || wordiness::analyze_wordiness(&sentences).total_wordy,
+                    |r| r.total_wordy,
+                ) as f64,
+                StyleComponent::AiTells => ai_tells_report.as_ref().map_or_else(
+                    || ai_tells::analyze_ai_tells(&paragraphs).total_tells,
+                    |r| r.total_tells,
+                ) as f64,
+                // Measured by the style analysis itself.
+                StyleComponent::Adverbs | StyleComponent::HiddenVerbs => continue,
+            };
+            measured.insert(component, value);
+        }
 
         Some(style::analyze_style(
-            &prose,
-            &words,
-            passive_count,
-            &sticky_for_score,
-            &diction_for_score,
+            &prose, &sentences, &words, &measured, formula,
         ))
     } else {
         None
@@ -267,6 +338,9 @@ pub fn run_full_analysis(
         echoes: echoes_report,
         sensory: sensory_report,
         diction: diction_report,
+        hedging: hedging_report,
+        wordiness: wordiness_report,
+        ai_tells: ai_tells_report,
         cliches: cliches_report,
         consistency: consistency_report,
         acronyms: acronyms_report,
@@ -280,11 +354,12 @@ pub fn run_full_analysis(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::ScoreWeight;
 
     #[test]
     fn full_analysis_runs() {
         let text = "The cat sat on the mat. The dog ran fast. However, the bird flew away.";
-        let report = run_full_analysis(text, false, None, None, None, None).unwrap();
+        let report = run_full_analysis(text, false, None, &AnalysisOptions::default()).unwrap();
         assert!(report.readability.is_some());
         assert!(report.grammar.is_some());
         assert!(report.sticky_sentences.is_some());
@@ -296,23 +371,68 @@ mod tests {
     fn selective_checks() {
         let text = "The cat sat on the mat. The dog ran fast.";
         let checks = vec!["readability".to_string(), "pacing".to_string()];
-        let report = run_full_analysis(text, false, Some(&checks), None, None, None).unwrap();
+        let report =
+            run_full_analysis(text, false, Some(&checks), &AnalysisOptions::default()).unwrap();
         assert!(report.readability.is_some());
         assert!(report.pacing.is_some());
         assert!(report.grammar.is_none());
         assert!(report.style.is_none());
     }
 
+    #[test]
+    fn hedge_deducted_once() {
+        let text = "The cache is somewhat slow and fairly new.";
+        let report = run_full_analysis(text, false, None, &AnalysisOptions::default()).unwrap();
+        let style = report.style.unwrap();
+        let points = |component| {
+            style
+                .deductions
+                .iter()
+                .find(|d| d.component == component)
+                .map_or(0.0, |d| d.points)
+        };
+        assert_eq!(points(StyleComponent::Hedging), 2.0);
+        assert_eq!(points(StyleComponent::Vague), 0.0);
+        assert_eq!(points(StyleComponent::Adverbs), 0.0);
+        assert_eq!(style.adverb_count, 1);
+        assert_eq!(report.diction.unwrap().total_vague, 2);
+    }
+
+    #[test]
+    fn hedges_count_as_vague_and_adverbs_without_hedging_component() {
+        let text = "The cache is somewhat slow and fairly new.";
+        let weight = |weight| ScoreWeight {
+            weight,
+            max: None,
+            threshold: None,
+        };
+        let options = AnalysisOptions {
+            style_score: Some(StyleScoreConfig {
+                components: BTreeMap::from([
+                    (StyleComponent::Adverbs, weight(1.0)),
+                    (StyleComponent::Vague, weight(1.0)),
+                ]),
+            }),
+            ..AnalysisOptions::default()
+        };
+        let report =
+            run_full_analysis(text, false, Some(&["style".to_string()]), &options).unwrap();
+        let style = report.style.unwrap();
+        let measured: Vec<_> = style.deductions.iter().map(|d| d.measured).collect();
+        assert_eq!(measured, vec![1.0, 2.0]);
+        assert_eq!(style.style_score, 97);
+    }
+
     #[test]
     fn empty_input_errors() {
-        let result = run_full_analysis("", false, None, None, None, None);
+        let result = run_full_analysis("", false, None, &AnalysisOptions::default());
         assert!(result.is_err());
     }
 
     #[test]
     fn markdown_stripping_works() {
         let md = "# Title\n\nThe cat sat on the mat.\n\n```rust\nlet x = 1;\n```";
-        let report = run_full_analysis(md, true, None, None, None, None).unwrap();
+        let report = run_full_analysis(md, true, None, &AnalysisOptions::default()).unwrap();
         assert!(report.readability.is_some());
     }
 
@@ -320,7 +440,7 @@ mod tests {
     fn unknown_check_returns_error() {
         let text = "The cat sat on the mat.";
         let checks = vec!["readablity".to_string()];
-        let result = run_full_analysis(text, false, Some(&checks), None, None, None);
+        let result = run_full_analysis(text, false, Some(&checks), &AnalysisOptions::default());
         let err = result.unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("readablity"), "should name the unknown check");
@@ -336,7 +456,7 @@ mod tests {
             "pacing".to_string(),
             "nope".to_string(),
         ];
-        let result = run_full_analysis(text, false, Some(&checks), None, None, None);
+        let result = run_full_analysis(text, false, Some(&checks), &AnalysisOptions::default());
         let err = result.unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("bogus"), "should name bogus");
@@ -353,7 +473,7 @@ mod tests {
     fn valid_checks_accepted() {
         let text = "The cat sat on the mat. The dog ran fast.";
         let checks: Vec<String> = ALL_CHECKS.iter().map(|s| (*s).to_string()).collect();
-        let result = run_full_analysis(text, false, Some(&checks), None, None, None);
+        let result = run_full_analysis(text, false, Some(&checks), &AnalysisOptions::default());
         assert!(result.is_ok(), "all valid check names should be accepted");
     }
 
@@ -364,9 +484,10 @@ mod tests {
             text,
             false,
             Some(&["consistency".to_string()]),
-            None,
-            None,
-            Some(Dialect::EnUs),
+            &AnalysisOptions {
+                dialect: Some(Dialect::EnUs),
+                ..Default::default()
+            },
         )
         .unwrap();
         let c = report.consistency.expect("consistency report should exist");
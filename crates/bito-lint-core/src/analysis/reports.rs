@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::config::StyleComponent;
 use crate::grammar::GrammarReport;
 use crate::readability::ReadabilityReport;
 
@@ -47,6 +48,15 @@ pub struct FullAnalysisReport {
     /// Vague word usage.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diction: Option<DictionReport>,
+    /// Hedge and weasel phrase detection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hedging: Option<HedgingReport>,
+    /// Wordy phrase and redundancy detection.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wordiness: Option<WordinessReport>,
+    /// Machine-drafted prose tells.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_tells: Option<AiTellsReport>,
     /// Cliché detection.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cliches: Option<ClichesReport>,
@@ -273,6 +283,131 @@ pub struct VagueWordCount {
     pub count: usize,
 }
 
+// -- Hedging ----------------------------------------------------------------
+
+/// Hedge and weasel phrase analysis.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HedgingReport {
+    /// Total hedge and weasel phrase occurrences.
+    pub total_hedges: usize,
+    /// Occurrences per 100 words.
+    pub density: f64,
+    /// Each occurrence, in document order.
+    pub hedges: Vec<HedgeFound>,
+}
+
+/// A hedge or weasel phrase found in the text.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HedgeFound {
+    /// The matched phrase (lowercase).
+    pub phrase: String,
+    /// Whether it softens a claim or attributes it to nobody in particular.
+    pub kind: HedgeKind,
+    /// Sentence number (1-indexed).
+    pub sentence_num: usize,
+}
+
+/// Kinds of hedging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HedgeKind {
+    /// Softens a claim ("arguably", "it seems").
+    Hedge,
+    /// Appeals to unnamed authority or consensus ("many people say").
+    Weasel,
+}
+
+// -- Wordiness --------------------------------------------------------------
+
+/// Wordy phrase and redundancy analysis.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WordinessReport {
+    /// Total wordy phrase occurrences.
+    pub total_wordy: usize,
+    /// Words saved by applying every suggestion.
+    pub words_saved: usize,
+    /// Estimated tokens saved by applying every suggestion.
+    pub tokens_saved: usize,
+    /// Each occurrence with its suggested rewrite, in document order.
+    pub phrases: Vec<WordyPhrase>,
+}
+
+/// A wordy phrase and its concise replacement.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WordyPhrase {
+    /// The phrase as written.
+    pub phrase: String,
+    /// Suggested replacement, matching the phrase's leading capitalization.
+    pub replacement: String,
+    /// Sentence number (1-indexed).
+    pub sentence_num: usize,
+    /// Byte offset in the input where the phrase starts. Unset if the phrase
+    /// is split by markup or a line break and could not be located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+    /// Byte offset in the input just past the phrase, if located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+    /// 1-indexed line of `start`, if located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// Words saved by the replacement.
+    pub words_saved: usize,
+    /// Estimated tokens saved by the replacement.
+    pub tokens_saved: usize,
+}
+
+// -- AI Tells --------------------------------------------------------------
+
+/// Machine-drafted prose tell analysis.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AiTellsReport {
+    /// Total tells found.
+    pub total_tells: usize,
+    /// Tells per 100 words across the document.
+    pub density: f64,
+    /// Each tell, in document order.
+    pub tells: Vec<AiTell>,
+    /// Paragraphs containing tells, with their density.
+    pub paragraphs: Vec<AiTellParagraph>,
+}
+
+/// A single tell.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AiTell {
+    /// The matched text.
+    pub text: String,
+    /// What kind of tell it is.
+    pub kind: AiTellKind,
+    /// Paragraph number (1-indexed).
+    pub paragraph_num: usize,
+}
+
+/// Kinds of machine-drafted prose tells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AiTellKind {
+    /// A stock phrase ("delve into", "rich tapestry").
+    Phrase,
+    /// Three or more em dashes in one paragraph.
+    EmDash,
+    /// A "not only ... but also" construction.
+    NotOnlyButAlso,
+    /// A second or later one-word triad ("fast, reliable, and secure") in one paragraph.
+    Triad,
+}
+
+/// Tell density for one paragraph.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AiTellParagraph {
+    /// Paragraph number (1-indexed).
+    pub paragraph_num: usize,
+    /// Tells in the paragraph.
+    pub tells: usize,
+    /// Tells per 100 words in the paragraph.
+    pub density: f64,
+}
+
 // -- Clichés ----------------------------------------------------------------
 
 /// Cliché detection.
@@ -318,6 +453,9 @@ pub struct AcronymReport {
     pub unique_acronyms: usize,
     /// Acronyms sorted by frequency.
     pub acronym_list: Vec<AcronymCount>,
+    /// Acronyms used before (or without) a definition, in order of first use.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub undefined: Vec<UndefinedAcronym>,
 }
 
 /// An acronym with its frequency.
@@ -329,6 +467,18 @@ pub struct AcronymCount {
     pub count: usize,
 }
 
+/// An acronym used before it is defined.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UndefinedAcronym {
+    /// The acronym.
+    pub acronym: String,
+    /// Sentence number (1-indexed) of the first undefined use.
+    pub sentence_num: usize,
+    /// Sentence number (1-indexed) of a later definition, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defined_at: Option<usize>,
+}
+
 // -- Business Jargon --------------------------------------------------------
 
 /// Business jargon detection.
@@ -395,8 +545,23 @@ pub struct StyleReport {
     pub adverb_count: usize,
     /// Hidden verbs found (noun forms that should be verbs).
     pub hidden_verbs: Vec<HiddenVerbSuggestion>,
+    /// Light verb + nominalization constructions ("make a decision about").
+    pub nominalizations: Vec<Nominalization>,
     /// Composite style score (0–100).
     pub style_score: i32,
+    /// Points each participating component deducted from 100.
+    pub deductions: Vec<StyleDeduction>,
+}
+
+/// Points one component deducted from the style score.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StyleDeduction {
+    /// The component.
+    pub component: StyleComponent,
+    /// What was counted (occurrences, or a percentage for glue words).
+    pub measured: f64,
+    /// Points deducted, after the weight and cap.
+    pub points: f64,
 }
 
 /// A hidden verb suggestion.
@@ -409,3 +574,17 @@ pub struct HiddenVerbSuggestion {
     /// Number of occurrences.
     pub count: usize,
 }
+
+/// A light verb paired with a nominalization.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Nominalization {
+    /// The construction as written ("perform an analysis of").
+    pub phrase: String,
+    /// The nominalized noun.
+    pub noun: String,
+    /// Suggested verb form, if one can be derived.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb: Option<String>,
+    /// Sentence number (1-indexed).
+    pub sentence_num: usize,
+}
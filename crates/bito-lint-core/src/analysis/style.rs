@@ -1,30 +1,65 @@
-//! Style analysis: adverbs, hidden verbs, and composite score.
+//! Style analysis: adverbs, hidden verbs, nominalizations, and composite score.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::LazyLock;
 
 use regex::Regex;
 
-use crate::word_lists::HIDDEN_VERBS;
+use crate::config::{StyleComponent, StyleScoreConfig};
+use crate::word_lists::{
+    CONJUNCTIONS, HEDGES, HIDDEN_VERBS, LIGHT_VERBS, NOMINALIZATION_VERBS, NOT_NOMINALIZATIONS,
+};
 
-use super::reports::{DictionReport, HiddenVerbSuggestion, StickySentencesReport, StyleReport};
+use super::reports::{
+    DictionReport, HiddenVerbSuggestion, Nominalization, StyleDeduction, StyleReport,
+};
 
 /// Regex for adverbs: words ending in -ly.
 static ADVERB_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"\b\w+ly\b").expect("valid regex"));
 
+/// Regex for a verb, optional determiner and modifier, a noun with a
+/// nominalization suffix (singular or plural), and an optional trailing
+/// preposition.
+///
+/// The verb is checked against [`LIGHT_VERBS`] separately, so one pattern
+/// serves every inflection.
+static NOMINALIZATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b([a-z]+)\s+(?:(?:a|an|the|some|any)\s+)?(?:[a-z]+\s+)?([a-z]+(?:tion|sion|ment|ance|ence|ity|sis))(s?)\b(?:\s+(?:of|about|to|for|on|into|with)\b)?",
+    )
+    .expect("valid regex")
+});
+
+/// Words that may follow the head noun of a nominalization.
+const PREPOSITIONS: &[&str] = &[
+    "about", "as", "at", "by", "for", "from", "in", "into", "of", "on", "to", "with",
+];
+
 /// Analyze style: adverbs, hidden verbs, and compute composite score.
 ///
-/// The composite score starts at 100 and deducts points for issues.
+/// The composite score starts at 100 and deducts points per `formula`.
+/// `measured` carries the counts for components other than adverbs and
+/// hidden verbs, which are measured here.
 #[tracing::instrument(skip_all)]
 pub fn analyze_style(
     text: &str,
+    sentences: &[String],
     words: &[String],
-    passive_count: usize,
-    sticky: &StickySentencesReport,
-    diction: &DictionReport,
+    measured: &BTreeMap<StyleComponent, f64>,
+    formula: &StyleScoreConfig,
 ) -> StyleReport {
-    let adverb_count = ADVERB_RE.find_iter(text).count();
+    let adverbs: Vec<&str> = ADVERB_RE.find_iter(text).map(|m| m.as_str()).collect();
+    let adverb_count = adverbs.len();
+    // -ly hedges ("arguably", "fairly") count once, under hedging, if it is weighted.
+    let scored_adverbs = if formula.components.contains_key(&StyleComponent::Hedging) {
+        adverbs
+            .iter()
+            .filter(|a| !HEDGES.contains(a.to_lowercase().as_str()))
+            .count()
+    } else {
+        adverb_count
+    };
 
     // Hidden verbs: nouns that should be verbs
     let mut hidden_counts: HashMap<&str, usize> = HashMap::new();
@@ -46,49 +81,289 @@ pub fn analyze_style(
         })
         .collect();
 
-    let style_score =
-        calculate_style_score(passive_count, adverb_count, &hidden_verbs, sticky, diction);
+    let nominalizations = find_nominalizations(sentences);
+
+    // Hidden verbs and nominalizations count once per distinct noun.
+    let nouns: HashSet<&str> = hidden_verbs
+        .iter()
+        .map(|h| h.noun.as_str())
+        .chain(nominalizations.iter().map(|n| n.noun.as_str()))
+        .collect();
+    let mut measured = measured.clone();
+    measured.insert(StyleComponent::Adverbs, scored_adverbs as f64);
+    measured.insert(StyleComponent::HiddenVerbs, nouns.len() as f64);
+
+    let (style_score, deductions) = calculate_style_score(&measured, formula);
 
     StyleReport {
         adverb_count,
         hidden_verbs,
+        nominalizations,
         style_score,
+        deductions,
     }
 }
 
+/// Find light verb + nominalization constructions ("perform an analysis of"),
+/// located per sentence.
+pub fn find_nominalizations(sentences: &[String]) -> Vec<Nominalization> {
+    let mut found = Vec::new();
+
+    for (i, sentence) in sentences.iter().enumerate() {
+        let mut start = 0;
+        while let Some(caps) = NOMINALIZATION_RE.captures_at(sentence, start) {
+            let whole = caps.get(0).expect("whole match");
+            let verb = caps[1].to_lowercase();
+            if !LIGHT_VERBS.contains(verb.as_str()) {
+                // Retry from the next word, which may itself be a light verb.
+                start = caps.get(1).expect("verb match").end();
+                continue;
+            }
+            start = whole.end();
+
+            let noun = caps[2].to_lowercase();
+            let noun_end = caps.get(3).expect("plural match").end();
+            if NOT_NOMINALIZATIONS.contains(noun.as_str()) || !is_head_noun(&sentence[noun_end..]) {
+                continue;
+            }
+            found.push(Nominalization {
+                phrase: whole.as_str().to_string(),
+                verb: verb_for(&noun),
+                noun,
+                sentence_num: i + 1,
+            });
+        }
+    }
+
+    found
+}
+
+/// Whether the noun before `rest` ends its noun phrase, rather than
+/// modifying the next word ("a security review").
+fn is_head_noun(rest: &str) -> bool {
+    let next: String = rest
+        .trim_start()
+        .chars()
+        .take_while(char::is_ascii_alphabetic)
+        .collect::<String>()
+        .to_lowercase();
+    next.is_empty() || PREPOSITIONS.contains(&next.as_str()) || CONJUNCTIONS.contains(next.as_str())
+}
+
+/// The verb hidden in a nominalization, if the noun is listed in
+/// [`HIDDEN_VERBS`] or [`NOMINALIZATION_VERBS`].
+pub(crate) fn verb_for(noun: &str) -> Option<String> {
+    HIDDEN_VERBS
+        .get(noun)
+        .or_else(|| NOMINALIZATION_VERBS.get(noun))
+        .map(|verb| (*verb).to_string())
+}
+
+/// Vague words the style score counts: all of `diction`'s, less the hedges
+/// ("somewhat", "fairly") when the hedging component already counts them.
+pub(crate) fn scored_vague(diction: &DictionReport, formula: &StyleScoreConfig) -> usize {
+    if !formula.components.contains_key(&StyleComponent::Hedging) {
+        return diction.total_vague;
+    }
+    diction
+        .most_common
+        .iter()
+        .filter(|v| !HEDGES.contains(v.word.as_str()))
+        .map(|v| v.count)
+        .sum()
+}
+
 /// Calculate composite style score (0–100).
 ///
-/// Starts at 100 and deducts:
+/// Starts at 100 and, for each component in `formula`, deducts
+/// `weight × (measured − threshold)`, capped at `max`. The built-in
+/// formula ([`StyleScoreConfig::default`]) deducts:
 /// - Passive voice: −2 per instance, max −20
 /// - Adverbs: −0.5 per adverb, max −15
-/// - Hidden verbs: −2 per type, max −10
+/// - Hidden verbs and nominalizations: −2 per noun, max −10
 /// - High glue index (>25%): −(index − 25), max −15
 /// - Vague words: −0.5 per word, max −10
-fn calculate_style_score(
-    passive_count: usize,
-    adverb_count: usize,
-    hidden_verbs: &[HiddenVerbSuggestion],
-    sticky: &StickySentencesReport,
-    diction: &DictionReport,
-) -> i32 {
-    let mut score: f64 = 100.0;
-
-    // Passive voice: −2 per instance, max −20
-    score -= (passive_count as f64 * 2.0).min(20.0);
-
-    // Adverbs: −0.5 per adverb, max −15
-    score -= (adverb_count as f64 * 0.5).min(15.0);
-
-    // Hidden verbs: −2 per type, max −10
-    score -= (hidden_verbs.len() as f64 * 2.0).min(10.0);
-
-    // Glue word index: if >25%, deduct overage, max −15
-    if sticky.overall_glue_index > 25.0 {
-        score -= (sticky.overall_glue_index - 25.0).min(15.0);
+/// - Hedges and weasel phrases: −1 per occurrence, max −10
+pub(crate) fn calculate_style_score(
+    measured: &BTreeMap<StyleComponent, f64>,
+    formula: &StyleScoreConfig,
+) -> (i32, Vec<StyleDeduction>) {
+    let deductions: Vec<StyleDeduction> = formula
+        .components
+        .iter()
+        .map(|(&component, w)| {
+            let value = measured.get(&component).copied().unwrap_or(0.0);
+            let counted = (value - w.threshold.unwrap_or(0.0)).max(0.0);
+            let points = w
+                .max
+                .map_or(counted * w.weight, |max| (counted * w.weight).min(max));
+            StyleDeduction {
+                component,
+                measured: value,
+                points,
+            }
+        })
+        .collect();
+
+    let score = 100.0 - deductions.iter().map(|d| d.points).sum::<f64>();
+    (score.max(0.0) as i32, deductions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(text: &str) -> Vec<Nominalization> {
+        find_nominalizations(&[text.to_string()])
+    }
+
+    #[test]
+    fn light_verb_constructions_detected() {
+        let found = find("We will perform an analysis of the logs and make a decision about it.");
+        let pairs: Vec<_> = found
+            .iter()
+            .map(|n| (n.phrase.as_str(), n.verb.as_deref()))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![
+                ("perform an analysis of", Some("analyze")),
+                ("make a decision about", Some("decide")),
+            ]
+        );
+    }
+
+    #[test]
+    fn listed_nouns_get_their_verbs() {
+        let cases = [
+            (
+                "They conducted an assessment of risk.",
+                "assessment",
+                "assess",
+            ),
+            (
+                "She gave a presentation to the team.",
+                "presentation",
+                "present",
+            ),
+            (
+                "Make a calculation and check it.",
+                "calculation",
+                "calculate",
+            ),
+            (
+                "We made an optimization to the loop.",
+                "optimization",
+                "optimize",
+            ),
+            ("He gave a final confirmation.", "confirmation", "confirm"),
+            (
+                "They performed a careful synthesis.",
+                "synthesis",
+                "synthesize",
+            ),
+        ];
+        for (text, noun, verb) in cases {
+            let found = find(text);
+            assert_eq!(found.len(), 1, "{text}");
+            assert_eq!(found[0].noun, noun);
+            assert_eq!(found[0].verb.as_deref(), Some(verb), "{text}");
+        }
+    }
+
+    #[test]
+    fn suggested_verbs_are_real_words() {
+        let cases = [
+            ("experiment", "experiment"),
+            ("maintenance", "maintain"),
+            ("observation", "observe"),
+            ("reservation", "reserve"),
+            ("explanation", "explain"),
+            ("declaration", "declare"),
+            ("examination", "examine"),
+            ("conclusion", "conclude"),
+            ("extension", "extend"),
+            ("permission", "permit"),
+        ];
+        for (noun, verb) in cases {
+            let found = find(&format!("They made an {noun} about it."));
+            assert_eq!(found.len(), 1, "{noun}");
+            assert_eq!(found[0].noun, noun);
+            assert_eq!(found[0].verb.as_deref(), Some(verb), "{noun}");
+        }
     }
 
-    // Vague words: −0.5 per word, max −10
-    score -= (diction.total_vague as f64 * 0.5).min(10.0);
+    #[test]
+    fn unlisted_nouns_reported_without_a_verb() {
+        let found = find("They performed a rotation of the keys.");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].noun, "rotation");
+        assert_eq!(found[0].verb, None);
+    }
+
+    #[test]
+    fn modifier_is_not_taken_for_the_noun() {
+        assert!(find("They performed a security review of the code.").is_empty());
+        let found = find("We performed a security assessment of the code.");
+        assert_eq!(found[0].noun, "assessment");
+    }
+
+    #[test]
+    fn plural_nouns_detected() {
+        let found = find("We made improvements to the parser.");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].phrase, "made improvements to");
+        assert_eq!(found[0].noun, "improvement");
+        assert_eq!(found[0].verb.as_deref(), Some("improve"));
+    }
+
+    #[test]
+    fn ordinary_nouns_and_other_verbs_ignored() {
+        assert!(find("They gave the community a voice.").is_empty());
+        assert!(find("We reviewed the implementation.").is_empty());
+    }
+
+    #[test]
+    fn built_in_formula_matches_documented_weights() {
+        let measured = BTreeMap::from([
+            (StyleComponent::Passive, 3.0),
+            (StyleComponent::Adverbs, 40.0),
+            (StyleComponent::Glue, 30.0),
+        ]);
+        let (score, deductions) = calculate_style_score(&measured, &StyleScoreConfig::default());
+        // 100 − 6 (passive) − 15 (adverbs, capped) − 5 (glue over 25%)
+        assert_eq!(score, 74);
+        let glue = deductions
+            .iter()
+            .find(|d| d.component == StyleComponent::Glue)
+            .unwrap();
+        assert!((glue.points - 5.0).abs() < f64::EPSILON);
+    }
 
-    score.max(0.0) as i32
+    #[test]
+    fn custom_formula_replaces_components() {
+        let formula: StyleScoreConfig = serde_json::from_str(
+            r#"{"cliches": {"weight": 5, "max": 20}, "adverbs": {"weight": 0}}"#,
+        )
+        .unwrap();
+        let measured = BTreeMap::from([
+            (StyleComponent::Passive, 10.0),
+            (StyleComponent::Adverbs, 10.0),
+            (StyleComponent::Cliches, 2.0),
+        ]);
+        let (score, deductions) = calculate_style_score(&measured, &formula);
+        assert_eq!(score, 90);
+        assert_eq!(deductions.len(), 2, "passive is not in the formula");
+    }
+
+    #[test]
+    fn located_by_sentence() {
+        let sentences = vec![
+            "The logs are clean.".to_string(),
+            "Now we make an improvement to them.".to_string(),
+        ];
+        let found = find_nominalizations(&sentences);
+        assert_eq!(found[0].sentence_num, 2);
+        assert_eq!(found[0].verb.as_deref(), Some("improve"));
+    }
 }
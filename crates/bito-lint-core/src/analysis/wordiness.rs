@@ -0,0 +1,209 @@
+//! Wordy phrase and redundancy detection.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::markdown;
+use crate::tokens::{self, Backend};
+use crate::word_lists::WORDY_PHRASES;
+
+use super::reports::{WordinessReport, WordyPhrase};
+
+/// Case-insensitive regex matching any wordy phrase as whole words.
+///
+/// Longer phrases come first so "in close proximity to" wins over
+/// "close proximity".
+pub(crate) static WORDY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    let mut phrases: Vec<&str> = WORDY_PHRASES.keys().copied().collect();
+    phrases.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+    let alternation = phrases
+        .iter()
+        .map(|p| regex::escape(p))
+        .collect::<Vec<_>>()
+        .join("|");
+    Regex::new(&format!(r"(?i)\b(?:{alternation})\b")).expect("valid regex")
+});
+
+/// Detect wordy phrases and suggest concise replacements, located by sentence.
+#[tracing::instrument(skip_all)]
+pub fn analyze_wordiness(sentences: &[String]) -> WordinessReport {
+    let mut phrases = Vec::new();
+
+    for (i, sentence) in sentences.iter().enumerate() {
+        for m in WORDY_RE.find_iter(sentence) {
+            let phrase = m.as_str();
+            let Some(&replacement) = WORDY_PHRASES.get(phrase.to_lowercase().as_str()) else {
+                continue;
+            };
+            let replacement = match_capitalization(phrase, replacement);
+            let words_saved = phrase
+                .split_whitespace()
+                .count()
+                .saturating_sub(replacement.split_whitespace().count());
+            let tokens_saved =
+                estimate_tokens(phrase).saturating_sub(estimate_tokens(&replacement));
+            phrases.push(WordyPhrase {
+                phrase: phrase.to_string(),
+                replacement,
+                sentence_num: i + 1,
+                start: None,
+                end: None,
+                line: None,
+                words_saved,
+                tokens_saved,
+            });
+        }
+    }
+
+    WordinessReport {
+        total_wordy: phrases.len(),
+        words_saved: phrases.iter().map(|p| p.words_saved).sum(),
+        tokens_saved: phrases.iter().map(|p| p.tokens_saved).sum(),
+        phrases,
+    }
+}
+
+/// Fill in where each phrase appears in `input`, the text its sentences
+/// came from.
+///
+/// Phrases are matched in document order against the prose of `input`: the
+/// ranges [`markdown::prose_ranges`] finds with `strip_md`, otherwise all of
+/// it. A phrase that cannot be matched is left unlocated.
+pub fn locate_phrases(phrases: &mut [WordyPhrase], input: &str, strip_md: bool) {
+    let segments = if strip_md {
+        markdown::prose_ranges(input)
+    } else {
+        std::iter::once(0..input.len()).collect()
+    };
+    let spans: Vec<(usize, usize)> = segments
+        .into_iter()
+        .flat_map(|segment| {
+            let base = segment.start;
+            WORDY_RE
+                .find_iter(&input[segment])
+                .map(move |m| (base + m.start(), base + m.end()))
+        })
+        .collect();
+
+    let mut next = 0;
+    for phrase in phrases {
+        let Some(offset) = spans[next..]
+            .iter()
+            .position(|&(start, end)| input[start..end] == phrase.phrase)
+        else {
+            continue;
+        };
+        let (start, end) = spans[next + offset];
+        next += offset + 1;
+        phrase.start = Some(start);
+        phrase.end = Some(end);
+        phrase.line = Some(input[..start].matches('\n').count() + 1);
+    }
+}
+
+/// Capitalize `replacement` if `phrase` starts with a capital letter.
+pub(crate) fn match_capitalization(phrase: &str, replacement: &str) -> String {
+    if !phrase.starts_with(|c: char| c.is_uppercase()) {
+        return replacement.to_string();
+    }
+    let mut chars = replacement.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+/// Token count of a short phrase with the default tokenizer.
+fn estimate_tokens(text: &str) -> usize {
+    tokens::count_tokens(text, None, Backend::default()).map_or(0, |r| r.count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn analyze(text: &[&str]) -> WordinessReport {
+        let sentences: Vec<String> = text.iter().map(|s| (*s).to_string()).collect();
+        analyze_wordiness(&sentences)
+    }
+
+    #[test]
+    fn no_wordy_phrases() {
+        let report = analyze(&["We cache results to save time."]);
+        assert_eq!(report.total_wordy, 0);
+        assert_eq!(report.words_saved, 0);
+    }
+
+    #[test]
+    fn suggests_replacements_with_location() {
+        let report = analyze(&[
+            "The service restarts.",
+            "We retry in order to recover due to the fact that networks fail.",
+        ]);
+        let found: Vec<_> = report
+            .phrases
+            .iter()
+            .map(|p| (p.phrase.as_str(), p.replacement.as_str(), p.sentence_num))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("in order to", "to", 2),
+                ("due to the fact that", "because", 2)
+            ]
+        );
+        assert_eq!(report.words_saved, 2 + 4);
+        assert!(report.tokens_saved > 0);
+    }
+
+    #[test]
+    fn locates_phrases_in_markdown_source() {
+        let input = "# Notes\n\nWe retry `in order to` recover.\nWe retry in order to recover,\n\
+                     due to the fact that networks fail.";
+        let prose = markdown::strip_to_prose(input);
+        let mut report = analyze_wordiness(&crate::text::split_sentences(&prose));
+        locate_phrases(&mut report.phrases, input, true);
+        let located: Vec<_> = report
+            .phrases
+            .iter()
+            .map(|p| (&input[p.start.unwrap()..p.end.unwrap()], p.line.unwrap()))
+            .collect();
+        assert_eq!(
+            located,
+            vec![("in order to", 4), ("due to the fact that", 5)]
+        );
+    }
+
+    #[test]
+    fn unmatched_phrase_left_unlocated() {
+        let mut report = analyze(&["We retry in order to recover."]);
+        locate_phrases(&mut report.phrases, "We retry in *order* to recover.", true);
+        assert_eq!(report.phrases[0].line, None);
+        assert_eq!(
+            (report.phrases[0].start, report.phrases[0].end),
+            (None, None)
+        );
+    }
+
+    #[test]
+    fn keeps_leading_capital() {
+        let report = analyze(&["At this point in time, the cache is cold."]);
+        assert_eq!(report.phrases[0].phrase, "At this point in time");
+        assert_eq!(report.phrases[0].replacement, "Now");
+    }
+
+    #[test]
+    fn redundancies_detected() {
+        let report = analyze(&["Review the past history before you revert back."]);
+        assert_eq!(report.total_wordy, 2);
+        assert_eq!(report.phrases[0].replacement, "history");
+        assert_eq!(report.phrases[1].replacement, "revert");
+    }
+
+    #[test]
+    fn longest_phrase_wins() {
+        let report = analyze(&["Keep it in close proximity to the server."]);
+        assert_eq!(report.total_wordy, 1);
+        assert_eq!(report.phrases[0].replacement, "near");
+    }
+}
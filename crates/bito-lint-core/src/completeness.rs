@@ -3,12 +3,35 @@
 //! Validates that a markdown document contains all required sections for a
 //! given template type (ADR, handoff, design-doc) and that those sections
 //! have substantive content (not just placeholders).
+//!
+//! Templates come from three places: the built-ins, flat lists of sections
+//! in config, and markdown skeleton files. Skeletons are parsed into
+//! the same [`Template`] structure, with heading levels, nesting, order,
+//! `(optional)` markers, and frontmatter keys taken from the skeleton itself.
+//! A template can additionally enforce section order, heading levels, and
+//! parent/child nesting; violations are reported per section.
+//!
+//! Sections match headings by case-insensitive substring of the section name
+//! or any alias, or by a regex pattern. In fuzzy mode, a missing section
+//! whose name closely resembles an unclaimed heading is reported as a near
+//! miss rather than simply missing. Fuzzy mode is opt-in for custom and
+//! built-in templates alike.
+//!
+//! Sections can also demand minimum content -- a word count, a number of
+//! list items, or a table, code block, or link -- so a one-line
+//! "Consequences" section does not pass.
 
 use std::collections::HashMap;
 
+use pulldown_cmark::{Event, Options, Parser, Tag};
+use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::config::{
+    SectionRequirements, TemplateConfig, TemplateSectionConfig, TemplateSectionEntry,
+    TemplateSkeleton,
+};
 use crate::error::{AnalysisError, AnalysisResult};
 use crate::markdown;
 
@@ -45,30 +68,355 @@ const TEMPLATES: &[(&str, &[&str])] = &[
     ),
 ];
 
+/// Other headings accepted for built-in template sections, as
+/// `(template, section, aliases)`.
+const BUILTIN_ALIASES: &[(&str, &str, &[&str])] = &[(
+    "adr",
+    "Context and Problem Statement",
+    &["Context", "Background"],
+)];
+
 /// Placeholder patterns that indicate a section hasn't been filled in.
 const PLACEHOLDER_PATTERNS: &[&str] = &["tbd", "todo", "n/a", "...", "\u{2014}", "placeholder"];
 
+/// Heading suffix that marks a skeleton section as optional.
+const OPTIONAL_MARKER: &str = "(optional)";
+
+/// Minimum normalized similarity for a heading to count as a near miss.
+const NEAR_MISS_THRESHOLD: f64 = 0.8;
+
+/// A resolved completeness template.
+#[derive(Debug, Clone, Default)]
+pub struct Template {
+    /// Sections in document order.
+    pub sections: Vec<TemplateSection>,
+    /// Frontmatter keys every document must define.
+    pub frontmatter: Vec<String>,
+    /// Report sections that appear out of template order.
+    pub enforce_order: bool,
+    /// Report sections whose heading level differs from the template.
+    pub enforce_levels: bool,
+    /// Report sections that are not under their template parent.
+    pub enforce_nesting: bool,
+    /// Report near-miss headings for missing sections.
+    pub fuzzy: bool,
+}
+
+/// A section defined by a template.
+#[derive(Debug, Clone)]
+pub struct TemplateSection {
+    /// Section heading text (without any optional marker).
+    pub name: String,
+    /// Heading level (2--6).
+    pub level: u8,
+    /// Name of the enclosing section, if nested.
+    pub parent: Option<String>,
+    /// Whether the section must be present for the document to pass.
+    pub required: bool,
+    /// Alternative heading texts accepted for the section.
+    pub aliases: Vec<String>,
+    /// Patterns that accept a heading when they match its text.
+    pub patterns: Vec<Regex>,
+    /// Minimum content the section must contain.
+    pub content: SectionRequirements,
+}
+
+impl TemplateSection {
+    const fn new(name: String, level: u8, parent: Option<String>, required: bool) -> Self {
+        Self {
+            name,
+            level,
+            parent,
+            required,
+            aliases: Vec::new(),
+            patterns: Vec::new(),
+            content: SectionRequirements {
+                min_words: None,
+                min_list_items: None,
+                require_table: false,
+                require_code_block: false,
+                require_link: false,
+            },
+        }
+    }
+
+    /// Whether a document heading satisfies this section.
+    ///
+    /// Name and aliases match as case-insensitive substrings; patterns
+    /// match as written (use `(?i)` for case-insensitive patterns).
+    pub fn matches(&self, heading_text: &str) -> bool {
+        let heading_lower = heading_text.to_lowercase();
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .any(|name| heading_lower.contains(&name.to_lowercase()))
+            || self.patterns.iter().any(|re| re.is_match(heading_text))
+    }
+
+    /// Apply a section's aliases, patterns, and content requirements.
+    ///
+    /// Fails with a reason if a pattern is not a valid regex.
+    fn configure(&mut self, options: &TemplateSectionConfig) -> Result<(), String> {
+        self.aliases.clone_from(&options.aliases);
+        self.content.clone_from(&options.content);
+        self.patterns = options
+            .patterns
+            .iter()
+            .map(|p| Regex::new(p).map_err(|e| format!("bad pattern '{p}': {e}")))
+            .collect::<Result<_, _>>()?;
+        Ok(())
+    }
+
+    /// Similarity of a heading to the section name or its closest alias (0.0--1.0).
+    fn similarity(&self, heading_text: &str) -> f64 {
+        let heading_lower = heading_text.trim().to_lowercase();
+        std::iter::once(&self.name)
+            .chain(&self.aliases)
+            .map(|name| {
+                strsim::normalized_damerau_levenshtein(&heading_lower, &name.to_lowercase())
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Template {
+    /// Build a template from a flat list of required level-2 section names.
+    pub fn from_sections<S: AsRef<str>>(names: &[S]) -> Self {
+        Self {
+            sections: names
+                .iter()
+                .map(|name| TemplateSection::new(name.as_ref().to_string(), 2, None, true))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Build a template from a flat config list of level-2 sections, each
+    /// a name or a name with matching options.
+    ///
+    /// Fails if a pattern is not a valid regex.
+    pub fn from_entries(name: &str, entries: &[TemplateSectionEntry]) -> AnalysisResult<Self> {
+        let mut template = Self::from_sections(
+            &entries
+                .iter()
+                .map(TemplateSectionEntry::name)
+                .collect::<Vec<_>>(),
+        );
+        for (section, entry) in template.sections.iter_mut().zip(entries) {
+            if let TemplateSectionEntry::Detailed { options, .. } = entry {
+                section
+                    .configure(options)
+                    .map_err(|reason| AnalysisError::InvalidTemplate {
+                        name: name.to_string(),
+                        reason,
+                    })?;
+            }
+        }
+        Ok(template)
+    }
+
+    /// A built-in template with its section aliases, if `name` is one.
+    ///
+    /// With `fuzzy`, the template reports near-miss headings.
+    pub fn builtin(name: &str, fuzzy: bool) -> Option<Self> {
+        let mut template = Self::from_sections(builtin_sections(name)?);
+        template.fuzzy = fuzzy;
+        for (_, section_name, aliases) in BUILTIN_ALIASES.iter().filter(|(t, ..)| *t == name) {
+            if let Some(section) = template
+                .sections
+                .iter_mut()
+                .find(|s| s.name == *section_name)
+            {
+                section.aliases = aliases.iter().map(|a| (*a).to_string()).collect();
+            }
+        }
+        Some(template)
+    }
+
+    /// Derive a template from a markdown skeleton document.
+    ///
+    /// Every heading below level 1 becomes a section (the H1 is treated as
+    /// the document title). A heading ending in `(optional)` is optional;
+    /// all others are required. Each section's parent is the nearest
+    /// preceding heading with a lower level. Top-level frontmatter keys in
+    /// the skeleton become required keys.
+    pub fn from_skeleton(markdown_text: &str) -> AnalysisResult<Self> {
+        let frontmatter = markdown::parse_frontmatter(markdown_text)?
+            .map(|map| map.keys().cloned().collect())
+            .unwrap_or_default();
+
+        let mut sections = Vec::new();
+        // Stack of (level, name) for the currently open headings.
+        let mut open: Vec<(u8, String)> = Vec::new();
+
+        for (level, text) in markdown::extract_headings(markdown_text) {
+            if level < 2 {
+                continue;
+            }
+            let (name, required) = strip_optional_marker(&text);
+            while open.last().is_some_and(|(l, _)| *l >= level) {
+                open.pop();
+            }
+            let parent = open.last().map(|(_, n)| n.clone());
+            open.push((level, name.clone()));
+            sections.push(TemplateSection::new(name, level, parent, required));
+        }
+
+        Ok(Self {
+            sections,
+            frontmatter,
+            ..Default::default()
+        })
+    }
+
+    /// Build a template from a skeleton config and the skeleton's text.
+    ///
+    /// Applies the structural flags and per-section aliases, patterns, and
+    /// content requirements.
+    /// Fails if a per-section entry names a section the skeleton lacks or
+    /// a pattern is not a valid regex.
+    pub fn from_skeleton_config(
+        name: &str,
+        skeleton: &TemplateSkeleton,
+        markdown_text: &str,
+    ) -> AnalysisResult<Self> {
+        let invalid = |reason: String| AnalysisError::InvalidTemplate {
+            name: name.to_string(),
+            reason,
+        };
+
+        let mut template = Self::from_skeleton(markdown_text)?;
+        template.enforce_order = skeleton.enforce_order;
+        template.enforce_levels = skeleton.enforce_levels;
+        template.enforce_nesting = skeleton.enforce_nesting;
+        template.fuzzy = skeleton.fuzzy;
+
+        for (section_name, options) in &skeleton.sections {
+            let section = template
+                .sections
+                .iter_mut()
+                .find(|s| s.name.eq_ignore_ascii_case(section_name))
+                .ok_or_else(|| invalid(format!("skeleton has no section '{section_name}'")))?;
+            section.configure(options).map_err(invalid)?;
+        }
+
+        Ok(template)
+    }
+}
+
+/// Instructions for drafting a document that satisfies `template`.
+///
+/// Lists each section as a heading outline, marking optional sections and
+/// any minimum content, followed by required frontmatter keys and the
+/// structural rules the template enforces.
+pub fn draft_instructions(name: &str, template: &Template) -> String {
+    let mut out = format!(
+        "Draft a markdown document that passes the \"{name}\" completeness template.\n\n\
+         Use these section headings, each with substantive content (no TBD, TODO, or \
+         other placeholders):\n\n"
+    );
+    for section in &template.sections {
+        let indent = "  ".repeat(usize::from(section.level.saturating_sub(2)));
+        let hashes = "#".repeat(usize::from(section.level));
+        out.push_str(&format!("{indent}- `{hashes} {}`", section.name));
+        let mut notes = Vec::new();
+        if !section.required {
+            notes.push("optional".to_string());
+        }
+        let content = &section.content;
+        if let Some(n) = content.min_words {
+            notes.push(format!("at least {n} words"));
+        }
+        if let Some(n) = content.min_list_items {
+            notes.push(format!("at least {n} list items"));
+        }
+        for (required, what) in [
+            (content.require_table, "a table"),
+            (content.require_code_block, "a code block"),
+            (content.require_link, "a link"),
+        ] {
+            if required {
+                notes.push(format!("include {what}"));
+            }
+        }
+        if !notes.is_empty() {
+            out.push_str(&format!(" ({})", notes.join("; ")));
+        }
+        out.push('\n');
+    }
+    if !template.frontmatter.is_empty() {
+        out.push_str(&format!(
+            "\nStart with YAML frontmatter defining: {}.\n",
+            template.frontmatter.join(", ")
+        ));
+    }
+    let rules: Vec<&str> = [
+        (template.enforce_order, "keep sections in the order listed"),
+        (template.enforce_levels, "use the heading levels shown"),
+        (
+            template.enforce_nesting,
+            "keep nested sections under their parent",
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(on, rule)| on.then_some(rule))
+    .collect();
+    if !rules.is_empty() {
+        out.push_str(&format!("\nStructure: {}.\n", rules.join("; ")));
+    }
+    out
+}
+
+/// Section names of a built-in template, if `name` is one.
+pub fn builtin_sections(name: &str) -> Option<&'static [&'static str]> {
+    TEMPLATES
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, sections)| *sections)
+}
+
+/// Split a trailing `(optional)` marker off a skeleton heading.
+///
+/// Returns the cleaned heading name and whether the section is required.
+pub(crate) fn strip_optional_marker(heading: &str) -> (String, bool) {
+    let trimmed = heading.trim();
+    let lower = trimmed.to_lowercase();
+    if lower.ends_with(OPTIONAL_MARKER) {
+        let name = trimmed[..trimmed.len() - OPTIONAL_MARKER.len()].trim_end();
+        (name.to_string(), false)
+    } else {
+        (trimmed.to_string(), true)
+    }
+}
+
 /// Result of completeness checking.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CompletenessReport {
     /// Template type that was checked.
     pub template: String,
-    /// Status of each required section.
+    /// Status of each template section.
     pub sections: Vec<SectionResult>,
-    /// Whether all sections passed.
+    /// Required frontmatter keys missing from the document.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_frontmatter: Vec<String>,
+    /// Whether all required sections and frontmatter keys passed.
     pub pass: bool,
 }
 
-/// Status of a single required section.
+/// Status of a single template section.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SectionResult {
     /// Section name as defined in the template.
     pub name: String,
+    /// Whether the template requires this section.
+    pub required: bool,
     /// Whether the section was found and has content.
     pub status: SectionStatus,
+    /// Explanation for a structural problem, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
 }
 
-/// Possible states for a required section.
+/// Possible states for a template section.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum SectionStatus {
@@ -78,6 +426,30 @@ pub enum SectionStatus {
     Empty,
     /// Section heading was not found.
     Missing,
+    /// Section appears before a section the template places ahead of it.
+    #[serde(rename = "out_of_order")]
+    OutOfOrder,
+    /// Section heading has the wrong level or sits under the wrong parent.
+    #[serde(rename = "wrong_level")]
+    WrongLevel,
+    /// Section heading was not found, but a similar heading was.
+    #[serde(rename = "near_miss")]
+    NearMiss,
+    /// Section has fewer words than the template requires.
+    #[serde(rename = "too_short")]
+    TooShort,
+    /// Section has fewer list items than the template requires.
+    #[serde(rename = "too_few_list_items")]
+    TooFewListItems,
+    /// Section lacks a required table.
+    #[serde(rename = "missing_table")]
+    MissingTable,
+    /// Section lacks a required code block.
+    #[serde(rename = "missing_code_block")]
+    MissingCodeBlock,
+    /// Section lacks a required link.
+    #[serde(rename = "missing_link")]
+    MissingLink,
 }
 
 /// Check that a document has all required sections for a template.
@@ -91,37 +463,85 @@ pub enum SectionStatus {
 /// * `template` — Template name: `"adr"`, `"handoff"`, `"design-doc"`, or a custom name.
 /// * `custom_templates` — Optional map of custom template definitions. Custom
 ///   templates take precedence over built-ins if names collide.
+/// * `fuzzy_builtins` — Report near-miss headings for a built-in template.
 #[tracing::instrument(skip(text, custom_templates), fields(text_len = text.len(), template))]
 pub fn check_completeness(
     text: &str,
     template: &str,
-    custom_templates: Option<&HashMap<String, Vec<String>>>,
+    custom_templates: Option<&HashMap<String, TemplateConfig>>,
+    fuzzy_builtins: bool,
 ) -> AnalysisResult<CompletenessReport> {
-    let required = find_template(template, custom_templates)?;
+    let definition = load_template(template, custom_templates, fuzzy_builtins)?;
+    Ok(check_template(text, template, &definition))
+}
+
+/// Check a document against an already-resolved template.
+pub fn check_template(text: &str, name: &str, template: &Template) -> CompletenessReport {
     let headings = markdown::extract_headings(text);
 
-    let sections: Vec<SectionResult> = required
+    let positions: Vec<Option<usize>> = template
+        .sections
+        .iter()
+        .map(|section| find_section_heading(section, &headings, template))
+        .collect();
+    let near_misses = if template.fuzzy {
+        near_misses(&template.sections, &positions, &headings)
+    } else {
+        vec![None; positions.len()]
+    };
+    let misplaced = if template.enforce_order {
+        order_problems(&template.sections, &positions)
+    } else {
+        vec![None; positions.len()]
+    };
+
+    let sections: Vec<SectionResult> = template
+        .sections
         .iter()
-        .map(|section_name| {
-            let status = check_section(text, section_name, &headings);
+        .zip(positions)
+        .zip(misplaced.into_iter().zip(near_misses))
+        .map(|((section, position), (misplaced, near_miss))| {
+            let (status, detail) = match (position, near_miss) {
+                (Some(idx), _) => {
+                    placed_section_status(text, section, &headings, idx, template, misplaced)
+                }
+                (None, Some(heading)) => (
+                    SectionStatus::NearMiss,
+                    Some(format!(
+                        "found '{heading}'; did you mean '{}'?",
+                        section.name
+                    )),
+                ),
+                (None, None) => (SectionStatus::Missing, None),
+            };
             SectionResult {
-                name: section_name.to_string(),
+                name: section.name.clone(),
+                required: section.required,
                 status,
+                detail,
             }
         })
         .collect();
 
-    let pass = sections.iter().all(|s| s.status == SectionStatus::Present);
+    let missing_frontmatter = missing_frontmatter_keys(text, &template.frontmatter);
+
+    let pass = missing_frontmatter.is_empty()
+        && sections
+            .iter()
+            .all(|s| !s.required || s.status == SectionStatus::Present);
 
-    Ok(CompletenessReport {
-        template: template.to_string(),
+    CompletenessReport {
+        template: name.to_string(),
         sections,
+        missing_frontmatter,
         pass,
-    })
+    }
 }
 
 /// List available template names, including any custom templates.
-pub fn available_templates(custom_templates: Option<&HashMap<String, Vec<String>>>) -> Vec<String> {
+pub fn available_templates(
+    custom_templates: Option<&HashMap<String, TemplateConfig>>,
+) -> Vec<String> {
     let mut names: Vec<String> = TEMPLATES
         .iter()
         .map(|(name, _)| (*name).to_string())
@@ -136,64 +556,344 @@ pub fn available_templates(custom_templates: Option<&HashMap<String, Vec<String>
     names
 }
 
-/// Look up required sections for a template name.
+/// Resolve a template name to its definition.
 ///
 /// Custom templates take precedence over built-ins if names collide.
-fn find_template<'a>(
+/// Skeleton templates are read from disk on each call. With
+/// `fuzzy_builtins`, a built-in template reports near-miss headings.
+pub fn load_template(
     name: &str,
-    custom_templates: Option<&'a HashMap<String, Vec<String>>>,
-) -> AnalysisResult<Vec<&'a str>>
-where
-    'static: 'a,
-{
+    custom_templates: Option<&HashMap<String, TemplateConfig>>,
+    fuzzy_builtins: bool,
+) -> AnalysisResult<Template> {
     // Check custom templates first
     if let Some(custom) = custom_templates
-        && let Some(sections) = custom.get(name)
+        && let Some(definition) = custom.get(name)
     {
-        return Ok(sections.iter().map(String::as_str).collect());
+        return match definition {
+            TemplateConfig::Sections(sections) => Template::from_entries(name, sections),
+            TemplateConfig::Skeleton(skeleton) => {
+                let markdown_text =
+                    std::fs::read_to_string(skeleton.file.as_std_path()).map_err(|e| {
+                        AnalysisError::TemplateFile {
+                            path: skeleton.file.clone(),
+                            source: e,
+                        }
+                    })?;
+                Template::from_skeleton_config(name, skeleton, &markdown_text)
+            }
+        };
     }
 
     // Fall back to built-in templates
-    TEMPLATES
+    Template::builtin(name, fuzzy_builtins).ok_or_else(|| {
+        let available = available_templates(custom_templates).join(", ");
+        AnalysisError::UnknownTemplate {
+            name: name.to_string(),
+            available,
+        }
+    })
+}
+
+/// Return the required frontmatter keys that the document does not define.
+///
+/// A document with unparseable frontmatter is treated as defining no keys.
+fn missing_frontmatter_keys(text: &str, required: &[String]) -> Vec<String> {
+    if required.is_empty() {
+        return Vec::new();
+    }
+    let present = markdown::parse_frontmatter(text)
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    required
         .iter()
-        .find(|(n, _)| *n == name)
-        .map(|(_, sections)| sections.iter().map(|s| *s as &str).collect())
-        .ok_or_else(|| {
-            let available = available_templates(custom_templates).join(", ");
-            AnalysisError::UnknownTemplate {
-                name: name.to_string(),
-                available,
+        .filter(|key| !present.contains_key(key.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Find the document heading that satisfies a template section.
+///
+/// Returns the heading's index in `headings`. Matching is a case-insensitive
+/// substring match. Without structural enforcement only levels 2 through
+/// 3 (or the section's own level, if deeper) are considered; with it, any
+/// level counts so a misplaced heading is reported as such rather than
+/// as missing.
+fn find_section_heading(
+    section: &TemplateSection,
+    headings: &[(u8, String)],
+    template: &Template,
+) -> Option<usize> {
+    let max_level = if template.enforce_levels || template.enforce_nesting {
+        6
+    } else {
+        section.level.max(3)
+    };
+    headings.iter().position(|(level, heading_text)| {
+        (2..=max_level).contains(level) && section.matches(heading_text)
+    })
+}
+
+/// Find near-miss headings for sections that did not match.
+///
+/// Only headings no section claimed are candidates, and each candidate is
+/// offered to at most one section. Returns the heading text per section
+/// (`None` when the section matched or nothing came close).
+fn near_misses(
+    sections: &[TemplateSection],
+    positions: &[Option<usize>],
+    headings: &[(u8, String)],
+) -> Vec<Option<String>> {
+    let mut claimed: Vec<bool> = (0..headings.len())
+        .map(|i| headings[i].0 < 2 || positions.contains(&Some(i)))
+        .collect();
+
+    sections
+        .iter()
+        .zip(positions)
+        .map(|(section, position)| {
+            if position.is_some() {
+                return None;
             }
+            let (idx, score) = headings
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !claimed[*i])
+                .map(|(i, (_, text))| (i, section.similarity(text)))
+                .max_by(|a, b| a.1.total_cmp(&b.1))?;
+            (score >= NEAR_MISS_THRESHOLD).then(|| {
+                claimed[idx] = true;
+                headings[idx].1.clone()
+            })
         })
+        .collect()
 }
 
-/// Check whether a specific section exists and has content.
-fn check_section(text: &str, section_name: &str, headings: &[(u8, String)]) -> SectionStatus {
-    let section_lower = section_name.to_lowercase();
+/// Status of a section whose heading was found at `headings[idx]`.
+///
+/// Structural problems take precedence over ordering, which takes
+/// precedence over content.
+fn placed_section_status(
+    text: &str,
+    section: &TemplateSection,
+    headings: &[(u8, String)],
+    idx: usize,
+    template: &Template,
+    misplaced: Option<String>,
+) -> (SectionStatus, Option<String>) {
+    if let Some(detail) = structure_problem(section, headings, idx, template) {
+        return (SectionStatus::WrongLevel, Some(detail));
+    }
+    if misplaced.is_some() {
+        return (SectionStatus::OutOfOrder, misplaced);
+    }
+    check_section_content(text, section, &headings[idx])
+}
 
-    // Find matching heading (level 2 or 3)
-    let matching_heading = headings.iter().find(|(level, heading_text)| {
-        (*level == 2 || *level == 3) && heading_text.to_lowercase().contains(&section_lower)
-    });
+/// Find sections that appear out of template order.
+///
+/// The longest run of found sections whose document positions increase in
+/// template order is treated as correctly placed; every other found section
+/// is out of order. This flags the one heading that moved rather than
+/// everything after it. Returns a description per section (`None` when the
+/// section is in order or missing).
+fn order_problems(
+    sections: &[TemplateSection],
+    positions: &[Option<usize>],
+) -> Vec<Option<String>> {
+    let found: Vec<(usize, usize)> = positions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, pos)| pos.map(|p| (i, p)))
+        .collect();
 
-    let Some((level, matched_text)) = matching_heading else {
-        return SectionStatus::Missing;
-    };
+    // Longest increasing subsequence over document positions (O(n^2) is
+    // fine for template-sized inputs).
+    let mut length = vec![1usize; found.len()];
+    let mut prev: Vec<Option<usize>> = vec![None; found.len()];
+    for j in 0..found.len() {
+        for i in 0..j {
+            if found[i].1 < found[j].1 && length[i] + 1 > length[j] {
+                length[j] = length[i] + 1;
+                prev[j] = Some(i);
+            }
+        }
+    }
+    let mut in_order = vec![false; sections.len()];
+    let mut cursor = (0..found.len()).max_by_key(|&j| (length[j], std::cmp::Reverse(j)));
+    while let Some(j) = cursor {
+        in_order[found[j].0] = true;
+        cursor = prev[j];
+    }
+
+    let mut problems = vec![None; sections.len()];
+    for &(i, pos) in &found {
+        if in_order[i] {
+            continue;
+        }
+        let next = found
+            .iter()
+            .find(|&&(k, p)| k > i && in_order[k] && p < pos)
+            .map(|&(k, _)| &sections[k].name);
+        let before = found
+            .iter()
+            .rev()
+            .find(|&&(k, p)| k < i && in_order[k] && p > pos)
+            .map(|&(k, _)| &sections[k].name);
+        problems[i] = Some(match (next, before) {
+            (Some(next), _) => format!("expected before '{next}'"),
+            (None, Some(before)) => format!("expected after '{before}'"),
+            (None, None) => "out of template order".to_string(),
+        });
+    }
+    problems
+}
+
+/// Describe a level or nesting violation for the heading at `idx`, if any.
+fn structure_problem(
+    section: &TemplateSection,
+    headings: &[(u8, String)],
+    idx: usize,
+    template: &Template,
+) -> Option<String> {
+    let level = headings[idx].0;
+
+    if template.enforce_levels && level != section.level {
+        return Some(format!(
+            "found at level {level}, expected level {}",
+            section.level
+        ));
+    }
+
+    if template.enforce_nesting {
+        // Nearest preceding heading with a lower level, ignoring the H1 title.
+        let enclosing = headings[..idx]
+            .iter()
+            .rev()
+            .find(|(l, _)| *l < level)
+            .filter(|(l, _)| *l >= 2);
+        let parent_matches = |text: &str, parent: &str| {
+            template
+                .sections
+                .iter()
+                .find(|s| s.name == parent)
+                .is_some_and(|s| s.matches(text))
+        };
+        match (&section.parent, enclosing) {
+            (Some(parent), Some((_, text))) if parent_matches(text, parent) => {}
+            (Some(parent), _) => return Some(format!("expected under '{parent}'")),
+            (None, Some((_, text))) => {
+                return Some(format!("expected at top level, found under '{text}'"));
+            }
+            (None, None) => {}
+        }
+    }
+
+    None
+}
+
+/// Check whether a matched section heading is followed by real content.
+fn check_section_content(
+    text: &str,
+    section: &TemplateSection,
+    heading: &(u8, String),
+) -> (SectionStatus, Option<String>) {
+    let (level, matched_text) = heading;
 
     // Extract content between this heading and the next heading of same or higher level
     let content = extract_section_content(text, matched_text, *level);
 
     if content.trim().is_empty() {
-        return SectionStatus::Empty;
+        return (SectionStatus::Empty, None);
     }
 
     // Check for placeholder-only content
     let normalized = content.trim().to_lowercase();
     if PLACEHOLDER_PATTERNS.iter().any(|p| normalized == *p) {
-        return SectionStatus::Empty;
+        return (SectionStatus::Empty, None);
     }
 
-    SectionStatus::Present
+    check_requirements(&content, &section.content)
+}
+
+/// Block-level counts for a section body.
+#[derive(Debug, Default)]
+struct ContentStats {
+    words: usize,
+    list_items: usize,
+    tables: usize,
+    code_blocks: usize,
+    links: usize,
+}
+
+impl ContentStats {
+    fn of(markdown_text: &str) -> Self {
+        let mut stats = Self::default();
+        let mut in_code_block = false;
+        let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+        for event in Parser::new_ext(markdown_text, options) {
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => {
+                    stats.code_blocks += 1;
+                    in_code_block = true;
+                }
+                Event::End(pulldown_cmark::TagEnd::CodeBlock) => in_code_block = false,
+                Event::Start(Tag::Item) => stats.list_items += 1,
+                Event::Start(Tag::Table(_)) => stats.tables += 1,
+                Event::Start(Tag::Link { .. }) => stats.links += 1,
+                Event::Text(t) if !in_code_block => stats.words += t.split_whitespace().count(),
+                Event::Code(t) => stats.words += t.split_whitespace().count(),
+                _ => {}
+            }
+        }
+        stats
+    }
+}
+
+/// Check section content against its minimum requirements.
+///
+/// Requirements are checked in a fixed order and the first failure is
+/// reported.
+fn check_requirements(
+    content: &str,
+    requirements: &SectionRequirements,
+) -> (SectionStatus, Option<String>) {
+    let stats = ContentStats::of(content);
+
+    if let Some(min) = requirements.min_words
+        && stats.words < min
+    {
+        return (
+            SectionStatus::TooShort,
+            Some(format!("{} words, expected at least {min}", stats.words)),
+        );
+    }
+    if let Some(min) = requirements.min_list_items
+        && stats.list_items < min
+    {
+        return (
+            SectionStatus::TooFewListItems,
+            Some(format!(
+                "{} list items, expected at least {min}",
+                stats.list_items
+            )),
+        );
+    }
+    if requirements.require_table && stats.tables == 0 {
+        return (SectionStatus::MissingTable, Some("no table".to_string()));
+    }
+    if requirements.require_code_block && stats.code_blocks == 0 {
+        return (
+            SectionStatus::MissingCodeBlock,
+            Some("no code block".to_string()),
+        );
+    }
+    if requirements.require_link && stats.links == 0 {
+        return (SectionStatus::MissingLink, Some("no link".to_string()));
+    }
+
+    (SectionStatus::Present, None)
 }
 
 /// Extract the text content between a heading and the next heading of same/higher level.
@@ -254,7 +954,7 @@ Everything works fine.
 
 - Watch out for the thing.
 "#;
-        let report = check_completeness(content, "handoff", None).unwrap();
+        let report = check_completeness(content, "handoff", None, false).unwrap();
         assert!(report.pass);
         assert!(
             report
@@ -276,7 +976,7 @@ Everything works fine.
 
 - Chose X.
 "#;
-        let report = check_completeness(content, "handoff", None).unwrap();
+        let report = check_completeness(content, "handoff", None, false).unwrap();
         assert!(!report.pass);
         let landmines = report
             .sections
@@ -306,7 +1006,7 @@ Do stuff.
 
 TBD
 "#;
-        let report = check_completeness(content, "handoff", None).unwrap();
+        let report = check_completeness(content, "handoff", None, false).unwrap();
         assert!(!report.pass);
         let landmines = report
             .sections
@@ -318,7 +1018,7 @@ TBD
 
     #[test]
     fn unknown_template_errors() {
-        let result = check_completeness("# Test", "nonexistent", None);
+        let result = check_completeness("# Test", "nonexistent", None, false);
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(err.to_string().contains("unknown template"));
@@ -337,31 +1037,75 @@ TBD
         let mut custom = HashMap::new();
         custom.insert(
             "release-notes".to_string(),
-            vec!["Summary".to_string(), "Changes".to_string()],
+            TemplateConfig::Sections(vec!["Summary".into(), "Changes".into()]),
         );
 
         let content = "## Summary\n\nStuff happened.\n\n## Changes\n\n- Fixed bug.";
-        let report = check_completeness(content, "release-notes", Some(&custom)).unwrap();
+        let report = check_completeness(content, "release-notes", Some(&custom), false).unwrap();
         assert!(report.pass);
     }
 
+    #[test]
+    fn flat_template_sections_take_aliases_and_patterns() {
+        let mut custom = HashMap::new();
+        custom.insert(
+            "rfc".to_string(),
+            TemplateConfig::Sections(vec![
+                TemplateSectionEntry::Detailed {
+                    name: "Motivation".to_string(),
+                    options: TemplateSectionConfig {
+                        aliases: vec!["Why".to_string()],
+                        ..Default::default()
+                    },
+                },
+                TemplateSectionEntry::Detailed {
+                    name: "Design".to_string(),
+                    options: TemplateSectionConfig {
+                        patterns: vec![r"(?i)^proposal\b".to_string()],
+                        ..Default::default()
+                    },
+                },
+            ]),
+        );
+
+        let content = "## Why\n\nBuilds are slow.\n\n## Proposal: cache\n\nCache them.";
+        let report = check_completeness(content, "rfc", Some(&custom), false).unwrap();
+        assert!(report.pass);
+
+        custom.insert(
+            "broken".to_string(),
+            TemplateConfig::Sections(vec![TemplateSectionEntry::Detailed {
+                name: "Design".to_string(),
+                options: TemplateSectionConfig {
+                    patterns: vec!["(".to_string()],
+                    ..Default::default()
+                },
+            }]),
+        );
+        let err = check_completeness(content, "broken", Some(&custom), false).unwrap_err();
+        assert!(err.to_string().contains("bad pattern"));
+    }
+
     #[test]
     fn custom_template_overrides_builtin() {
         let mut custom = HashMap::new();
         custom.insert(
             "handoff".to_string(),
-            vec!["Status".to_string(), "Next".to_string()],
+            TemplateConfig::Sections(vec!["Status".into(), "Next".into()]),
         );
 
         let content = "## Status\n\nDone.\n\n## Next\n\nShip it.";
-        let report = check_completeness(content, "handoff", Some(&custom)).unwrap();
+        let report = check_completeness(content, "handoff", Some(&custom), false).unwrap();
         assert!(report.pass);
     }
 
     #[test]
     fn available_templates_includes_custom() {
         let mut custom = HashMap::new();
-        custom.insert("release-notes".to_string(), vec!["Summary".to_string()]);
+        custom.insert(
+            "release-notes".to_string(),
+            TemplateConfig::Sections(vec!["Summary".into()]),
+        );
         let templates = available_templates(Some(&custom));
         assert!(templates.iter().any(|t| t == "release-notes"));
         assert!(templates.iter().any(|t| t == "adr"));
@@ -394,7 +1138,473 @@ Chose option A because it's faster.
 - Good: faster delivery.
 - Bad: more complexity.
 "#;
-        let report = check_completeness(content, "adr", None).unwrap();
+        let report = check_completeness(content, "adr", None, false).unwrap();
         assert!(report.pass);
     }
+
+    #[test]
+    fn builtin_adr_accepts_context_aliases() {
+        for heading in ["Context and Problem Statement", "Context", "Background"] {
+            let content = format!(
+                "## {heading}\n\nWe need to decide.\n\n## Decision Drivers\n\n- Speed\n\n\
+                 ## Considered Options\n\n1. A\n\n## Decision Outcome\n\nChose A.\n\n\
+                 ## Consequences\n\n- Faster."
+            );
+            let report = check_completeness(&content, "adr", None, false).unwrap();
+            assert!(report.pass, "'{heading}' should satisfy the adr template");
+        }
+    }
+
+    #[test]
+    fn builtin_templates_report_near_misses_when_fuzzy() {
+        let content = "## Context\n\nWe need to decide.\n\n## Decision Driver\n\n- Speed";
+        let status = |fuzzy| {
+            let report = check_completeness(content, "adr", None, fuzzy).unwrap();
+            report
+                .sections
+                .into_iter()
+                .find(|s| s.name == "Decision Drivers")
+                .unwrap()
+                .status
+        };
+        assert_eq!(status(true), SectionStatus::NearMiss);
+        assert_eq!(status(false), SectionStatus::Missing);
+    }
+
+    const ADR_SKELETON: &str = r#"---
+status: proposed
+date: YYYY-MM-DD
+---
+
+# ADR-NNNN: Title
+
+## Context and Problem Statement
+
+## Decision Drivers (optional)
+
+## Considered Options
+
+## Decision Outcome
+
+### Consequences
+
+### Confirmation (optional)
+"#;
+
+    #[test]
+    fn skeleton_derives_sections_levels_and_parents() {
+        let template = Template::from_skeleton(ADR_SKELETON).unwrap();
+        let names: Vec<&str> = template.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Context and Problem Statement",
+                "Decision Drivers",
+                "Considered Options",
+                "Decision Outcome",
+                "Consequences",
+                "Confirmation",
+            ]
+        );
+        let drivers = &template.sections[1];
+        assert!(!drivers.required);
+        assert_eq!(drivers.level, 2);
+        let consequences = &template.sections[4];
+        assert!(consequences.required);
+        assert_eq!(consequences.level, 3);
+        assert_eq!(consequences.parent.as_deref(), Some("Decision Outcome"));
+        assert_eq!(template.frontmatter, ["date", "status"]);
+    }
+
+    #[test]
+    fn skeleton_template_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = camino::Utf8PathBuf::try_from(dir.path().join("adr.md")).unwrap();
+        std::fs::write(&path, ADR_SKELETON).unwrap();
+        let mut custom = HashMap::new();
+        custom.insert(
+            "adr".to_string(),
+            TemplateConfig::Skeleton(crate::config::TemplateSkeleton {
+                file: path,
+                ..Default::default()
+            }),
+        );
+
+        let content = r#"---
+status: accepted
+date: 2026-03-01
+---
+
+# ADR-0007: Use Postgres
+
+## Context and Problem Statement
+
+We need a database.
+
+## Considered Options
+
+Postgres, SQLite.
+
+## Decision Outcome
+
+Postgres.
+
+### Consequences
+
+More ops work.
+"#;
+        let report = check_completeness(content, "adr", Some(&custom), false).unwrap();
+        assert!(report.pass, "optional sections may be omitted: {report:?}");
+        let drivers = report
+            .sections
+            .iter()
+            .find(|s| s.name == "Decision Drivers")
+            .unwrap();
+        assert!(!drivers.required);
+        assert_eq!(drivers.status, SectionStatus::Missing);
+    }
+
+    #[test]
+    fn missing_frontmatter_key_fails() {
+        let template = Template::from_skeleton(ADR_SKELETON).unwrap();
+        let content = "---\nstatus: accepted\n---\n\n## Context and Problem Statement\n\nX.\n\n## Considered Options\n\nY.\n\n## Decision Outcome\n\nZ.\n\n### Consequences\n\nW.\n";
+        let report = check_template(content, "adr", &template);
+        assert!(!report.pass);
+        assert_eq!(report.missing_frontmatter, ["date"]);
+    }
+
+    #[test]
+    fn missing_skeleton_file_errors() {
+        let mut custom = HashMap::new();
+        custom.insert(
+            "adr".to_string(),
+            TemplateConfig::Skeleton(crate::config::TemplateSkeleton {
+                file: "/nonexistent/adr.md".into(),
+                ..Default::default()
+            }),
+        );
+        let err = check_completeness("# Test", "adr", Some(&custom), false).unwrap_err();
+        assert!(err.to_string().contains("failed to read template file"));
+    }
+
+    const STRUCTURED_ADR: &str = r#"# ADR-0007: Use Postgres
+
+## Context and Problem Statement
+
+We need a database.
+
+## Considered Options
+
+Postgres, SQLite.
+
+## Decision Outcome
+
+Postgres.
+
+### Consequences
+
+More ops work.
+"#;
+
+    fn strict_adr() -> Template {
+        Template {
+            enforce_order: true,
+            enforce_levels: true,
+            enforce_nesting: true,
+            ..Template::from_skeleton(ADR_SKELETON).unwrap()
+        }
+    }
+
+    fn status_of(report: &CompletenessReport, name: &str) -> SectionStatus {
+        report
+            .sections
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap()
+            .status
+    }
+
+    #[test]
+    fn structure_enforcement_accepts_matching_document() {
+        let content = format!("---\nstatus: accepted\ndate: 2026-03-01\n---\n\n{STRUCTURED_ADR}");
+        let report = check_template(&content, "adr", &strict_adr());
+        assert!(report.pass, "{report:?}");
+    }
+
+    #[test]
+    fn out_of_order_section_detected() {
+        let content = STRUCTURED_ADR.replace("## Considered Options\n\nPostgres, SQLite.\n\n", "")
+            + "\n## Considered Options\n\nPostgres, SQLite.\n";
+        let report = check_template(&content, "adr", &strict_adr());
+        assert!(!report.pass);
+        assert_eq!(
+            status_of(&report, "Considered Options"),
+            SectionStatus::OutOfOrder
+        );
+        assert_eq!(
+            status_of(&report, "Decision Outcome"),
+            SectionStatus::Present
+        );
+        let options = report
+            .sections
+            .iter()
+            .find(|s| s.name == "Considered Options")
+            .unwrap();
+        assert_eq!(
+            options.detail.as_deref(),
+            Some("expected before 'Decision Outcome'")
+        );
+    }
+
+    #[test]
+    fn wrong_heading_level_detected() {
+        let content = STRUCTURED_ADR.replace("### Consequences", "#### Consequences");
+        let report = check_template(&content, "adr", &strict_adr());
+        let consequences = report
+            .sections
+            .iter()
+            .find(|s| s.name == "Consequences")
+            .unwrap();
+        assert_eq!(consequences.status, SectionStatus::WrongLevel);
+        assert_eq!(
+            consequences.detail.as_deref(),
+            Some("found at level 4, expected level 3")
+        );
+    }
+
+    #[test]
+    fn wrong_parent_detected() {
+        let content = STRUCTURED_ADR.replace(
+            "## Decision Outcome\n\nPostgres.\n\n### Consequences",
+            "### Consequences\n\nMore ops work.\n\n## Decision Outcome\n\nPostgres.\n\n### Other",
+        );
+        let template = Template {
+            enforce_order: false,
+            ..strict_adr()
+        };
+        let report = check_template(&content, "adr", &template);
+        assert_eq!(
+            status_of(&report, "Consequences"),
+            SectionStatus::WrongLevel
+        );
+    }
+
+    #[test]
+    fn structure_not_enforced_by_default() {
+        let content = STRUCTURED_ADR.replace("### Consequences", "## Consequences");
+        let template = Template::from_skeleton(ADR_SKELETON).unwrap();
+        let report = check_template(&content, "adr", &template);
+        assert_eq!(status_of(&report, "Consequences"), SectionStatus::Present);
+    }
+
+    fn skeleton_config(sections: &[(&str, &[&str], &[&str])]) -> TemplateSkeleton {
+        TemplateSkeleton {
+            sections: sections
+                .iter()
+                .map(|(name, aliases, patterns)| {
+                    (
+                        (*name).to_string(),
+                        crate::config::TemplateSectionConfig {
+                            aliases: aliases.iter().map(|a| (*a).to_string()).collect(),
+                            patterns: patterns.iter().map(|p| (*p).to_string()).collect(),
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn aliases_and_patterns_match_headings() {
+        let config = skeleton_config(&[
+            ("Context and Problem Statement", &["Background"], &[]),
+            ("Considered Options", &[], &[r"(?i)^alternatives?\b"]),
+        ]);
+        let template = Template::from_skeleton_config("adr", &config, ADR_SKELETON).unwrap();
+        let content = STRUCTURED_ADR
+            .replace("Context and Problem Statement", "Background")
+            .replace("Considered Options", "Alternatives weighed");
+        let report = check_template(&content, "adr", &template);
+        assert_eq!(
+            status_of(&report, "Context and Problem Statement"),
+            SectionStatus::Present
+        );
+        assert_eq!(
+            status_of(&report, "Considered Options"),
+            SectionStatus::Present
+        );
+    }
+
+    #[test]
+    fn fuzzy_mode_reports_near_miss() {
+        let config = TemplateSkeleton {
+            fuzzy: true,
+            ..Default::default()
+        };
+        let template = Template::from_skeleton_config("adr", &config, ADR_SKELETON).unwrap();
+        let content = STRUCTURED_ADR.replace("## Considered Options", "## Considred Option");
+        let report = check_template(&content, "adr", &template);
+        let options = report
+            .sections
+            .iter()
+            .find(|s| s.name == "Considered Options")
+            .unwrap();
+        assert_eq!(options.status, SectionStatus::NearMiss);
+        assert_eq!(
+            options.detail.as_deref(),
+            Some("found 'Considred Option'; did you mean 'Considered Options'?")
+        );
+        // Dissimilar headings stay missing.
+        assert_eq!(
+            status_of(&report, "Decision Drivers"),
+            SectionStatus::Missing
+        );
+    }
+
+    #[test]
+    fn exact_mode_does_not_report_near_miss() {
+        let template = Template::from_skeleton(ADR_SKELETON).unwrap();
+        let content = STRUCTURED_ADR.replace("## Considered Options", "## Considred Option");
+        let report = check_template(&content, "adr", &template);
+        assert_eq!(
+            status_of(&report, "Considered Options"),
+            SectionStatus::Missing
+        );
+    }
+
+    #[test]
+    fn unknown_section_config_errors() {
+        let config = skeleton_config(&[("Nonexistent", &["X"], &[])]);
+        let err = Template::from_skeleton_config("adr", &config, ADR_SKELETON).unwrap_err();
+        assert!(err.to_string().contains("no section 'Nonexistent'"));
+    }
+
+    #[test]
+    fn invalid_pattern_errors() {
+        let config = skeleton_config(&[("Considered Options", &[], &["("])]);
+        let err = Template::from_skeleton_config("adr", &config, ADR_SKELETON).unwrap_err();
+        assert!(err.to_string().contains("bad pattern"));
+    }
+
+    fn requiring(name: &str, content: SectionRequirements) -> Template {
+        let mut template = Template::from_skeleton(ADR_SKELETON).unwrap();
+        template
+            .sections
+            .iter_mut()
+            .find(|s| s.name == name)
+            .unwrap()
+            .content = content;
+        template
+    }
+
+    #[test]
+    fn min_words_requirement() {
+        let template = requiring(
+            "Consequences",
+            SectionRequirements {
+                min_words: Some(5),
+                ..Default::default()
+            },
+        );
+        let report = check_template(STRUCTURED_ADR, "adr", &template);
+        let consequences = report
+            .sections
+            .iter()
+            .find(|s| s.name == "Consequences")
+            .unwrap();
+        assert_eq!(consequences.status, SectionStatus::TooShort);
+        assert_eq!(
+            consequences.detail.as_deref(),
+            Some("3 words, expected at least 5")
+        );
+
+        let longer =
+            STRUCTURED_ADR.replace("More ops work.", "More ops work, plus backups to run.");
+        let report = check_template(&longer, "adr", &template);
+        assert_eq!(status_of(&report, "Consequences"), SectionStatus::Present);
+    }
+
+    #[test]
+    fn min_list_items_requirement() {
+        let template = requiring(
+            "Considered Options",
+            SectionRequirements {
+                min_list_items: Some(2),
+                ..Default::default()
+            },
+        );
+        let report = check_template(STRUCTURED_ADR, "adr", &template);
+        assert_eq!(
+            status_of(&report, "Considered Options"),
+            SectionStatus::TooFewListItems
+        );
+
+        let listed = STRUCTURED_ADR.replace("Postgres, SQLite.", "- Postgres\n- SQLite");
+        let report = check_template(&listed, "adr", &template);
+        assert_eq!(
+            status_of(&report, "Considered Options"),
+            SectionStatus::Present
+        );
+    }
+
+    #[test]
+    fn required_blocks_and_links() {
+        let cases = [
+            (
+                SectionRequirements {
+                    require_table: true,
+                    ..Default::default()
+                },
+                SectionStatus::MissingTable,
+                "| Option | Cost |\n|---|---|\n| Postgres | Low |",
+            ),
+            (
+                SectionRequirements {
+                    require_code_block: true,
+                    ..Default::default()
+                },
+                SectionStatus::MissingCodeBlock,
+                "```sql\nCREATE TABLE t ();\n```",
+            ),
+            (
+                SectionRequirements {
+                    require_link: true,
+                    ..Default::default()
+                },
+                SectionStatus::MissingLink,
+                "See [the docs](https://www.postgresql.org/docs/).",
+            ),
+        ];
+        for (requirements, missing_status, satisfying) in cases {
+            let template = requiring("Considered Options", requirements);
+            let report = check_template(STRUCTURED_ADR, "adr", &template);
+            assert_eq!(status_of(&report, "Considered Options"), missing_status);
+
+            let fixed = STRUCTURED_ADR.replace("Postgres, SQLite.", satisfying);
+            let report = check_template(&fixed, "adr", &template);
+            assert_eq!(
+                status_of(&report, "Considered Options"),
+                SectionStatus::Present,
+                "{missing_status:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn draft_instructions_outline_sections_and_requirements() {
+        let skeleton = "---\nstatus: draft\n---\n# Title\n\n## Context\n\n### Risks (optional)\n\n## Decision\n";
+        let mut template = Template::from_skeleton(skeleton).unwrap();
+        template.sections[2].content.min_words = Some(30);
+        template.enforce_order = true;
+
+        let text = draft_instructions("rfc", &template);
+        assert!(text.contains("\"rfc\" completeness template"));
+        assert!(text.contains("- `## Context`\n"));
+        assert!(text.contains("  - `### Risks` (optional)\n"));
+        assert!(text.contains("- `## Decision` (at least 30 words)\n"));
+        assert!(text.contains("frontmatter defining: status."));
+        assert!(text.contains("Structure: keep sections in the order listed."));
+    }
 }
@@ -37,7 +37,7 @@
 //!     .unwrap();
 //! ```
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use camino::{Utf8Path, Utf8PathBuf};
 use figment::Figment;
@@ -51,7 +51,7 @@ use crate::tokens::Backend;
 ///
 /// When set, the consistency checker enforces the chosen dialect's spelling
 /// (e.g., "color" vs "colour") in addition to detecting mixed usage.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, schemars::JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
 pub enum Dialect {
@@ -88,10 +88,10 @@ impl std::fmt::Display for Dialect {
 }
 
 /// Settings for the `analyze` check within a rule.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, schemars::JsonSchema)]
 #[serde(default)]
 pub struct AnalyzeRuleConfig {
-    /// Which of the 18 analysis checks to run. Omit for all.
+    /// Which of the 21 analysis checks to run. Omit for all.
     pub checks: Option<Vec<String>>,
     /// Which analysis checks to skip.
     pub exclude: Option<Vec<String>>,
@@ -103,10 +103,111 @@ pub struct AnalyzeRuleConfig {
     pub style_min: Option<i32>,
     /// English dialect for spelling enforcement.
     pub dialect: Option<Dialect>,
+    /// Acronyms that need no definition, beyond the built-in list.
+    pub known_acronyms: Option<Vec<String>>,
+    /// Style score formula. Omit for the built-in formula.
+    pub style_score: Option<StyleScoreConfig>,
+}
+
+/// A component of the style score.
+#[derive(
+    Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, schemars::JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum StyleComponent {
+    /// Passive voice constructions.
+    Passive,
+    /// Adverbs (words ending in -ly).
+    Adverbs,
+    /// Distinct hidden verb and nominalization nouns.
+    HiddenVerbs,
+    /// Overall glue word percentage.
+    Glue,
+    /// Vague words and phrases.
+    Vague,
+    /// Hedges and weasel phrases.
+    Hedging,
+    /// Clichés.
+    Cliches,
+    /// Business jargon.
+    Jargon,
+    /// Wordy phrases.
+    Wordiness,
+    /// Machine-drafted prose tells.
+    AiTells,
+}
+
+impl StyleComponent {
+    /// Returns the component's config key.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Passive => "passive",
+            Self::Adverbs => "adverbs",
+            Self::HiddenVerbs => "hidden_verbs",
+            Self::Glue => "glue",
+            Self::Vague => "vague",
+            Self::Hedging => "hedging",
+            Self::Cliches => "cliches",
+            Self::Jargon => "jargon",
+            Self::Wordiness => "wordiness",
+            Self::AiTells => "ai_tells",
+        }
+    }
+}
+
+impl std::fmt::Display for StyleComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How much one style score component costs.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, schemars::JsonSchema)]
+pub struct ScoreWeight {
+    /// Points deducted per unit counted (required).
+    pub weight: f64,
+    /// Most points this component can deduct. Omit for no cap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// Units below this are free; only the excess is counted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+}
+
+/// Style score formula: the components that deduct points from 100.
+///
+/// Only listed components participate, so a formula replaces the built-in
+/// one rather than adjusting it.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, schemars::JsonSchema)]
+#[serde(transparent)]
+pub struct StyleScoreConfig {
+    /// Weight for each participating component.
+    pub components: BTreeMap<StyleComponent, ScoreWeight>,
+}
+
+impl Default for StyleScoreConfig {
+    /// The built-in formula.
+    fn default() -> Self {
+        let weight = |weight, max, threshold| ScoreWeight {
+            weight,
+            max: Some(max),
+            threshold,
+        };
+        Self {
+            components: BTreeMap::from([
+                (StyleComponent::Passive, weight(2.0, 20.0, None)),
+                (StyleComponent::Adverbs, weight(0.5, 15.0, None)),
+                (StyleComponent::HiddenVerbs, weight(2.0, 10.0, None)),
+                (StyleComponent::Glue, weight(1.0, 15.0, Some(25.0))),
+                (StyleComponent::Vague, weight(0.5, 10.0, None)),
+                (StyleComponent::Hedging, weight(1.0, 10.0, None)),
+            ]),
+        }
+    }
 }
 
 /// Settings for the `readability` check within a rule.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, schemars::JsonSchema)]
 #[serde(default)]
 pub struct ReadabilityRuleConfig {
     /// Maximum acceptable Flesch-Kincaid grade level.
@@ -114,7 +215,7 @@ pub struct ReadabilityRuleConfig {
 }
 
 /// Settings for the `grammar` check within a rule.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, schemars::JsonSchema)]
 #[serde(default)]
 pub struct GrammarRuleConfig {
     /// Maximum acceptable passive voice percentage (0--100).
@@ -122,14 +223,50 @@ pub struct GrammarRuleConfig {
 }
 
 /// Settings for the `completeness` check within a rule.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
 pub struct CompletenessRuleConfig {
     /// Template name (required): "adr", "handoff", "design-doc", or custom.
     pub template: String,
 }
 
+/// Settings for the `frontmatter` check within a rule.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
+pub struct FrontmatterRuleConfig {
+    /// Schema name (required), as defined under `frontmatter_schemas`.
+    pub schema: String,
+}
+
+/// Capitalization style for headings.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HeadingCase {
+    /// Capitalize major words ("Getting Started with the API").
+    Title,
+    /// Capitalize only the first word and proper nouns ("Getting started with the API").
+    Sentence,
+}
+
+/// Settings for the `headings` check within a rule.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(default)]
+pub struct HeadingsRuleConfig {
+    /// Required capitalization style. Omit to skip case checking.
+    pub case: Option<HeadingCase>,
+    /// Proper nouns and product names exempt from case checking
+    /// (e.g. "Rust", "Visual Studio Code").
+    pub exceptions: Vec<String>,
+}
+
+/// Settings for the `links` check within a rule.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
+#[serde(default)]
+pub struct LinksRuleConfig {
+    /// Syntax-check external URLs (default: false). URLs are never fetched.
+    pub check_urls: bool,
+}
+
 /// Settings for the `tokens` check within a rule.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, schemars::JsonSchema)]
 #[serde(default)]
 pub struct TokensRuleConfig {
     /// Maximum token budget. Omit for no limit.
@@ -178,11 +315,186 @@ impl CustomEntry {
     }
 }
 
+/// A custom completeness template definition.
+///
+/// Either a plain list of required section headings, or a table pointing at
+/// a markdown skeleton file whose headings define the template.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TemplateConfig {
+    /// Required section headings, all at level 2.
+    Sections(Vec<TemplateSectionEntry>),
+    /// A markdown skeleton file defining the template.
+    Skeleton(TemplateSkeleton),
+}
+
+/// One section of a flat template: a heading, or a table giving the heading
+/// with its matching options.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TemplateSectionEntry {
+    /// Just the section heading.
+    Name(String),
+    /// The section heading with aliases, patterns, and content requirements.
+    Detailed {
+        /// Section heading text.
+        name: String,
+        /// Matching options for the section.
+        #[serde(flatten)]
+        options: TemplateSectionConfig,
+    },
+}
+
+impl TemplateSectionEntry {
+    /// The section heading text.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Name(name) | Self::Detailed { name, .. } => name,
+        }
+    }
+}
+
+impl From<&str> for TemplateSectionEntry {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+/// A completeness template backed by a markdown skeleton file.
+///
+/// Section names, heading levels, nesting, order, optional markers, and
+/// required frontmatter keys are all derived from the skeleton, so the
+/// template a team copies and the check that validates it cannot drift.
+///
+/// Structural enforcement is opt-in: by default a section only has to exist
+/// somewhere in the document with real content.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TemplateSkeleton {
+    /// Path to the skeleton (relative to config file location, or absolute).
+    pub file: Utf8PathBuf,
+    /// Require sections to appear in skeleton order.
+    #[serde(default)]
+    pub enforce_order: bool,
+    /// Require each section to use the skeleton's heading level.
+    #[serde(default)]
+    pub enforce_levels: bool,
+    /// Require each section to sit under the skeleton's parent heading.
+    #[serde(default)]
+    pub enforce_nesting: bool,
+    /// Report headings that nearly match a missing section ("did you mean").
+    #[serde(default)]
+    pub fuzzy: bool,
+    /// Per-section options, keyed by skeleton heading name.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub sections: HashMap<String, TemplateSectionConfig>,
+}
+
+/// Matching options for one section of a template.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TemplateSectionConfig {
+    /// Alternative heading texts accepted for the section.
+    pub aliases: Vec<String>,
+    /// Regular expressions matched against the heading text.
+    pub patterns: Vec<String>,
+    /// Minimum content the section must contain.
+    #[serde(flatten)]
+    pub content: SectionRequirements,
+}
+
+/// Minimum content requirements for a template section.
+///
+/// All requirements are off by default; a section then passes with any
+/// non-placeholder content.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SectionRequirements {
+    /// Minimum number of prose words (code blocks excluded).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_words: Option<usize>,
+    /// Minimum number of list items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_list_items: Option<usize>,
+    /// Require at least one table.
+    pub require_table: bool,
+    /// Require at least one fenced or indented code block.
+    pub require_code_block: bool,
+    /// Require at least one link.
+    pub require_link: bool,
+}
+
+/// A named frontmatter schema.
+///
+/// Either a JSON Schema file or a simple per-key spec. Exactly one of
+/// `file` and `fields` must be set.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FrontmatterSchemaConfig {
+    /// Path to a JSON Schema file, in JSON or YAML (relative to config file
+    /// location, or absolute).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Utf8PathBuf>,
+    /// Simple spec: frontmatter key → expected type and allowed values.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, FrontmatterField>>,
+}
+
+/// Expected shape of one frontmatter key in a simple schema spec.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FrontmatterField {
+    /// Expected value type. Omit to accept any type.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<FrontmatterType>,
+    /// Allowed values. Omit to accept any value.
+    #[serde(rename = "enum", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<serde_json::Value>>,
+    /// Whether the key may be omitted. Keys are required by default.
+    pub optional: bool,
+}
+
+/// Value types for simple frontmatter specs.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FrontmatterType {
+    /// Any string.
+    String,
+    /// Any number.
+    Number,
+    /// A whole number.
+    Integer,
+    /// `true` or `false`.
+    Boolean,
+    /// A YAML sequence.
+    List,
+    /// A YAML mapping.
+    Map,
+    /// A `YYYY-MM-DD` date string.
+    Date,
+}
+
+/// Settings for validating a directory of Architecture Decision Records.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AdrConfig {
+    /// Directory containing the ADRs (relative to config file location, or absolute).
+    pub dir: Utf8PathBuf,
+    /// Allowed status values, compared case-insensitively.
+    ///
+    /// Default: proposed, accepted, rejected, deprecated, superseded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Vec<String>>,
+    /// Number the first ADR must have.
+    ///
+    /// Default: 0 or 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_number: Option<u32>,
+}
+
 /// Checks to run for a path-based rule.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 #[serde(default)]
 pub struct RuleChecks {
-    /// Run comprehensive writing analysis (18 checks).
+    /// Run comprehensive writing analysis (21 checks).
     pub analyze: Option<AnalyzeRuleConfig>,
     /// Run standalone readability scoring (gate on grade level).
     pub readability: Option<ReadabilityRuleConfig>,
@@ -190,6 +502,12 @@ pub struct RuleChecks {
     pub grammar: Option<GrammarRuleConfig>,
     /// Run completeness checking against a template.
     pub completeness: Option<CompletenessRuleConfig>,
+    /// Validate frontmatter against a named schema.
+    pub frontmatter: Option<FrontmatterRuleConfig>,
+    /// Check heading case, levels, duplicates, and punctuation.
+    pub headings: Option<HeadingsRuleConfig>,
+    /// Check relative links, anchors, and reference definitions.
+    pub links: Option<LinksRuleConfig>,
     /// Run token counting (gate on budget).
     pub tokens: Option<TokensRuleConfig>,
 }
@@ -226,8 +544,13 @@ pub struct Config {
     pub passive_max_percent: Option<f64>,
     /// Default minimum style score for the `analyze` command.
     pub style_min_score: Option<i32>,
+    /// Default style score formula. Omit for the built-in formula.
+    pub style_score: Option<StyleScoreConfig>,
     /// English dialect for spelling enforcement (en-us, en-gb, en-ca, en-au).
     pub dialect: Option<Dialect>,
+    /// Acronyms that need no definition (e.g. "ADR", "SLO"), beyond the
+    /// built-in list of well-known ones like API, URL, and HTML.
+    pub known_acronyms: Option<Vec<String>>,
     /// Maximum input size in bytes (default: 5 MiB).
     ///
     /// Prevents resource exhaustion from oversized inputs in both CLI and MCP server.
@@ -240,13 +563,38 @@ pub struct Config {
     /// Default: `false`.
     #[serde(default)]
     pub disable_input_limit: bool,
+    /// Require a reason (` -- why`) on every suppression directive.
+    ///
+    /// When `true`, `lint` fails on files with a directive that gives none.
+    /// Default: `false`.
+    #[serde(default)]
+    pub require_directive_reason: bool,
+    /// Report suppression directives that hid no findings.
+    ///
+    /// When `true`, `lint` fails on files with such directives. The `lint`
+    /// command's `--report-unused-directives` flag sets this.
+    /// Default: `false`.
+    #[serde(default)]
+    pub report_unused_directives: bool,
     /// Tokenizer backend (claude or openai). Defaults to claude.
     pub tokenizer: Option<Backend>,
-    /// Custom completeness templates (name → required section headings).
+    /// Report near-miss headings for the built-in completeness templates.
+    ///
+    /// Custom templates opt in with their own `fuzzy` setting.
+    /// Default: `false`.
+    #[serde(default)]
+    pub fuzzy_builtin_templates: bool,
+    /// Custom completeness templates (name → section headings or skeleton file).
     ///
     /// These extend (not replace) the built-in templates (adr, handoff, design-doc).
     /// If a custom template name collides with a built-in, the custom one wins.
-    pub templates: Option<HashMap<String, Vec<String>>>,
+    pub templates: Option<HashMap<String, TemplateConfig>>,
+    /// Frontmatter schemas (name → JSON Schema file or simple spec).
+    ///
+    /// Rules reference these by name from the `frontmatter` check.
+    pub frontmatter_schemas: Option<HashMap<String, FrontmatterSchemaConfig>>,
+    /// Architecture Decision Record collection settings for the `adr` command.
+    pub adr: Option<AdrConfig>,
     /// Path-based lint rules.
     ///
     /// Each rule maps glob patterns to checks with specific settings.
@@ -257,6 +605,106 @@ pub struct Config {
     pub custom: Option<HashMap<String, CustomEntry>>,
 }
 
+impl Config {
+    /// Resolve relative template skeleton and schema paths against `config_dir`.
+    ///
+    /// Called once at load time so checks can read these files without
+    /// knowing where the config file lives.
+    fn resolve_relative_paths(&mut self, config_dir: &Utf8Path) {
+        if let Some(ref mut templates) = self.templates {
+            for template in templates.values_mut() {
+                if let TemplateConfig::Skeleton(skeleton) = template
+                    && skeleton.file.is_relative()
+                {
+                    skeleton.file = config_dir.join(&skeleton.file);
+                }
+            }
+        }
+        if let Some(ref mut schemas) = self.frontmatter_schemas {
+            for schema in schemas.values_mut() {
+                if let Some(ref mut file) = schema.file
+                    && file.is_relative()
+                {
+                    *file = config_dir.join(&*file);
+                }
+            }
+        }
+        if let Some(ref mut adr) = self.adr
+            && adr.dir.is_relative()
+        {
+            adr.dir = config_dir.join(&adr.dir);
+        }
+    }
+
+    /// Check cross-references that deserialization cannot.
+    ///
+    /// Reports every rule glob that does not compile, analyze check names
+    /// that do not exist, analyze rules setting both `checks` and `exclude`,
+    /// completeness templates and frontmatter schemas that are not defined,
+    /// and custom entries with no content.
+    pub fn validate(&self) -> ConfigResult<()> {
+        let mut problems = Vec::new();
+        let templates = crate::completeness::available_templates(self.templates.as_ref());
+
+        for (i, rule) in self.rules.iter().flatten().enumerate() {
+            let at = format!("rules[{i}]");
+            for pattern in &rule.paths {
+                if let Err(e) = globset::Glob::new(pattern) {
+                    problems.push(format!(
+                        "{at}: invalid path pattern '{pattern}': {}",
+                        e.kind()
+                    ));
+                }
+            }
+            if let Some(ref analyze) = rule.checks.analyze {
+                if analyze.checks.is_some() && analyze.exclude.is_some() {
+                    problems.push(format!("{at}: analyze sets both 'checks' and 'exclude'"));
+                }
+                for name in analyze.checks.iter().chain(&analyze.exclude).flatten() {
+                    if !crate::analysis::ALL_CHECKS.contains(&name.as_str()) {
+                        problems.push(format!("{at}: unknown analyze check '{name}'"));
+                    }
+                }
+            }
+            if let Some(ref completeness) = rule.checks.completeness
+                && !templates.contains(&completeness.template)
+            {
+                problems.push(format!(
+                    "{at}: unknown template '{}'",
+                    completeness.template
+                ));
+            }
+            if let Some(ref frontmatter) = rule.checks.frontmatter
+                && !self
+                    .frontmatter_schemas
+                    .as_ref()
+                    .is_some_and(|schemas| schemas.contains_key(&frontmatter.schema))
+            {
+                problems.push(format!(
+                    "{at}: unknown frontmatter schema '{}'",
+                    frontmatter.schema
+                ));
+            }
+        }
+
+        let mut custom: Vec<_> = self.custom.iter().flatten().collect();
+        custom.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, entry) in custom {
+            if entry.instructions.is_none() && entry.file.is_none() {
+                problems.push(format!(
+                    "custom.{name}: neither 'instructions' nor 'file' is set"
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Validation(problems.join("; ")))
+        }
+    }
+}
+
 /// Log level configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -312,6 +760,30 @@ impl ConfigSources {
             .or_else(|| self.project_files.last().map(Utf8PathBuf::as_path))
             .or(self.user_file.as_deref())
     }
+
+    /// Every loaded config file, ordered low to high precedence.
+    pub fn files(&self) -> Vec<&Utf8Path> {
+        self.user_file
+            .iter()
+            .chain(&self.project_files)
+            .chain(&self.explicit_files)
+            .map(Utf8PathBuf::as_path)
+            .collect()
+    }
+
+    /// Load the same files again, with the same precedence.
+    ///
+    /// Does not re-run discovery: a config file created since the first
+    /// load is not picked up, and one deleted since is an error.
+    pub fn reload(&self) -> ConfigResult<Config> {
+        let loader = self.files().into_iter().fold(
+            ConfigLoader::new()
+                .with_user_config(false)
+                .without_boundary_marker(),
+            ConfigLoader::with_file,
+        );
+        loader.load().map(|(config, _)| config)
+    }
 }
 
 /// Supported configuration file extensions (in order of preference).
@@ -429,9 +901,12 @@ impl ConfigLoader {
         // BITO_LINT_DIALECT=en-gb, BITO_LINT_LOG_LEVEL=debug, etc.
         figment = figment.merge(Env::prefixed("BITO_LINT_").lowercase(true));
 
-        let config: Config = figment
+        let mut config: Config = figment
             .extract()
             .map_err(|e| ConfigError::Deserialize(Box::new(e)))?;
+        if let Some(config_dir) = sources.primary_file().and_then(Utf8Path::parent) {
+            config.resolve_relative_paths(config_dir);
+        }
         tracing::info!(
             log_level = config.log_level.as_str(),
             "configuration loaded"
@@ -777,6 +1252,25 @@ log_dir = "/tmp/bito-lint"
         assert_eq!(config.log_level, LogLevel::Debug);
     }
 
+    #[test]
+    fn test_load_leaves_validation_to_the_caller() {
+        let tmp = TempDir::new().unwrap();
+        let config_path = Utf8PathBuf::try_from(tmp.path().join("config.toml")).unwrap();
+        fs::write(
+            &config_path,
+            "[[rules]]\npaths = [\"*.md\"]\n[rules.checks.completeness]\ntemplate = \"nope\"\n",
+        )
+        .unwrap();
+
+        let (config, _) = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("unknown template 'nope'"));
+    }
+
     #[test]
     fn test_user_config_dir() {
         // Should return Some on most systems
@@ -1096,6 +1590,156 @@ custom:
         assert_eq!(style.file.as_ref().unwrap().as_str(), "docs/style.md");
     }
 
+    #[test]
+    fn templates_accept_sections_and_skeletons() {
+        let yaml = r#"
+templates:
+  rfc:
+    - Summary
+    - name: Motivation
+      aliases: ["Why"]
+  adr:
+    file: "docs/templates/adr.md"
+  design:
+    file: "docs/templates/design.md"
+    fuzzy: true
+    sections:
+      Context:
+        aliases: ["Background"]
+        patterns: ["(?i)^problem"]
+        min_words: 40
+        require_link: true
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let templates = config.templates.expect("templates should be present");
+        assert_eq!(
+            templates["rfc"],
+            TemplateConfig::Sections(vec![
+                "Summary".into(),
+                TemplateSectionEntry::Detailed {
+                    name: "Motivation".to_string(),
+                    options: TemplateSectionConfig {
+                        aliases: vec!["Why".to_string()],
+                        ..Default::default()
+                    },
+                },
+            ])
+        );
+        assert_eq!(
+            templates["adr"],
+            TemplateConfig::Skeleton(TemplateSkeleton {
+                file: Utf8PathBuf::from("docs/templates/adr.md"),
+                ..Default::default()
+            })
+        );
+        let TemplateConfig::Skeleton(ref design) = templates["design"] else {
+            panic!("expected skeleton template");
+        };
+        assert!(design.fuzzy);
+        assert_eq!(design.sections["Context"].aliases, ["Background"]);
+        assert_eq!(design.sections["Context"].patterns, ["(?i)^problem"]);
+        assert_eq!(design.sections["Context"].content.min_words, Some(40));
+        assert!(design.sections["Context"].content.require_link);
+    }
+
+    #[test]
+    fn template_and_schema_paths_resolve_against_config_dir() {
+        let tmp = TempDir::new().unwrap();
+        let dir = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+        let config_path = dir.join("bito-lint.yaml");
+        fs::write(
+            &config_path,
+            "templates:\n  adr:\n    file: docs/templates/adr.md\n\
+             frontmatter_schemas:\n  adr:\n    file: schemas/adr.json\n\
+             adr:\n  dir: docs/decisions\n",
+        )
+        .unwrap();
+
+        let (config, _) = ConfigLoader::new()
+            .with_user_config(false)
+            .with_file(&config_path)
+            .load()
+            .unwrap();
+
+        let templates = config.templates.unwrap();
+        let TemplateConfig::Skeleton(ref skeleton) = templates["adr"] else {
+            panic!("expected skeleton template");
+        };
+        assert_eq!(skeleton.file, dir.join("docs/templates/adr.md"));
+
+        let schemas = config.frontmatter_schemas.unwrap();
+        assert_eq!(schemas["adr"].file, Some(dir.join("schemas/adr.json")));
+
+        assert_eq!(config.adr.unwrap().dir, dir.join("docs/decisions"));
+    }
+
+    #[test]
+    fn frontmatter_schema_fields_deserialize() {
+        let yaml = r#"
+frontmatter_schemas:
+  adr:
+    fields:
+      status:
+        type: string
+        enum: [proposed, accepted, superseded]
+      deciders:
+        type: list
+        optional: true
+rules:
+  - paths: ["docs/decisions/*.md"]
+    checks:
+      frontmatter:
+        schema: adr
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let schemas = config.frontmatter_schemas.unwrap();
+        let fields = schemas["adr"].fields.as_ref().unwrap();
+        assert_eq!(fields["status"].kind, Some(FrontmatterType::String));
+        assert_eq!(fields["status"].values.as_ref().unwrap().len(), 3);
+        assert!(!fields["status"].optional);
+        assert!(fields["deciders"].optional);
+        let rules = config.rules.unwrap();
+        assert_eq!(rules[0].checks.frontmatter.as_ref().unwrap().schema, "adr");
+    }
+
+    #[test]
+    fn style_score_formula_deserializes_per_rule() {
+        let yaml = r#"
+rules:
+  - paths: ["docs/**/*.md"]
+    checks:
+      analyze:
+        style_score:
+          passive: { weight: 1.0, max: 10 }
+          glue: { weight: 1.0, threshold: 30 }
+          cliches: { weight: 3 }
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let rules = config.rules.unwrap();
+        let formula = rules[0]
+            .checks
+            .analyze
+            .as_ref()
+            .unwrap()
+            .style_score
+            .as_ref()
+            .unwrap();
+        assert_eq!(formula.components.len(), 3);
+        assert_eq!(formula.components[&StyleComponent::Passive].max, Some(10.0));
+        assert_eq!(
+            formula.components[&StyleComponent::Glue].threshold,
+            Some(30.0)
+        );
+        assert_eq!(formula.components[&StyleComponent::Cliches].max, None);
+    }
+
+    #[test]
+    fn style_score_component_requires_weight() {
+        let yaml = "style_score:\n  passive: { max: 5 }\n";
+        let err = serde_yaml::from_str::<Config>(yaml).unwrap_err();
+        assert!(err.to_string().contains("weight"), "{err}");
+    }
+
     #[test]
     fn custom_entries_default_to_none() {
         let config = Config::default();
@@ -1169,4 +1813,63 @@ custom:
         assert_eq!(config.log_level, LogLevel::Debug);
         assert_eq!(sources.project_files.len(), 1);
     }
+
+    #[test]
+    fn reload_rereads_the_same_files() {
+        let tmp = TempDir::new().unwrap();
+        let tmp_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+        fs::write(tmp.path().join(".bito.toml"), "dialect = \"en-gb\"\n").unwrap();
+        fs::write(tmp.path().join("bito-lint.toml"), "log_level = \"warn\"\n").unwrap();
+
+        let (_, sources) = ConfigLoader::new()
+            .with_user_config(false)
+            .without_boundary_marker()
+            .with_project_search(&tmp_path)
+            .load()
+            .unwrap();
+        assert_eq!(sources.files().len(), 2);
+
+        fs::write(
+            tmp.path().join("bito-lint.toml"),
+            "log_level = \"error\"\ndialect = \"en-ca\"\n",
+        )
+        .unwrap();
+        let config = sources.reload().unwrap();
+        assert_eq!(config.log_level, LogLevel::Error);
+        assert_eq!(config.dialect, Some(Dialect::EnCa));
+
+        fs::write(tmp.path().join("bito-lint.toml"), "log_level = 7\n").unwrap();
+        assert!(sources.reload().is_err());
+    }
+
+    #[test]
+    fn validate_reports_dangling_references() {
+        let yaml = r#"
+rules:
+  - paths: ["docs/[*.md"]
+    checks:
+      analyze:
+        checks: [style, nope]
+        exclude: [cliches]
+      completeness:
+        template: missing
+      frontmatter:
+        schema: post
+custom:
+  empty: {}
+"#;
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let err = config.validate().unwrap_err().to_string();
+        for expected in [
+            "rules[0]: invalid path pattern 'docs/[*.md'",
+            "both 'checks' and 'exclude'",
+            "unknown analyze check 'nope'",
+            "unknown template 'missing'",
+            "unknown frontmatter schema 'post'",
+            "custom.empty",
+        ] {
+            assert!(err.contains(expected), "{expected} not in {err}");
+        }
+        assert!(Config::default().validate().is_ok());
+    }
 }
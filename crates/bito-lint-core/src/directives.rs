@@ -3,61 +3,351 @@
 //! Parses HTML comments in the form:
 //! - `<!-- bito-lint disable check1,check2 -->` — suppress checks until re-enabled
 //! - `<!-- bito-lint enable check1,check2 -->` — re-enable previously suppressed checks
+//! - `<!-- bito-lint disable-line check1 -->` — suppress for the directive's own line
 //! - `<!-- bito-lint disable-next-line check1 -->` — suppress for the next line only
+//! - `<!-- bito-lint disable-file check1 -->` — suppress for the whole file,
+//!   wherever the directive appears
+//! - `<!-- bito-lint disable-all -->` — shorthand for `disable all`
+//!
+//! Check names are separated by commas or spaces. A directive that names no
+//! checks, or names `all`, applies to every check; `enable all` ends every
+//! open region. Text after ` -- ` is the reason for the suppression:
+//! `<!-- bito-lint disable jargon -- marketing copy -->`.
+//!
+//! Parsing also records directive mistakes: check names that match no check
+//! and `enable`s with nothing to close. Suppressions that end up hiding no
+//! findings can be listed once checks have run.
 //!
 //! Directives are parsed from the raw input before markdown stripping.
 
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 use std::sync::LazyLock;
 
 use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::ALL_CHECKS;
+
+/// Check name that matches every check.
+pub const ALL: &str = "all";
+
+/// Checks `lint` runs besides the analysis checks.
+const LINT_CHECKS: &[&str] = &["completeness", "frontmatter", "headings", "links", "tokens"];
+
+/// Checks whose findings carry a line, so region directives can hide them.
+///
+/// The rest report on the document as a whole and can only be suppressed
+/// for the whole file.
+const LINE_CHECKS: &[&str] = &[
+    "grammar",
+    "sticky",
+    "sentence_length",
+    "hedging",
+    "wordiness",
+    "ai_tells",
+    "style",
+    "acronyms",
+    "complex_paragraphs",
+    "echoes",
+    "headings",
+    "links",
+];
+
+/// Old check names directives still accept, and the checks they now name.
+const DEPRECATED_NAMES: &[(&str, &str)] = &[("sticky_sentences", "sticky")];
+
+/// Similarity (0.0--1.0) above which an unknown check name gets a suggestion.
+const SUGGESTION_THRESHOLD: f64 = 0.7;
 
 /// Pre-compiled directive regex.
 static DIRECTIVE_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"<!--\s*bito-lint\s+(disable|enable|disable-next-line)\s+([\w,\s]+?)\s*-->")
-        .expect("directive regex should compile")
+    Regex::new(
+        r"<!--\s*bito-lint\s+(disable-next-line|disable-line|disable-file|disable-all|disable|enable)(?:\s+([\w,\s]*?))?(?:\s+--\s+(.*?))?\s*-->",
+    )
+    .expect("directive regex should compile")
 });
 
-/// Map of check names to their suppressed line ranges (1-indexed, inclusive).
+/// What a directive does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectiveKind {
+    /// Suppress until a matching `enable`, or for the whole file if none follows.
+    Disable,
+    /// End a `disable` region.
+    Enable,
+    /// Suppress on the directive's own line.
+    DisableLine,
+    /// Suppress on the line after the directive.
+    DisableNextLine,
+    /// Suppress for the whole file.
+    DisableFile,
+}
+
+impl DirectiveKind {
+    /// The directive keyword, as written in the comment.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Enable => "enable",
+            Self::DisableLine => "disable-line",
+            Self::DisableNextLine => "disable-next-line",
+            Self::DisableFile => "disable-file",
+        }
+    }
+}
+
+/// A parsed directive comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive {
+    /// 1-indexed line the directive is on.
+    pub line: usize,
+    /// What the directive does.
+    pub kind: DirectiveKind,
+    /// Checks the directive names; `["all"]` if it named none.
+    pub checks: Vec<String>,
+    /// Justification after ` -- `, if given.
+    pub reason: Option<String>,
+}
+
+/// A problem with a directive comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DirectiveIssue {
+    /// 1-indexed line of the directive.
+    pub line: usize,
+    /// What is wrong with it.
+    pub kind: DirectiveIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kinds of directive problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DirectiveIssueKind {
+    /// A suppression without a reason, when reasons are required.
+    MissingReason,
+    /// A check name that matches no check.
+    UnknownCheck,
+    /// An `enable` with no open `disable` for its check.
+    UnmatchedEnable,
+    /// A line or region suppression of a check that has no line to hide.
+    FileOnly,
+    /// A suppression that hid no findings.
+    Unused,
+}
+
+/// A suppressed line range (inclusive) and the directive that made it.
+#[derive(Debug, Clone)]
+struct Region {
+    start: usize,
+    end: usize,
+    directive: usize,
+    /// Checks re-enabled inside a `disable all` region, which it no longer covers.
+    except: Vec<String>,
+}
+
+/// Map of check names to where they are suppressed.
 ///
-/// A check present in this map with an empty vec means "file-level suppression"
-/// (disable without a matching enable).
+/// Lookups have no side effects. Callers [`mark_used`](Self::mark_used) the
+/// suppressions that hid a finding, so after checks have run,
+/// [`unused`](Self::unused) lists the suppressions that hid nothing.
 #[derive(Debug, Clone, Default)]
 pub struct SuppressionMap {
-    /// check name → list of suppressed line ranges (start, end) inclusive.
-    suppressed: HashMap<String, Vec<(usize, usize)>>,
+    /// check name → suppressed line ranges.
+    ranges: HashMap<String, Vec<Region>>,
+    /// check name → directives suppressing it for the whole file.
+    file_level: HashMap<String, Vec<usize>>,
+    /// Unclosed `disable all` directive → checks re-enabled after it.
+    except: HashMap<usize, Vec<String>>,
+    /// Every directive, in document order.
+    directives: Vec<Directive>,
+    /// Unknown check names and unmatched `enable`s found while parsing.
+    issues: Vec<DirectiveIssue>,
+    /// (directive index, check name) pairs that suppressed something.
+    used: HashSet<(usize, String)>,
 }
 
 impl SuppressionMap {
     /// Returns `true` if the given check is suppressed at the given line.
     pub fn is_suppressed(&self, check: &str, line: usize) -> bool {
-        match self.suppressed.get(check) {
-            None => false,
-            Some(ranges) => {
-                if ranges.is_empty() {
-                    return true; // File-level suppression
-                }
-                ranges
-                    .iter()
-                    .any(|(start, end)| line >= *start && line <= *end)
-            }
-        }
+        self.suppressing(check, Some(line)).next().is_some()
     }
 
     /// Returns `true` if the given check is suppressed for the entire document.
     pub fn is_fully_suppressed(&self, check: &str) -> bool {
-        matches!(self.suppressed.get(check), Some(ranges) if ranges.is_empty())
+        self.suppressing(check, None).next().is_some()
+    }
+
+    /// Record that a finding of `check` at `line` was hidden, marking every
+    /// suppression covering it used. `None` means a finding about the
+    /// document as a whole, hidden only by file-level suppressions.
+    pub fn mark_used(&mut self, check: &str, line: Option<usize>) {
+        let hits: Vec<(usize, String)> = self
+            .suppressing(check, line)
+            .map(|(directive, name)| (directive, name.to_string()))
+            .collect();
+        self.used.extend(hits);
     }
 
     /// Returns `true` if no suppressions exist.
     pub fn is_empty(&self) -> bool {
-        self.suppressed.is_empty()
+        self.ranges.is_empty() && self.file_level.is_empty()
     }
 
     /// All check names that have any suppression.
     pub fn suppressed_checks(&self) -> HashSet<&str> {
-        self.suppressed.keys().map(String::as_str).collect()
+        self.ranges
+            .keys()
+            .chain(self.file_level.keys())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Every directive in the document, in order.
+    pub fn directives(&self) -> &[Directive] {
+        &self.directives
+    }
+
+    /// Directives naming unknown checks, and `enable`s with no open `disable`.
+    pub fn issues(&self) -> &[DirectiveIssue] {
+        &self.issues
+    }
+
+    /// Suppressing directives that give no reason.
+    pub fn missing_reasons(&self) -> Vec<DirectiveIssue> {
+        self.directives
+            .iter()
+            .filter(|d| d.kind != DirectiveKind::Enable && d.reason.is_none())
+            .map(|d| DirectiveIssue {
+                line: d.line,
+                kind: DirectiveIssueKind::MissingReason,
+                message: format!(
+                    "`{} {}` gives no reason; add one after ` -- `",
+                    d.kind.as_str(),
+                    d.checks.join(",")
+                ),
+            })
+            .collect()
+    }
+
+    /// Suppressions that have not hidden anything so far.
+    ///
+    /// Call after every check has run and been filtered. A line or region
+    /// suppression of a check whose findings carry no line can never hide
+    /// anything, so it is reported as [`FileOnly`](DirectiveIssueKind::FileOnly)
+    /// instead. Unknown check names are left to [`issues`](Self::issues).
+    pub fn unused(&self) -> Vec<DirectiveIssue> {
+        let used = &self.used;
+        self.directives
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.kind != DirectiveKind::Enable)
+            .flat_map(|(index, d)| {
+                d.checks
+                    .iter()
+                    .filter(|check| is_known_check(check))
+                    .filter_map(move |check| {
+                        let (kind, message) =
+                            if !is_line_check(check) && self.has_region(index, check) {
+                                (
+                                    DirectiveIssueKind::FileOnly,
+                                    format!(
+                                        "`{} {check}` has no effect; `{check}` can only be \
+                                         suppressed with `disable-file`",
+                                        d.kind.as_str()
+                                    ),
+                                )
+                            } else if !used.contains(&(index, check.clone())) {
+                                (
+                                    DirectiveIssueKind::Unused,
+                                    format!("`{} {check}` suppressed nothing", d.kind.as_str()),
+                                )
+                            } else {
+                                return None;
+                            };
+                        Some(DirectiveIssue {
+                            line: d.line,
+                            kind,
+                            message,
+                        })
+                    })
+            })
+            .collect()
     }
+
+    /// Whether `directive` suppresses `check` on a range of lines.
+    fn has_region(&self, directive: usize, check: &str) -> bool {
+        self.ranges
+            .get(check)
+            .is_some_and(|regions| regions.iter().any(|r| r.directive == directive))
+    }
+
+    /// Directives suppressing `check` at `line`, or for the whole file if
+    /// `line` is `None`, each with the name it matched (`check` or `all`).
+    fn suppressing<'a>(
+        &'a self,
+        check: &'a str,
+        line: Option<usize>,
+    ) -> impl Iterator<Item = (usize, &'a str)> + 'a {
+        [check, ALL].into_iter().flat_map(move |name| {
+            let covers = move |except: Option<&Vec<String>>| {
+                name != ALL || !except.is_some_and(|except| except.iter().any(|c| c == check))
+            };
+            let file_level = self
+                .file_level
+                .get(name)
+                .into_iter()
+                .flatten()
+                .copied()
+                .filter(move |directive| covers(self.except.get(directive)));
+            let regions = self
+                .ranges
+                .get(name)
+                .into_iter()
+                .flatten()
+                .filter(move |r| {
+                    line.is_some_and(|line| line >= r.start && line <= r.end)
+                        && covers(Some(&r.except))
+                })
+                .map(|r| r.directive);
+            file_level
+                .chain(regions)
+                .map(move |directive| (directive, name))
+        })
+    }
+}
+
+/// Whether `check` names a check directives can suppress, or [`ALL`].
+///
+/// Deprecated names such as `sticky_sentences` are accepted.
+pub fn is_known_check(check: &str) -> bool {
+    let check = canonical_check(check);
+    check == ALL || ALL_CHECKS.contains(&check) || LINT_CHECKS.contains(&check)
+}
+
+/// Whether findings of `check` carry a line, or `check` is [`ALL`].
+fn is_line_check(check: &str) -> bool {
+    check == ALL || LINE_CHECKS.contains(&check)
+}
+
+/// The current name for `check`, which may be a deprecated one.
+fn canonical_check(check: &str) -> &str {
+    DEPRECATED_NAMES
+        .iter()
+        .find(|(old, _)| *old == check)
+        .map_or(check, |(_, new)| *new)
+}
+
+/// The known check name closest to `check`, if any is close.
+fn closest_check(check: &str) -> Option<&'static str> {
+    ALL_CHECKS
+        .iter()
+        .chain(LINT_CHECKS)
+        .map(|name| (name, strsim::normalized_damerau_levenshtein(check, name)))
+        .filter(|(_, score)| *score >= SUGGESTION_THRESHOLD)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(name, _)| *name)
 }
 
 /// Returns `true` if the given line contains a bito-lint directive comment.
@@ -65,58 +355,157 @@ pub fn is_directive_line(line: &str) -> bool {
     DIRECTIVE_RE.is_match(line)
 }
 
+/// Replace each directive comment in `text` with spaces.
+///
+/// Line breaks inside a comment are kept, so line numbers and byte offsets
+/// of the surrounding text are unchanged.
+pub fn blank_directives(text: &str) -> Cow<'_, str> {
+    DIRECTIVE_RE.replace_all(text, |caps: &regex::Captures<'_>| {
+        caps[0]
+            .bytes()
+            .map(|b| if b == b'\n' { '\n' } else { ' ' })
+            .collect::<String>()
+    })
+}
+
 /// Parse suppression directives from raw input text.
 ///
 /// Call this on the original text BEFORE markdown stripping.
 pub fn parse_suppressions(input: &str) -> SuppressionMap {
     let mut map = SuppressionMap::default();
-    let mut open: HashMap<String, usize> = HashMap::new();
+    // check name → (start line, directive index) of an open `disable`.
+    let mut open: HashMap<String, (usize, usize)> = HashMap::new();
+    // Checks re-enabled inside the open `disable all` region.
+    let mut all_except: Vec<String> = Vec::new();
 
     for (line_idx, line_text) in input.lines().enumerate() {
         let line_num = line_idx + 1;
 
         for cap in DIRECTIVE_RE.captures_iter(line_text) {
-            let action = &cap[1];
-            let checks_str = &cap[2];
-            let checks: Vec<String> = checks_str
-                .split(',')
-                .map(|s| s.trim().to_string())
+            let directive = map.directives.len();
+            let kind = match &cap[1] {
+                "disable" | "disable-all" => DirectiveKind::Disable,
+                "enable" => DirectiveKind::Enable,
+                "disable-line" => DirectiveKind::DisableLine,
+                "disable-next-line" => DirectiveKind::DisableNextLine,
+                _ => DirectiveKind::DisableFile,
+            };
+            let mut checks: Vec<String> = cap
+                .get(2)
+                .map_or("", |m| m.as_str())
+                .split(|c: char| c == ',' || c.is_whitespace())
                 .filter(|s| !s.is_empty())
+                .map(|name| canonical_check(name).to_string())
                 .collect();
+            if checks.is_empty() || &cap[1] == "disable-all" {
+                checks = vec![ALL.to_string()];
+            }
+            let reason = cap
+                .get(3)
+                .map(|m| m.as_str().trim().to_string())
+                .filter(|r| !r.is_empty());
 
-            match action {
-                "disable" => {
-                    for check in &checks {
-                        open.insert(check.clone(), line_num);
-                    }
+            // `except` applies only to a `disable all` region.
+            let mut add_range =
+                |start: usize, end: usize, check: &str, directive: usize, except: &[String]| {
+                    let except = if check == ALL {
+                        except.to_vec()
+                    } else {
+                        Vec::new()
+                    };
+                    map.ranges
+                        .entry(check.to_string())
+                        .or_default()
+                        .push(Region {
+                            start,
+                            end,
+                            directive,
+                            except,
+                        });
+                };
+            for check in &checks {
+                if !is_known_check(check) {
+                    let hint = closest_check(check)
+                        .map(|name| format!("; did you mean `{name}`?"))
+                        .unwrap_or_default();
+                    map.issues.push(DirectiveIssue {
+                        line: line_num,
+                        kind: DirectiveIssueKind::UnknownCheck,
+                        message: format!("unknown check `{check}`{hint}"),
+                    });
                 }
-                "enable" => {
-                    for check in &checks {
-                        if let Some(start) = open.remove(check.as_str()) {
-                            map.suppressed
-                                .entry(check.clone())
-                                .or_default()
-                                .push((start, line_num));
+                match kind {
+                    // A second `disable all` covers the checks re-enabled since the first.
+                    DirectiveKind::Disable if check == ALL && !all_except.is_empty() => {
+                        if let Some((start, disable)) =
+                            open.insert(check.clone(), (line_num, directive))
+                        {
+                            add_range(start, line_num, check, disable, &all_except);
                         }
+                        all_except.clear();
                     }
-                }
-                "disable-next-line" => {
-                    let next_line = line_num + 1;
-                    for check in &checks {
-                        map.suppressed
+                    DirectiveKind::Disable => {
+                        open.entry(check.clone()).or_insert((line_num, directive));
+                    }
+                    DirectiveKind::Enable if check == ALL && !open.is_empty() => {
+                        for (check, (start, disable)) in open.drain() {
+                            add_range(start, line_num, &check, disable, &all_except);
+                        }
+                        all_except.clear();
+                    }
+                    DirectiveKind::Enable => {
+                        let own = open.remove(check.as_str());
+                        if let Some((start, disable)) = own {
+                            add_range(start, line_num, check, disable, &[]);
+                        }
+                        if let Some(&(start, disable)) = open.get(ALL)
+                            && !all_except.contains(check)
+                        {
+                            // Split the `disable all` region: it stops covering
+                            // `check` after this line.
+                            add_range(start, line_num, ALL, disable, &all_except);
+                            open.insert(ALL.to_string(), (line_num + 1, disable));
+                            all_except.push(check.clone());
+                        } else if own.is_none() {
+                            map.issues.push(DirectiveIssue {
+                                line: line_num,
+                                kind: DirectiveIssueKind::UnmatchedEnable,
+                                message: format!("`enable {check}` has no open `disable`"),
+                            });
+                        }
+                    }
+                    DirectiveKind::DisableLine => {
+                        add_range(line_num, line_num, check, directive, &[]);
+                    }
+                    DirectiveKind::DisableNextLine => {
+                        add_range(line_num + 1, line_num + 1, check, directive, &[]);
+                    }
+                    DirectiveKind::DisableFile => {
+                        map.file_level
                             .entry(check.clone())
                             .or_default()
-                            .push((next_line, next_line));
+                            .push(directive);
                     }
                 }
-                _ => {}
             }
+
+            map.directives.push(Directive {
+                line: line_num,
+                kind,
+                checks,
+                reason,
+            });
         }
     }
 
-    // Unclosed disable → file-level suppression (empty ranges).
-    for (check, _start) in open {
-        map.suppressed.entry(check).or_default().clear();
+    // Unclosed disable → file-level suppression.
+    // An unclosed `disable all` no longer covers the checks re-enabled after it.
+    for (check, (_start, directive)) in open {
+        if check == ALL && !all_except.is_empty() {
+            map.except
+                .insert(directive, std::mem::take(&mut all_except));
+        }
+        map.file_level.entry(check).or_default().push(directive);
     }
 
     map
@@ -202,4 +591,261 @@ Region 2.
         assert!(!map.is_suppressed("style", 4));
         assert!(map.is_suppressed("style", 6));
     }
+
+    #[test]
+    fn disable_line_covers_own_line() {
+        let input = "Line 1.\nJargon here. <!-- bito-lint disable-line jargon -->\nLine 3.";
+        let map = parse_suppressions(input);
+        assert!(!map.is_suppressed("jargon", 1));
+        assert!(map.is_suppressed("jargon", 2));
+        assert!(!map.is_suppressed("jargon", 3));
+    }
+
+    #[test]
+    fn disable_file_applies_anywhere() {
+        let input = "\
+<!-- bito-lint disable style -->
+Line 2.
+<!-- bito-lint enable style -->
+Line 4.
+<!-- bito-lint disable-file cliches -->";
+        let map = parse_suppressions(input);
+        assert!(map.is_fully_suppressed("cliches"));
+        assert!(map.is_suppressed("cliches", 1));
+        assert!(!map.is_fully_suppressed("style"));
+        assert!(!map.is_suppressed("style", 4));
+    }
+
+    #[test]
+    fn all_and_bare_directives_cover_every_check() {
+        let input = "\
+<!-- bito-lint disable -->
+Line 2.
+<!-- bito-lint enable all -->
+<!-- bito-lint disable-next-line all -->
+Line 5.
+Line 6.";
+        let map = parse_suppressions(input);
+        assert!(map.is_suppressed("grammar", 2));
+        assert!(map.is_suppressed("links", 5));
+        assert!(!map.is_suppressed("grammar", 4));
+        assert!(!map.is_suppressed("links", 6));
+        assert!(!map.is_fully_suppressed("grammar"));
+    }
+
+    #[test]
+    fn disable_all_keyword_covers_every_check() {
+        let input = "\
+<!-- bito-lint disable-all -- vendor copy -->
+Line 2.
+<!-- bito-lint enable -->
+Line 4.";
+        let map = parse_suppressions(input);
+        assert_eq!(map.directives()[0].kind, DirectiveKind::Disable);
+        assert_eq!(map.directives()[0].checks, [ALL]);
+        assert_eq!(map.directives()[0].reason.as_deref(), Some("vendor copy"));
+        assert!(map.is_suppressed("grammar", 2));
+        assert!(map.is_suppressed("links", 2));
+        assert!(!map.is_suppressed("grammar", 4));
+        assert!(map.issues().is_empty());
+    }
+
+    #[test]
+    fn reasons_are_parsed() {
+        let input = "\
+<!-- bito-lint disable jargon cliches -- marketing copy -->
+<!-- bito-lint disable-next-line -- quoted verbatim -->
+<!-- bito-lint disable-line style -->";
+        let map = parse_suppressions(input);
+        let directives = map.directives();
+        assert_eq!(directives[0].checks, ["jargon", "cliches"]);
+        assert_eq!(directives[0].reason.as_deref(), Some("marketing copy"));
+        assert_eq!(directives[1].kind, DirectiveKind::DisableNextLine);
+        assert_eq!(directives[1].checks, [ALL]);
+        assert_eq!(directives[1].reason.as_deref(), Some("quoted verbatim"));
+        assert_eq!(directives[2].reason, None);
+        assert!(map.is_fully_suppressed("cliches"));
+
+        let missing = map.missing_reasons();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].line, 3);
+        assert_eq!(missing[0].kind, DirectiveIssueKind::MissingReason);
+    }
+
+    #[test]
+    fn enable_needs_no_reason() {
+        let input =
+            "<!-- bito-lint disable style -- legacy -->\nText.\n<!-- bito-lint enable style -->";
+        assert!(parse_suppressions(input).missing_reasons().is_empty());
+    }
+
+    #[test]
+    fn unknown_checks_reported_with_suggestion() {
+        let input = "\
+<!-- bito-lint disable-next-line sentance_length -->
+Line 2.
+<!-- bito-lint disable-line sticky_sentance, jargon -->";
+        let issues = parse_suppressions(input).issues().to_vec();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].kind, DirectiveIssueKind::UnknownCheck);
+        assert_eq!(
+            issues[0].message,
+            "unknown check `sentance_length`; did you mean `sentence_length`?"
+        );
+        assert_eq!(issues[1].line, 3);
+        assert!(issues[1].message.contains("`sticky_sentance`"));
+    }
+
+    #[test]
+    fn unmatched_enable_reported() {
+        let input = "\
+<!-- bito-lint disable style -->
+Line 2.
+<!-- bito-lint enable style -->
+<!-- bito-lint enable style -->
+<!-- bito-lint enable all -->";
+        let issues = parse_suppressions(input).issues().to_vec();
+        let lines: Vec<usize> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, [4, 5]);
+        assert!(
+            issues
+                .iter()
+                .all(|i| i.kind == DirectiveIssueKind::UnmatchedEnable)
+        );
+    }
+
+    #[test]
+    fn deprecated_check_names_still_suppress() {
+        let input = "\
+<!-- bito-lint disable sticky_sentences -->
+Line 2.
+<!-- bito-lint enable sticky_sentences -->";
+        let map = parse_suppressions(input);
+        assert!(map.issues().is_empty());
+        assert!(map.is_suppressed("sticky", 2));
+        assert!(!map.is_suppressed("sticky", 4));
+        assert!(is_known_check("sticky_sentences"));
+    }
+
+    #[test]
+    fn unused_lists_suppressions_not_marked_used() {
+        let input = "\
+<!-- bito-lint disable-next-line grammar -->
+Line 2.
+Line 3. <!-- bito-lint disable-line style,hedging -->
+<!-- bito-lint disable-file links -->
+<!-- bito-lint disable-line nope -->";
+        let mut map = parse_suppressions(input);
+        // Lookups alone mark nothing used.
+        assert!(map.is_suppressed("grammar", 2));
+        assert!(map.is_fully_suppressed("links"));
+        assert_eq!(map.unused().len(), 4);
+
+        map.mark_used("grammar", Some(2));
+        map.mark_used("hedging", Some(3));
+        map.mark_used("style", Some(2));
+        map.mark_used("links", None);
+
+        let unused = map.unused();
+        assert_eq!(unused.len(), 1);
+        assert_eq!(unused[0].line, 3);
+        assert_eq!(unused[0].kind, DirectiveIssueKind::Unused);
+        assert_eq!(unused[0].message, "`disable-line style` suppressed nothing");
+    }
+
+    #[test]
+    fn region_suppression_of_file_only_check_is_not_called_unused() {
+        let input = "\
+<!-- bito-lint disable jargon,hedging -->
+Line 2.
+<!-- bito-lint enable jargon,hedging -->
+Line 4. <!-- bito-lint disable-line cliches -->
+<!-- bito-lint disable-file diction -->
+<!-- bito-lint disable overused -->";
+        let map = parse_suppressions(input);
+        assert!(map.issues().is_empty());
+
+        let unused: Vec<_> = map
+            .unused()
+            .into_iter()
+            .map(|i| (i.kind, i.message))
+            .collect();
+        assert_eq!(
+            unused,
+            [
+                (
+                    DirectiveIssueKind::FileOnly,
+                    "`disable jargon` has no effect; `jargon` can only be suppressed with \
+                     `disable-file`"
+                        .to_string()
+                ),
+                (
+                    DirectiveIssueKind::Unused,
+                    "`disable hedging` suppressed nothing".to_string()
+                ),
+                (
+                    DirectiveIssueKind::FileOnly,
+                    "`disable-line cliches` has no effect; `cliches` can only be suppressed \
+                     with `disable-file`"
+                        .to_string()
+                ),
+                (
+                    DirectiveIssueKind::Unused,
+                    "`disable-file diction` suppressed nothing".to_string()
+                ),
+                (
+                    DirectiveIssueKind::Unused,
+                    "`disable overused` suppressed nothing".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn enable_inside_disable_all_reenables_one_check() {
+        let input = "\
+<!-- bito-lint disable all -->
+Line 2.
+<!-- bito-lint enable grammar -->
+Line 4.
+<!-- bito-lint enable all -->
+Line 6.";
+        let map = parse_suppressions(input);
+        assert!(map.issues().is_empty());
+        assert!(map.is_suppressed("grammar", 2));
+        assert!(!map.is_suppressed("grammar", 4));
+        assert!(map.is_suppressed("style", 4));
+        assert!(!map.is_suppressed("style", 6));
+    }
+
+    #[test]
+    fn enable_after_check_and_all_disables_reenables_check() {
+        let input = "\
+<!-- bito-lint disable grammar -->
+<!-- bito-lint disable all -->
+Line 3.
+<!-- bito-lint enable grammar -->
+Line 5.
+<!-- bito-lint enable all -->";
+        let map = parse_suppressions(input);
+        assert!(map.issues().is_empty());
+        assert!(map.is_suppressed("grammar", 3));
+        assert!(!map.is_suppressed("grammar", 5));
+        assert!(map.is_suppressed("style", 5));
+    }
+
+    #[test]
+    fn enable_inside_unclosed_disable_all_exempts_check_from_file_level() {
+        let input = "\
+<!-- bito-lint disable all -->
+Line 2.
+<!-- bito-lint enable grammar -->
+Line 4.";
+        let map = parse_suppressions(input);
+        assert!(map.issues().is_empty());
+        assert!(map.is_fully_suppressed("readability"));
+        assert!(!map.is_fully_suppressed("grammar"));
+        assert!(map.is_suppressed("grammar", 2));
+        assert!(!map.is_suppressed("grammar", 4));
+    }
 }
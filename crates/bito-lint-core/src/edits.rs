@@ -0,0 +1,338 @@
+//! Machine-applicable rewrite suggestions.
+//!
+//! Where the analysis checks report statistics, this module returns edits:
+//! a byte span of the original text, its replacement, and why. Edits come
+//! from hidden verbs ("make a decision" → "decide"), wordy phrases ("in
+//! order to" → "to"), wrong-dialect spellings, and double spaces.
+//!
+//! Only rewrites that are safe to apply blindly are suggested. A hidden verb
+//! is skipped when its light verb cannot be inflected by regular rules or
+//! when a preposition other than "of" follows the noun, since the rewrite
+//! would need more context to stay grammatical.
+//!
+//! Edits never overlap and are sorted by position, so applying them from
+//! last to first keeps earlier offsets valid.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::style::verb_for;
+use crate::analysis::wordiness::{WORDY_RE, match_capitalization};
+use crate::config::Dialect;
+use crate::directives;
+use crate::markdown;
+use crate::word_lists::{
+    LIGHT_VERBS, NOT_NOMINALIZATIONS, SPELLING_PAIRS, SpellingPair, WORDY_PHRASES,
+};
+
+/// Regex for a light verb, optional article, nominalization, and the
+/// preposition after it, if any.
+static HIDDEN_VERB_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b([a-z]+)\s+(?:(?:a|an|the)\s+)?([a-z]+(?:tion|sion|ment|ance|ence|ity|sis))\b(\s+of\b)?(\s+(?:about|to|for|on|into|with)\b)?",
+    )
+    .expect("valid regex")
+});
+
+/// Regex for a single word.
+static WORD_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b[A-Za-z]+\b").expect("valid regex"));
+
+/// Regex for a run of two or more spaces.
+static SPACES_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r" {2,}").expect("valid regex"));
+
+/// Every US and UK spelling, lowercase, mapped to its pair.
+static SPELLINGS: LazyLock<HashMap<&'static str, &'static SpellingPair>> = LazyLock::new(|| {
+    SPELLING_PAIRS
+        .iter()
+        .flat_map(|pair| [(pair.us, pair), (pair.uk, pair)])
+        .collect()
+});
+
+/// A located replacement for part of the input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Edit {
+    /// Check the edit belongs to (`style`, `wordiness`, `consistency`, or
+    /// `grammar`), as named in suppression directives.
+    pub check: String,
+    /// Byte offset where the replaced span starts.
+    pub start: usize,
+    /// Byte offset just past the replaced span.
+    pub end: usize,
+    /// 1-indexed line of `start`.
+    pub line: usize,
+    /// 1-indexed column of `start`, in characters.
+    pub column: usize,
+    /// The text being replaced.
+    pub original: String,
+    /// The text to put in its place.
+    pub replacement: String,
+    /// Why the edit helps.
+    pub rationale: String,
+}
+
+/// Edits suggested for a document.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EditsReport {
+    /// Number of edits.
+    pub total_edits: usize,
+    /// Edits in document order.
+    pub edits: Vec<Edit>,
+}
+
+/// Suggest edits for `text`.
+///
+/// With `strip_md`, only prose is considered: frontmatter, code, and HTML
+/// are left alone. Spelling edits need a `dialect`. Edits on lines where a
+/// directive suppresses their check are dropped.
+#[tracing::instrument(skip_all, fields(input_len = text.len()))]
+pub fn suggest_edits(text: &str, strip_md: bool, dialect: Option<Dialect>) -> EditsReport {
+    let segments = if strip_md {
+        markdown::prose_ranges(text)
+    } else {
+        std::iter::once(0..text.len()).collect()
+    };
+
+    let mut edits = Vec::new();
+    for segment in segments {
+        let base = segment.start;
+        let prose = &text[segment];
+        let mut push = |check: &str, span: Range<usize>, replacement: String, rationale: String| {
+            edits.push(Edit {
+                check: check.to_string(),
+                start: base + span.start,
+                end: base + span.end,
+                line: 0,
+                column: 0,
+                original: prose[span].to_string(),
+                replacement,
+                rationale,
+            });
+        };
+
+        for (span, verb) in hidden_verbs(prose) {
+            let rationale = format!("\"{}\" hides the verb \"{verb}\"", &prose[span.clone()]);
+            push("style", span, verb, rationale);
+        }
+        for m in WORDY_RE.find_iter(prose) {
+            if let Some(replacement) = WORDY_PHRASES.get(m.as_str().to_lowercase().as_str()) {
+                push(
+                    "wordiness",
+                    m.range(),
+                    match_capitalization(m.as_str(), replacement),
+                    format!("\"{replacement}\" says the same in fewer words"),
+                );
+            }
+        }
+        if let Some(dialect) = dialect {
+            for m in WORD_RE.find_iter(prose) {
+                let Some(pair) = SPELLINGS.get(m.as_str().to_lowercase().as_str()) else {
+                    continue;
+                };
+                let preferred = dialect.preferred_form(pair);
+                if !m.as_str().eq_ignore_ascii_case(preferred) {
+                    push(
+                        "consistency",
+                        m.range(),
+                        match_capitalization(m.as_str(), preferred),
+                        format!("{} spelling", dialect.as_str()),
+                    );
+                }
+            }
+        }
+        for m in SPACES_RE.find_iter(prose) {
+            let between_words = m.start() > 0
+                && m.end() < prose.len()
+                && !prose[..m.start()].ends_with(char::is_whitespace)
+                && !prose[m.end()..].starts_with(char::is_whitespace);
+            if between_words {
+                push(
+                    "grammar",
+                    m.range(),
+                    " ".to_string(),
+                    "double space".to_string(),
+                );
+            }
+        }
+    }
+
+    // Keep the earliest (then longest) of any overlapping edits.
+    edits.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    let mut kept: Vec<Edit> = Vec::with_capacity(edits.len());
+    for edit in edits {
+        if kept.last().is_none_or(|last| edit.start >= last.end) {
+            kept.push(edit);
+        }
+    }
+
+    let suppressions = directives::parse_suppressions(text);
+    for edit in &mut kept {
+        let before = &text[..edit.start];
+        edit.line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        edit.column = text[line_start..edit.start].chars().count() + 1;
+    }
+    kept.retain(|edit| !suppressions.is_suppressed(&edit.check, edit.line));
+
+    EditsReport {
+        total_edits: kept.len(),
+        edits: kept,
+    }
+}
+
+/// Light verb + nominalization spans in `prose` with their verb rewrites.
+fn hidden_verbs(prose: &str) -> Vec<(Range<usize>, String)> {
+    let mut found = Vec::new();
+    let mut start = 0;
+
+    while let Some(caps) = HIDDEN_VERB_RE.captures_at(prose, start) {
+        let whole = caps.get(0).expect("whole match");
+        let light = caps[1].to_lowercase();
+        if !LIGHT_VERBS.contains(light.as_str()) {
+            // Retry from the next word, which may itself be a light verb.
+            start = caps.get(1).expect("verb match").end();
+            continue;
+        }
+        start = whole.end();
+
+        let noun = caps[2].to_lowercase();
+        if caps.get(4).is_some() || NOT_NOMINALIZATIONS.contains(noun.as_str()) {
+            continue;
+        }
+        let Some(verb) = verb_for(&noun).and_then(|verb| inflect(&verb, &light)) else {
+            continue;
+        };
+        let end = caps
+            .get(3)
+            .or_else(|| caps.get(2))
+            .expect("noun match")
+            .end();
+        let span = whole.start()..end;
+        found.push((span.clone(), match_capitalization(&prose[span], &verb)));
+    }
+
+    found
+}
+
+/// Inflect `verb` to match the form of `light` ("made" → past tense).
+///
+/// Returns `None` where regular rules might be wrong: verbs ending
+/// consonant–vowel–consonant may double their last letter.
+fn inflect(verb: &str, light: &str) -> Option<String> {
+    let stem_y = verb
+        .strip_suffix('y')
+        .filter(|stem| stem.ends_with(|c: char| !is_vowel(c)));
+    let doubles = {
+        let tail: Vec<char> = verb.chars().rev().take(3).collect();
+        tail.len() == 3
+            && !is_vowel(tail[0])
+            && !"wxy".contains(tail[0])
+            && is_vowel(tail[1])
+            && !is_vowel(tail[2])
+    };
+
+    match light {
+        "make" | "perform" | "conduct" | "give" => Some(verb.to_string()),
+        "makes" | "performs" | "conducts" | "gives" => Some(
+            if ["s", "x", "z", "ch", "sh"]
+                .iter()
+                .any(|s| verb.ends_with(s))
+            {
+                format!("{verb}es")
+            } else if let Some(stem) = stem_y {
+                format!("{stem}ies")
+            } else {
+                format!("{verb}s")
+            },
+        ),
+        "making" | "performing" | "conducting" | "giving" => (!doubles).then(|| {
+            let stem = verb.strip_suffix('e').filter(|stem| !stem.ends_with('e'));
+            format!("{}ing", stem.unwrap_or(verb))
+        }),
+        _ => (!doubles).then(|| {
+            if verb.ends_with('e') {
+                format!("{verb}d")
+            } else if let Some(stem) = stem_y {
+                format!("{stem}ied")
+            } else {
+                format!("{verb}ed")
+            }
+        }),
+    }
+}
+
+/// Whether `c` is a lowercase vowel.
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, report: &EditsReport) -> String {
+        let mut out = text.to_string();
+        for edit in report.edits.iter().rev() {
+            out.replace_range(edit.start..edit.end, &edit.replacement);
+        }
+        out
+    }
+
+    #[test]
+    fn hidden_verbs_rewritten_in_matching_form() {
+        let text = "We made a decision. They perform an analysis of the logs. \
+                    She gives consideration to it.";
+        let report = suggest_edits(text, false, None);
+        assert_eq!(
+            apply(text, &report),
+            "We decided. They analyze the logs. She gives consideration to it."
+        );
+        assert!(report.edits.iter().all(|e| e.check == "style"));
+    }
+
+    #[test]
+    fn wordy_phrases_and_double_spaces() {
+        let text = "In order to ship,  we wait.\nDue to the fact that it rains.";
+        let report = suggest_edits(text, false, None);
+        assert_eq!(apply(text, &report), "To ship, we wait.\nBecause it rains.");
+        let last = report.edits.last().unwrap();
+        assert_eq!((last.line, last.column), (2, 1));
+        assert_eq!(last.check, "wordiness");
+    }
+
+    #[test]
+    fn spellings_follow_dialect() {
+        let text = "The colour of the Center.";
+        assert_eq!(suggest_edits(text, false, None).total_edits, 0);
+        let us = suggest_edits(text, false, Some(Dialect::EnUs));
+        assert_eq!(apply(text, &us), "The color of the Center.");
+        let gb = suggest_edits(text, false, Some(Dialect::EnGb));
+        assert_eq!(apply(text, &gb), "The colour of the Centre.");
+    }
+
+    #[test]
+    fn markdown_code_and_suppressed_lines_untouched() {
+        let text = "Run `in order to` now.\n\n\
+                    <!-- bito-lint disable-next-line wordiness -->\n\
+                    We act in order to win.\n\n\
+                    ```\nin order to\n```\n\n\
+                    We act in order to win.\n";
+        let report = suggest_edits(text, true, None);
+        assert_eq!(report.total_edits, 1);
+        assert_eq!(report.edits[0].line, 10);
+        assert_eq!(report.edits[0].original, "in order to");
+    }
+
+    #[test]
+    fn irregular_inflections_skipped() {
+        assert_eq!(inflect("decide", "made").as_deref(), Some("decided"));
+        assert_eq!(inflect("decide", "making").as_deref(), Some("deciding"));
+        assert_eq!(inflect("apply", "makes").as_deref(), Some("applies"));
+        assert_eq!(inflect("commit", "made"), None);
+    }
+}
@@ -26,6 +26,10 @@ pub enum ConfigError {
     /// A custom entry has neither `instructions` nor `file`.
     #[error("custom entry has neither 'instructions' nor 'file'")]
     CustomEntryEmpty,
+
+    /// Configuration parsed but refers to things that do not exist.
+    #[error("config validation failed: {0}")]
+    Validation(String),
 }
 
 /// Result type alias using [`ConfigError`].
@@ -69,9 +73,80 @@ pub enum AnalysisError {
         max: usize,
     },
 
+    /// A completeness template file could not be read.
+    #[error("failed to read template file {path}: {source}")]
+    TemplateFile {
+        /// The resolved template file path.
+        path: Utf8PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+
+    /// A completeness template definition is invalid.
+    #[error("invalid template {name}: {reason}")]
+    InvalidTemplate {
+        /// The template name.
+        name: String,
+        /// What is wrong with the definition.
+        reason: String,
+    },
+
+    /// An unknown frontmatter schema name was provided.
+    #[error("unknown frontmatter schema: {name}. Defined: {available}")]
+    UnknownSchema {
+        /// The schema name that was requested.
+        name: String,
+        /// Comma-separated list of defined schema names.
+        available: String,
+    },
+
+    /// A frontmatter schema definition is invalid or unreadable.
+    #[error("invalid frontmatter schema {name}: {reason}")]
+    InvalidSchema {
+        /// The schema name.
+        name: String,
+        /// What is wrong with the definition.
+        reason: String,
+    },
+
+    /// An ADR directory could not be read.
+    #[error("failed to read ADR directory {path}: {source}")]
+    AdrDirectory {
+        /// The directory path.
+        path: Utf8PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+
+    /// Document frontmatter is not valid YAML or not a mapping.
+    #[error("invalid frontmatter: {0}")]
+    InvalidFrontmatter(String),
+
     /// A rule specifies conflicting configuration options.
     #[error("conflicting rule config: {0}")]
     ConflictingConfig(String),
+
+    /// A workspace path is absolute or escapes the project root.
+    #[error("path is outside the workspace: {0}")]
+    OutsideWorkspace(String),
+
+    /// A workspace glob pattern could not be compiled.
+    #[error("invalid path pattern {pattern}: {reason}")]
+    InvalidPattern {
+        /// The pattern as given.
+        pattern: String,
+        /// Why it failed to compile.
+        reason: String,
+    },
+
+    /// A workspace file or directory could not be read.
+    #[error("failed to read {path}: {source}")]
+    WorkspaceRead {
+        /// The path that could not be read.
+        path: Utf8PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
 }
 
 /// Result type alias using [`AnalysisError`].
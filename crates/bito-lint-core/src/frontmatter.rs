@@ -0,0 +1,328 @@
+//! Frontmatter schema validation.
+//!
+//! Validates a document's YAML frontmatter against a named schema from
+//! config. A schema is either a JSON Schema file (JSON or YAML) or a simple
+//! per-key spec of types and allowed values; simple specs are compiled to
+//! JSON Schema so both take the same validation path.
+//!
+//! Problems are reported per key as missing keys, wrong types, invalid
+//! values, or other schema violations.
+
+use std::collections::{BTreeMap, HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::config::{FrontmatterField, FrontmatterSchemaConfig, FrontmatterType};
+use crate::error::{AnalysisError, AnalysisResult};
+use crate::markdown;
+
+/// Pattern for `date` fields in simple specs.
+const DATE_PATTERN: &str = r"^\d{4}-\d{2}-\d{2}$";
+
+/// Result of frontmatter validation.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FrontmatterReport {
+    /// Schema name that was checked.
+    pub schema: String,
+    /// Problems found, in schema evaluation order.
+    pub issues: Vec<FrontmatterIssue>,
+    /// Whether the frontmatter satisfied the schema.
+    pub pass: bool,
+}
+
+/// A single frontmatter problem.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FrontmatterIssue {
+    /// Key path the problem refers to (e.g. `status`, `deciders/0`).
+    /// Empty for problems with the frontmatter as a whole.
+    pub key: String,
+    /// Kind of problem.
+    pub kind: FrontmatterIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kinds of frontmatter problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FrontmatterIssueKind {
+    /// A required key is absent.
+    Missing,
+    /// A value has the wrong type.
+    WrongType,
+    /// A value is not one of the allowed values.
+    InvalidValue,
+    /// Any other violation (pattern, range, unparseable frontmatter, ...).
+    Invalid,
+}
+
+/// Validate a document's frontmatter against a named schema.
+///
+/// # Arguments
+///
+/// * `text` — The full markdown document text.
+/// * `schema` — Schema name, as defined under `frontmatter_schemas`.
+/// * `schemas` — Schema definitions from config.
+#[tracing::instrument(skip(text, schemas), fields(text_len = text.len(), schema))]
+pub fn check_frontmatter(
+    text: &str,
+    schema: &str,
+    schemas: Option<&HashMap<String, FrontmatterSchemaConfig>>,
+) -> AnalysisResult<FrontmatterReport> {
+    let definition = load_schema(schema, schemas)?;
+    check_against_schema(text, schema, &definition)
+}
+
+/// Resolve a schema name to a JSON Schema document.
+///
+/// Schema files are read from disk on each call.
+pub fn load_schema(
+    name: &str,
+    schemas: Option<&HashMap<String, FrontmatterSchemaConfig>>,
+) -> AnalysisResult<Value> {
+    let invalid = |reason: String| AnalysisError::InvalidSchema {
+        name: name.to_string(),
+        reason,
+    };
+
+    let Some(config) = schemas.and_then(|s| s.get(name)) else {
+        let mut available: Vec<&str> = schemas
+            .map(|s| s.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        available.sort_unstable();
+        return Err(AnalysisError::UnknownSchema {
+            name: name.to_string(),
+            available: if available.is_empty() {
+                "(none)".to_string()
+            } else {
+                available.join(", ")
+            },
+        });
+    };
+
+    match (&config.file, &config.fields) {
+        (Some(_), Some(_)) => Err(invalid("set either 'file' or 'fields', not both".into())),
+        (None, None) => Err(invalid("set 'file' or 'fields'".into())),
+        (Some(file), None) => {
+            let raw = std::fs::read_to_string(file.as_std_path())
+                .map_err(|e| invalid(format!("failed to read {file}: {e}")))?;
+            // YAML is a superset of JSON, so one parser handles both.
+            serde_yaml::from_str(&raw).map_err(|e| invalid(format!("failed to parse {file}: {e}")))
+        }
+        (None, Some(fields)) => Ok(fields_to_schema(fields)),
+    }
+}
+
+/// Compile a simple per-key spec into an equivalent JSON Schema.
+fn fields_to_schema(fields: &BTreeMap<String, FrontmatterField>) -> Value {
+    let required: Vec<&str> = fields
+        .iter()
+        .filter(|(_, f)| !f.optional)
+        .map(|(k, _)| k.as_str())
+        .collect();
+    let properties: serde_json::Map<String, Value> = fields
+        .iter()
+        .map(|(key, field)| {
+            let mut property = serde_json::Map::new();
+            if let Some(kind) = field.kind {
+                let (type_name, pattern) = match kind {
+                    FrontmatterType::String => ("string", None),
+                    FrontmatterType::Number => ("number", None),
+                    FrontmatterType::Integer => ("integer", None),
+                    FrontmatterType::Boolean => ("boolean", None),
+                    FrontmatterType::List => ("array", None),
+                    FrontmatterType::Map => ("object", None),
+                    FrontmatterType::Date => ("string", Some(DATE_PATTERN)),
+                };
+                property.insert("type".into(), json!(type_name));
+                if let Some(pattern) = pattern {
+                    property.insert("pattern".into(), json!(pattern));
+                }
+            }
+            if let Some(ref values) = field.values {
+                property.insert("enum".into(), Value::Array(values.clone()));
+            }
+            (key.clone(), Value::Object(property))
+        })
+        .collect();
+
+    json!({
+        "type": "object",
+        "required": required,
+        "properties": properties,
+    })
+}
+
+/// Validate a document's frontmatter against a JSON Schema document.
+///
+/// A document without frontmatter is validated as an empty mapping.
+/// Unparseable frontmatter is reported as a single issue rather than an
+/// error, since it is a problem with the document, not the schema.
+pub fn check_against_schema(
+    text: &str,
+    name: &str,
+    schema: &Value,
+) -> AnalysisResult<FrontmatterReport> {
+    let validator =
+        jsonschema::validator_for(schema).map_err(|e| AnalysisError::InvalidSchema {
+            name: name.to_string(),
+            reason: e.to_string(),
+        })?;
+
+    let issues = match markdown::parse_frontmatter(text) {
+        Ok(map) => {
+            let instance = Value::Object(map.unwrap_or_default());
+            validator.iter_errors(&instance).map(to_issue).collect()
+        }
+        Err(e) => vec![FrontmatterIssue {
+            key: String::new(),
+            kind: FrontmatterIssueKind::Invalid,
+            message: e.to_string(),
+        }],
+    };
+
+    Ok(FrontmatterReport {
+        schema: name.to_string(),
+        pass: issues.is_empty(),
+        issues,
+    })
+}
+
+/// Convert a JSON Schema validation error into a report issue.
+fn to_issue(error: jsonschema::ValidationError<'_>) -> FrontmatterIssue {
+    use jsonschema::error::ValidationErrorKind;
+
+    let path = error.instance_path().as_str().trim_start_matches('/');
+    let (key, kind) = match error.kind() {
+        ValidationErrorKind::Required { property } => {
+            let property = property
+                .as_str()
+                .map_or_else(|| property.to_string(), str::to_string);
+            let key = if path.is_empty() {
+                property
+            } else {
+                format!("{path}/{property}")
+            };
+            (key, FrontmatterIssueKind::Missing)
+        }
+        ValidationErrorKind::Type { .. } => (path.to_string(), FrontmatterIssueKind::WrongType),
+        ValidationErrorKind::Enum { .. } | ValidationErrorKind::Constant { .. } => {
+            (path.to_string(), FrontmatterIssueKind::InvalidValue)
+        }
+        _ => (path.to_string(), FrontmatterIssueKind::Invalid),
+    };
+    FrontmatterIssue {
+        key,
+        kind,
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adr_schemas() -> HashMap<String, FrontmatterSchemaConfig> {
+        let yaml = r#"
+adr:
+  fields:
+    status:
+      type: string
+      enum: [proposed, accepted, superseded]
+    date:
+      type: date
+    deciders:
+      type: list
+      optional: true
+"#;
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn valid_frontmatter_passes() {
+        let text = "---\nstatus: accepted\ndate: 2026-03-01\ndeciders: [ana, ben]\n---\n\n# ADR\n";
+        let report = check_frontmatter(text, "adr", Some(&adr_schemas())).unwrap();
+        assert!(report.pass, "{report:?}");
+    }
+
+    #[test]
+    fn missing_key_reported() {
+        let text = "---\nstatus: accepted\n---\n\n# ADR\n";
+        let report = check_frontmatter(text, "adr", Some(&adr_schemas())).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].key, "date");
+        assert_eq!(report.issues[0].kind, FrontmatterIssueKind::Missing);
+    }
+
+    #[test]
+    fn wrong_type_and_enum_reported() {
+        let text = "---\nstatus: done\ndate: 2026-03-01\ndeciders: ana\n---\n";
+        let report = check_frontmatter(text, "adr", Some(&adr_schemas())).unwrap();
+        let kind_of = |key: &str| report.issues.iter().find(|i| i.key == key).unwrap().kind;
+        assert_eq!(kind_of("status"), FrontmatterIssueKind::InvalidValue);
+        assert_eq!(kind_of("deciders"), FrontmatterIssueKind::WrongType);
+    }
+
+    #[test]
+    fn date_fields_require_iso_format() {
+        let text = "---\nstatus: accepted\ndate: March 1st\n---\n";
+        let report = check_frontmatter(text, "adr", Some(&adr_schemas())).unwrap();
+        assert_eq!(report.issues[0].key, "date");
+        assert_eq!(report.issues[0].kind, FrontmatterIssueKind::Invalid);
+    }
+
+    #[test]
+    fn no_frontmatter_reports_all_required_keys() {
+        let report = check_frontmatter("# ADR\n", "adr", Some(&adr_schemas())).unwrap();
+        let keys: Vec<&str> = report.issues.iter().map(|i| i.key.as_str()).collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&"status") && keys.contains(&"date"));
+    }
+
+    #[test]
+    fn unparseable_frontmatter_is_an_issue() {
+        let text = "---\n- just\n- a list\n---\n";
+        let report = check_frontmatter(text, "adr", Some(&adr_schemas())).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.issues[0].kind, FrontmatterIssueKind::Invalid);
+    }
+
+    #[test]
+    fn json_schema_file_is_loaded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = camino::Utf8PathBuf::try_from(dir.path().join("adr.json")).unwrap();
+        std::fs::write(
+            &path,
+            r#"{"type": "object", "required": ["status"], "properties": {"status": {"const": "accepted"}}}"#,
+        )
+        .unwrap();
+        let mut schemas = HashMap::new();
+        schemas.insert(
+            "adr".to_string(),
+            FrontmatterSchemaConfig {
+                file: Some(path),
+                fields: None,
+            },
+        );
+        let report =
+            check_frontmatter("---\nstatus: proposed\n---\n", "adr", Some(&schemas)).unwrap();
+        assert_eq!(report.issues[0].kind, FrontmatterIssueKind::InvalidValue);
+    }
+
+    #[test]
+    fn unknown_schema_errors() {
+        let err = check_frontmatter("", "rfc", Some(&adr_schemas())).unwrap_err();
+        assert!(err.to_string().contains("unknown frontmatter schema: rfc"));
+    }
+
+    #[test]
+    fn schema_with_file_and_fields_errors() {
+        let mut schemas = adr_schemas();
+        schemas.get_mut("adr").unwrap().file = Some("adr.json".into());
+        let err = check_frontmatter("", "adr", Some(&schemas)).unwrap_err();
+        assert!(err.to_string().contains("not both"));
+    }
+}
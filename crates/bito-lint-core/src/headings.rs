@@ -0,0 +1,326 @@
+//! Heading style and structure checking.
+//!
+//! Checks a document's headings for a consistent capitalization style
+//! (title or sentence case), skipped levels, duplicate sibling headings,
+//! more than one H1, and trailing colons or periods.
+//!
+//! Case checking exempts configured proper nouns, acronyms found in the
+//! document, words with internal capitals (`GitHub`, `iOS`), words with
+//! digits, and inline code.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::acronyms::analyze_acronyms;
+use crate::config::{HeadingCase, HeadingsRuleConfig};
+use crate::markdown;
+use crate::word_lists::TITLE_CASE_MINOR_WORDS;
+
+static CODE_SPAN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"`+([^`]+)`+").expect("valid code span pattern"));
+
+/// Result of heading checking.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HeadingsReport {
+    /// Number of headings examined.
+    pub headings: usize,
+    /// Problems found, in document order.
+    pub issues: Vec<HeadingIssue>,
+    /// Whether every heading passed.
+    pub pass: bool,
+}
+
+/// A single heading problem.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HeadingIssue {
+    /// Line number (1-indexed) of the heading.
+    pub line: usize,
+    /// Heading text.
+    pub heading: String,
+    /// Kind of problem.
+    pub kind: HeadingIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kinds of heading problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HeadingIssueKind {
+    /// Capitalization does not match the configured style.
+    Case,
+    /// Heading level jumps by more than one (H2 → H4).
+    SkippedLevel,
+    /// Same heading text as a sibling under the same parent.
+    DuplicateSibling,
+    /// A second (or later) H1.
+    MultipleH1,
+    /// Heading ends with a colon or period.
+    TrailingPunctuation,
+}
+
+/// An open heading while walking the outline, with its children's texts.
+struct OpenHeading {
+    level: u8,
+    text: String,
+    children: HashSet<String>,
+}
+
+/// Check a markdown document's headings.
+#[tracing::instrument(skip(text), fields(text_len = text.len()))]
+pub fn check_headings(text: &str, config: &HeadingsRuleConfig) -> HeadingsReport {
+    let headings = markdown::extract_headings_with_lines(text);
+    let source_lines: Vec<&str> = text.lines().collect();
+
+    let mut exempt: HashSet<String> = config
+        .exceptions
+        .iter()
+        .flat_map(|e| e.split_whitespace())
+        .map(str::to_string)
+        .collect();
+    if config.case.is_some() {
+        exempt.extend(
+            analyze_acronyms(text)
+                .acronym_list
+                .into_iter()
+                .map(|a| a.acronym),
+        );
+    }
+
+    let mut issues = Vec::new();
+    let mut push = |line: usize, heading: &str, kind, message| {
+        issues.push(HeadingIssue {
+            line,
+            heading: heading.to_string(),
+            kind,
+            message,
+        });
+    };
+
+    let mut previous_level: Option<u8> = None;
+    let mut first_h1: Option<usize> = None;
+    let mut top_level: HashSet<String> = HashSet::new();
+    let mut outline: Vec<OpenHeading> = Vec::new();
+
+    for (line, level, heading) in &headings {
+        let (line, level, heading) = (*line, *level, heading.trim());
+
+        if let Some(case) = config.case {
+            let mut heading_exempt = exempt.clone();
+            if let Some(source) = source_lines.get(line - 1) {
+                for caps in CODE_SPAN.captures_iter(source) {
+                    heading_exempt.extend(caps[1].split_whitespace().map(str::to_string));
+                }
+            }
+            if let Some(message) = case_problem(heading, case, &heading_exempt) {
+                push(line, heading, HeadingIssueKind::Case, message);
+            }
+        }
+
+        if let Some(prev) = previous_level
+            && level > prev + 1
+        {
+            let message = format!("skipped heading level: H{prev} to H{level}");
+            push(line, heading, HeadingIssueKind::SkippedLevel, message);
+        }
+        previous_level = Some(level);
+
+        if level == 1 {
+            if let Some(first) = first_h1 {
+                let message = format!("multiple H1 headings (first on line {first})");
+                push(line, heading, HeadingIssueKind::MultipleH1, message);
+            } else {
+                first_h1 = Some(line);
+            }
+        }
+
+        while outline.last().is_some_and(|open| open.level >= level) {
+            outline.pop();
+        }
+        let key = heading.to_lowercase();
+        let (siblings, location) = match outline.last_mut() {
+            Some(parent) => (&mut parent.children, format!("under '{}'", parent.text)),
+            None => (&mut top_level, "at top level".to_string()),
+        };
+        if !siblings.insert(key) {
+            let message = format!("duplicate heading '{heading}' {location}");
+            push(line, heading, HeadingIssueKind::DuplicateSibling, message);
+        }
+        outline.push(OpenHeading {
+            level,
+            text: heading.to_string(),
+            children: HashSet::new(),
+        });
+
+        if heading.ends_with(':') || (heading.ends_with('.') && !heading.ends_with("...")) {
+            let punctuation = if heading.ends_with(':') {
+                "colon"
+            } else {
+                "period"
+            };
+            let message = format!("heading ends with a {punctuation}");
+            push(
+                line,
+                heading,
+                HeadingIssueKind::TrailingPunctuation,
+                message,
+            );
+        }
+    }
+
+    HeadingsReport {
+        headings: headings.len(),
+        pass: issues.is_empty(),
+        issues,
+    }
+}
+
+/// Describe words that break the case style, or `None` if the heading complies.
+fn case_problem(heading: &str, case: HeadingCase, exempt: &HashSet<String>) -> Option<String> {
+    let words: Vec<&str> = heading.split_whitespace().collect();
+    let mut should_capitalize = Vec::new();
+    let mut should_lowercase = Vec::new();
+
+    for (i, raw) in words.iter().enumerate() {
+        let word = raw.trim_matches(|c: char| !c.is_alphanumeric());
+        // Hyphenated compounds are judged by their first part.
+        let part = word.split('-').next().unwrap_or(word);
+        let Some(first) = part.chars().next() else {
+            continue;
+        };
+        if !first.is_alphabetic()
+            || part.chars().any(|c| c.is_ascii_digit())
+            || part.chars().skip(1).any(char::is_uppercase)
+            || exempt.contains(word)
+            || exempt.contains(part)
+        {
+            continue;
+        }
+
+        let after_colon = i > 0 && words[i - 1].ends_with(':');
+        let upper = first.is_uppercase();
+        match case {
+            HeadingCase::Sentence => {
+                if i == 0 && !upper {
+                    should_capitalize.push(word);
+                } else if i > 0 && !after_colon && upper {
+                    should_lowercase.push(word);
+                }
+            }
+            HeadingCase::Title => {
+                let edge = i == 0 || i == words.len() - 1 || after_colon;
+                let minor = TITLE_CASE_MINOR_WORDS.contains(part.to_lowercase().as_str());
+                if (edge || !minor) && !upper {
+                    should_capitalize.push(word);
+                } else if !edge && minor && upper {
+                    should_lowercase.push(word);
+                }
+            }
+        }
+    }
+
+    if should_capitalize.is_empty() && should_lowercase.is_empty() {
+        return None;
+    }
+    let style = match case {
+        HeadingCase::Title => "title case",
+        HeadingCase::Sentence => "sentence case",
+    };
+    let quote = |words: &[&str]| {
+        words
+            .iter()
+            .map(|w| format!("'{w}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let mut fixes = Vec::new();
+    if !should_capitalize.is_empty() {
+        fixes.push(format!("capitalize {}", quote(&should_capitalize)));
+    }
+    if !should_lowercase.is_empty() {
+        fixes.push(format!("lowercase {}", quote(&should_lowercase)));
+    }
+    Some(format!("not {style}: {}", fixes.join("; ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(case: Option<HeadingCase>, exceptions: &[&str]) -> HeadingsRuleConfig {
+        HeadingsRuleConfig {
+            case,
+            exceptions: exceptions.iter().map(|s| (*s).to_string()).collect(),
+        }
+    }
+
+    fn kinds(report: &HeadingsReport) -> Vec<HeadingIssueKind> {
+        report.issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn sentence_case_with_exemptions() {
+        let text = "# Getting started with Rust on GitHub\n\n\
+                    The API is small.\n\n\
+                    ## Using the API from `Config`\n\n\
+                    ## Step 2: Configure the server\n\n\
+                    ## Advanced Topics\n";
+        let report = check_headings(text, &config(Some(HeadingCase::Sentence), &["Rust"]));
+        assert_eq!(kinds(&report), vec![HeadingIssueKind::Case]);
+        assert_eq!(report.issues[0].line, 9);
+        assert!(report.issues[0].message.contains("lowercase 'Topics'"));
+    }
+
+    #[test]
+    fn title_case_minor_words() {
+        let text = "# Getting Started With the API\n\n\
+                    ## Working In the Cloud\n\n\
+                    ## Where to go\n";
+        let report = check_headings(text, &config(Some(HeadingCase::Title), &[]));
+        assert_eq!(report.issues.len(), 2);
+        assert!(report.issues[0].message.contains("lowercase 'In'"));
+        assert!(report.issues[1].message.contains("capitalize 'go'"));
+    }
+
+    #[test]
+    fn case_not_checked_without_style() {
+        let text = "# getting Started\n";
+        assert!(check_headings(text, &config(None, &[])).pass);
+    }
+
+    #[test]
+    fn structure_problems_reported() {
+        let text = "# Guide\n\n### Deep\n\n# Second\n\n## Usage\n\n## Usage\n\n\
+                    ### Notes\n\n## Other\n\n### Notes\n";
+        let report = check_headings(text, &config(None, &[]));
+        assert_eq!(
+            kinds(&report),
+            vec![
+                HeadingIssueKind::SkippedLevel,
+                HeadingIssueKind::MultipleH1,
+                HeadingIssueKind::DuplicateSibling,
+            ]
+        );
+        assert_eq!(
+            report.issues[2].message,
+            "duplicate heading 'Usage' under 'Second'"
+        );
+    }
+
+    #[test]
+    fn trailing_colon_and_period_flagged() {
+        let text = "# Guide\n\n## Options:\n\n## The end.\n\n## Wait...\n\n## Why?\n";
+        let report = check_headings(text, &config(None, &[]));
+        assert_eq!(
+            kinds(&report),
+            vec![
+                HeadingIssueKind::TrailingPunctuation,
+                HeadingIssueKind::TrailingPunctuation
+            ]
+        );
+    }
+}
@@ -11,8 +11,15 @@
 //! - [`tokens`] — Pluggable token counting (Claude / OpenAI backends)
 //! - [`readability`] — Flesch-Kincaid Grade Level scoring
 //! - [`completeness`] — Template section validation
+//! - [`frontmatter`] — Frontmatter schema validation
+//! - [`headings`] — Heading style and structure checking
+//! - [`links`] — Local link and anchor checking
+//! - [`scaffold`] — New documents from completeness templates
+//! - [`adr`] — Architecture Decision Record collection validation
 //! - [`grammar`] — Grammar checking and passive voice detection
-//! - [`analysis`] — Comprehensive writing analysis (18 features)
+//! - [`analysis`] — Comprehensive writing analysis (21 features)
+//! - [`edits`] — Machine-applicable rewrite suggestions
+//! - [`workspace`] — Sandboxed path resolution and reads under a project root
 //!
 //! # Quick Start
 //!
@@ -24,20 +31,27 @@
 //! ```
 #![deny(unsafe_code)]
 
+pub mod adr;
 pub mod analysis;
 pub mod completeness;
 pub mod config;
 pub mod dictionaries;
 pub mod directives;
+pub mod edits;
 pub mod error;
+pub mod frontmatter;
 pub mod grammar;
+pub mod headings;
+pub mod links;
 pub mod lint;
 pub mod markdown;
 pub mod readability;
 pub mod rules;
+pub mod scaffold;
 pub mod text;
 pub mod tokens;
 pub mod word_lists;
+pub mod workspace;
 
 pub use config::{Config, ConfigLoader, CustomEntry, Dialect, LogLevel};
 pub use error::{AnalysisError, AnalysisResult, ConfigError, ConfigResult};
@@ -0,0 +1,498 @@
+//! Local link and anchor checking.
+//!
+//! Verifies a markdown document's links without touching the network:
+//! relative file targets must exist on disk, `#anchor` fragments must match
+//! a heading (GitHub slug rules) or an HTML `id`/`name` in the target file,
+//! and full or collapsed reference links (`[text][ref]`, `[ref][]`) must
+//! have a definition. External URLs are optionally checked for syntax only.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use pulldown_cmark::{BrokenLink, Event, LinkType, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::markdown;
+
+static SCHEME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*:").expect("valid scheme pattern"));
+
+static HTML_ANCHOR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)\b(?:id|name)\s*=\s*["']([^"']+)["']"#).expect("valid anchor pattern")
+});
+
+/// Result of link checking.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LinksReport {
+    /// Number of links and images examined.
+    pub links_checked: usize,
+    /// Problems found, in document order.
+    pub issues: Vec<LinkIssue>,
+    /// Whether every link resolved.
+    pub pass: bool,
+}
+
+/// A single broken link.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LinkIssue {
+    /// Line number (1-indexed) where the link starts.
+    pub line: usize,
+    /// Link target as written (or the reference label for undefined references).
+    pub target: String,
+    /// Kind of problem.
+    pub kind: LinkIssueKind,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Kinds of link problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkIssueKind {
+    /// A relative link points at a file that does not exist.
+    MissingFile,
+    /// A `#fragment` does not match any heading or anchor in the target.
+    MissingAnchor,
+    /// A reference link has no matching definition.
+    UndefinedReference,
+    /// An external URL is malformed (only with URL checking enabled).
+    InvalidUrl,
+    /// A relative link resolves outside the root it is confined to.
+    OutsideRoot,
+}
+
+/// Check every link and image in a markdown document.
+///
+/// # Arguments
+///
+/// * `text` — The full markdown document text.
+/// * `base_dir` — Directory relative targets resolve against (normally the
+///   document's own directory). `None` skips file and cross-file anchor checks.
+/// * `root` — If set, canonical directory relative targets must stay inside.
+///   Targets that leave it are reported without being looked up.
+/// * `check_urls` — Syntax-check external URLs. URLs are never fetched.
+#[tracing::instrument(skip(text), fields(text_len = text.len()))]
+pub fn check_links(
+    text: &str,
+    base_dir: Option<&Utf8Path>,
+    root: Option<&Utf8Path>,
+    check_urls: bool,
+) -> LinksReport {
+    let body = blank_frontmatter(text);
+    let lines = LineIndex::new(&body);
+    let own_anchors = anchors(&body);
+    let mut file_anchors: HashMap<Utf8PathBuf, Option<HashSet<String>>> = HashMap::new();
+
+    let mut issues = Vec::new();
+    let mut links_checked = 0;
+    let mut undefined = Vec::new();
+    {
+        let callback = |broken: BrokenLink<'_>| {
+            // Shortcut links (`[text]`) are indistinguishable from bracketed
+            // prose, so only full and collapsed references are reported.
+            if matches!(broken.link_type, LinkType::Reference | LinkType::Collapsed) {
+                undefined.push((broken.span.start, broken.reference.to_string()));
+            }
+            None
+        };
+        let parser = Parser::new_with_broken_link_callback(&body, parser_options(), Some(callback));
+        for (event, range) in parser.into_offset_iter() {
+            let (Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                ..
+            })
+            | Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                ..
+            })) = event
+            else {
+                continue;
+            };
+            if link_type == LinkType::Email {
+                continue;
+            }
+            links_checked += 1;
+            let problem = check_target(
+                &dest_url,
+                base_dir,
+                root,
+                check_urls,
+                &own_anchors,
+                &mut file_anchors,
+            );
+            if let Some((kind, message)) = problem {
+                issues.push(LinkIssue {
+                    line: lines.line_of(range.start),
+                    target: dest_url.to_string(),
+                    kind,
+                    message,
+                });
+            }
+        }
+    }
+
+    links_checked += undefined.len();
+    issues.extend(undefined.into_iter().map(|(offset, reference)| LinkIssue {
+        line: lines.line_of(offset),
+        message: format!("no definition for reference [{reference}]"),
+        target: reference,
+        kind: LinkIssueKind::UndefinedReference,
+    }));
+    issues.sort_by_key(|i| i.line);
+
+    LinksReport {
+        links_checked,
+        pass: issues.is_empty(),
+        issues,
+    }
+}
+
+/// Check one link destination, returning the problem if it does not resolve.
+fn check_target(
+    dest: &str,
+    base_dir: Option<&Utf8Path>,
+    root: Option<&Utf8Path>,
+    check_urls: bool,
+    own_anchors: &HashSet<String>,
+    file_anchors: &mut HashMap<Utf8PathBuf, Option<HashSet<String>>>,
+) -> Option<(LinkIssueKind, String)> {
+    if let Some(fragment) = dest.strip_prefix('#') {
+        return missing_anchor(fragment, own_anchors, "this document");
+    }
+
+    if dest.starts_with("//") || SCHEME.is_match(dest) {
+        if !check_urls {
+            return None;
+        }
+        let absolute = if dest.starts_with("//") {
+            format!("https:{dest}")
+        } else {
+            dest.to_string()
+        };
+        return match url::Url::parse(&absolute) {
+            Ok(_) => None,
+            Err(e) => Some((LinkIssueKind::InvalidUrl, format!("invalid URL: {e}"))),
+        };
+    }
+
+    // Root-relative links depend on where the site or repo is served from.
+    let base_dir = base_dir?;
+    if dest.starts_with('/') {
+        return None;
+    }
+
+    let (path, fragment) = dest.split_once('#').unwrap_or((dest, ""));
+    let path = path.split_once('?').map_or(path, |(p, _)| p);
+    let target = base_dir.join(percent_decode(path));
+    if let Some(root) = root
+        && !is_within(&target, root)
+    {
+        return Some((
+            LinkIssueKind::OutsideRoot,
+            format!("{dest} is outside the project root"),
+        ));
+    }
+    if !target.exists() {
+        return Some((
+            LinkIssueKind::MissingFile,
+            format!("{target} does not exist"),
+        ));
+    }
+    if fragment.is_empty() || !is_markdown(&target) {
+        return None;
+    }
+    let anchors = file_anchors
+        .entry(target.clone())
+        .or_insert_with(|| {
+            std::fs::read_to_string(target.as_std_path())
+                .ok()
+                .map(|text| anchors(&blank_frontmatter(&text)))
+        })
+        .as_ref()?;
+    missing_anchor(fragment, anchors, target.as_str())
+}
+
+/// Whether `path` lies inside the canonical directory `root`, both as
+/// written and, if it exists, once symlinks are resolved.
+///
+/// The lexical check comes first so a path outside `root` is never looked up.
+fn is_within(path: &Utf8Path, root: &Utf8Path) -> bool {
+    let mut lexical = Utf8PathBuf::new();
+    for component in path.components() {
+        match component {
+            Utf8Component::CurDir => {}
+            Utf8Component::ParentDir => {
+                lexical.pop();
+            }
+            other => lexical.push(other.as_str()),
+        }
+    }
+    lexical.starts_with(root)
+        && lexical
+            .canonicalize_utf8()
+            .map_or(true, |real| real.starts_with(root))
+}
+
+fn missing_anchor(
+    fragment: &str,
+    anchors: &HashSet<String>,
+    target: &str,
+) -> Option<(LinkIssueKind, String)> {
+    // An empty fragment (`#`) links to the top of the page.
+    if fragment.is_empty() {
+        return None;
+    }
+    let wanted = percent_decode(fragment).to_lowercase();
+    if anchors.contains(&wanted) {
+        return None;
+    }
+    Some((
+        LinkIssueKind::MissingAnchor,
+        format!("no heading or anchor '#{fragment}' in {target}"),
+    ))
+}
+
+fn is_markdown(path: &Utf8Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// Anchors a document exposes: heading slugs plus HTML `id`/`name` attributes.
+fn anchors(text: &str) -> HashSet<String> {
+    let mut anchors: HashSet<String> = heading_slugs(text).into_iter().collect();
+    for event in Parser::new_ext(text, parser_options()) {
+        if let Event::Html(html) | Event::InlineHtml(html) = event {
+            anchors.extend(
+                HTML_ANCHOR
+                    .captures_iter(&html)
+                    .map(|caps| caps[1].to_lowercase()),
+            );
+        }
+    }
+    anchors
+}
+
+/// Heading anchors in document order, as GitHub generates them.
+///
+/// Repeated headings get `-1`, `-2`, ... suffixes, matching GitHub.
+pub fn heading_slugs(text: &str) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut slugs = Vec::new();
+    let mut heading: Option<String> = None;
+    for event in Parser::new_ext(text, parser_options()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::Text(t) | Event::Code(t) => {
+                if let Some(ref mut h) = heading {
+                    h.push_str(&t);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                let Some(h) = heading.take() else { continue };
+                let base = slugify(&h);
+                let count = seen.entry(base.clone()).or_insert(0);
+                let slug = if *count == 0 {
+                    base.clone()
+                } else {
+                    format!("{base}-{count}")
+                };
+                *count += 1;
+                slugs.push(slug);
+            }
+            _ => {}
+        }
+    }
+    slugs
+}
+
+/// GitHub-style heading slug: lowercase, punctuation removed, spaces to hyphens.
+///
+/// `"Step 2: Configure the API"` → `"step-2-configure-the-api"`.
+pub fn slugify(heading: &str) -> String {
+    heading
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+const fn parser_options() -> Options {
+    Options::ENABLE_TABLES
+        .union(Options::ENABLE_STRIKETHROUGH)
+        .union(Options::ENABLE_FOOTNOTES)
+}
+
+/// Replace frontmatter with blank lines so it is not parsed as markdown
+/// (its closing `---` would otherwise turn the last key into a heading)
+/// while keeping line numbers aligned with the source.
+fn blank_frontmatter(text: &str) -> String {
+    if markdown::extract_frontmatter(text).is_none() {
+        return text.to_string();
+    }
+    let body = markdown::strip_frontmatter(text);
+    let prefix = &text[..text.len() - body.len()];
+    let mut out = "\n".repeat(prefix.matches('\n').count());
+    out.push_str(&body);
+    out
+}
+
+/// Decode `%XX` escapes (e.g. `%20` in file names). Invalid escapes are kept.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = s.get(i + 1..i + 3)
+            && let Ok(byte) = u8::from_str_radix(hex, 16)
+        {
+            out.push(byte);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(out).unwrap_or_else(|_| s.to_string())
+}
+
+/// Byte offset to 1-indexed line number lookup.
+struct LineIndex {
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(text: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { starts }
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.starts.partition_point(|&start| start <= offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(report: &LinksReport) -> Vec<LinkIssueKind> {
+        report.issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn slugs_follow_github_rules() {
+        assert_eq!(
+            slugify("Step 2: Configure the API"),
+            "step-2-configure-the-api"
+        );
+        assert_eq!(slugify("What's `new`?"), "whats-new");
+        assert_eq!(slugify("A -- B"), "a----b");
+        assert_eq!(slugify("Über_alles"), "über_alles");
+        let text = "# FAQ\n\n## Setup\n\n## Setup\n\n## Setup\n";
+        assert_eq!(
+            heading_slugs(text),
+            vec!["faq", "setup", "setup-1", "setup-2"]
+        );
+    }
+
+    #[test]
+    fn in_document_anchors_checked() {
+        let text = "# Guide\n\n## Getting Started\n\nSee [start](#getting-started) \
+                    and [nope](#missing).\n\n<a id=\"custom\"></a>\n\n[custom](#custom)\n";
+        let report = check_links(text, None, None, false);
+        assert_eq!(report.links_checked, 3);
+        assert_eq!(kinds(&report), vec![LinkIssueKind::MissingAnchor]);
+        assert_eq!(report.issues[0].line, 5);
+        assert_eq!(report.issues[0].target, "#missing");
+    }
+
+    #[test]
+    fn undefined_references_reported_but_shortcuts_ignored() {
+        let text = "Use [the guide][guide] and [docs][].\n\n\
+                    A [shortcut] or [ ] checkbox is fine.\n\n[docs]: docs.md\n";
+        let report = check_links(text, None, None, false);
+        assert_eq!(kinds(&report), vec![LinkIssueKind::UndefinedReference]);
+        assert_eq!(report.issues[0].target, "guide");
+    }
+
+    #[test]
+    fn relative_files_and_anchors_checked_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        std::fs::write(
+            base.join("other.md"),
+            "---\ntitle: Other\n---\n# Other\n\n## Install Steps\n",
+        )
+        .unwrap();
+        std::fs::write(base.join("my file.md"), "# Spaces\n").unwrap();
+
+        let text = "[ok](other.md#install-steps)\n\
+                    [spaces](my%20file.md)\n\
+                    [bad anchor](other.md#title)\n\
+                    [gone](missing.md)\n\
+                    ![img](img/logo.png)\n\
+                    [root](/elsewhere.md)\n";
+        let report = check_links(text, Some(&base), None, false);
+        assert_eq!(
+            kinds(&report),
+            vec![
+                LinkIssueKind::MissingAnchor,
+                LinkIssueKind::MissingFile,
+                LinkIssueKind::MissingFile,
+            ]
+        );
+        assert_eq!(
+            report.issues.iter().map(|i| i.line).collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn targets_outside_root_reported_without_lookup() {
+        let dir = tempfile::tempdir().unwrap();
+        let outer = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+        let root = outer.join("project");
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("README.md"), "# Readme\n").unwrap();
+        std::fs::write(outer.join("secret.md"), "# Secret\n").unwrap();
+
+        let text = "[up](../README.md#readme)\n\
+                    [out](../../secret.md#secret)\n\
+                    [abs](../../../../../../etc/hosts)\n";
+        let report = check_links(text, Some(&root.join("docs")), Some(&root), false);
+        assert_eq!(
+            kinds(&report),
+            vec![LinkIssueKind::OutsideRoot, LinkIssueKind::OutsideRoot]
+        );
+    }
+
+    #[test]
+    fn external_urls_only_checked_when_enabled() {
+        let text = "[a](https://example.com/x) [b](http://) [c](mailto:me@example.com)\n";
+        assert!(check_links(text, None, None, false).pass);
+        let report = check_links(text, None, None, true);
+        assert_eq!(kinds(&report), vec![LinkIssueKind::InvalidUrl]);
+        assert_eq!(report.issues[0].target, "http://");
+    }
+
+    #[test]
+    fn frontmatter_lines_are_preserved() {
+        let text = "---\ntitle: x\n---\n\n[bad](#nowhere)\n";
+        let report = check_links(text, None, None, false);
+        assert_eq!(report.issues[0].line, 5);
+    }
+}
@@ -3,17 +3,21 @@
 //! Runs the checks specified by [`ResolvedChecks`] against file content,
 //! applying project-wide config defaults where rule-level settings are absent.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
+use camino::{Utf8Path, Utf8PathBuf};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::analysis::{self, ALL_CHECKS, FullAnalysisReport};
+use crate::analysis::{self, ALL_CHECKS, AnalysisOptions, FullAnalysisReport, style};
 use crate::completeness::{self, CompletenessReport};
-use crate::config::{AnalyzeRuleConfig, Config};
-use crate::directives::{self, SuppressionMap};
+use crate::config::{AnalyzeRuleConfig, Config, StyleComponent, StyleScoreConfig};
+use crate::directives::{self, DirectiveIssue, SuppressionMap};
 use crate::error::{AnalysisError, AnalysisResult};
+use crate::frontmatter::{self, FrontmatterReport};
 use crate::grammar::{self, GrammarReport};
+use crate::headings::{self, HeadingsReport};
+use crate::links::{self, LinksReport};
 use crate::readability::{self, ReadabilityReport};
 use crate::rules::ResolvedChecks;
 use crate::text;
@@ -24,7 +28,10 @@ use crate::tokens::{self, TokenReport};
 pub struct LintReport {
     /// The file that was linted.
     pub file: String,
-    /// Full analysis report (18 checks), if `analyze` was configured.
+    /// `false` if no rule matches the file, so no checks ran; omitted otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matched: Option<bool>,
+    /// Full analysis report (21 checks), if `analyze` was configured.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analyze: Option<FullAnalysisReport>,
     /// Standalone readability report, if `readability` was configured.
@@ -36,87 +43,157 @@ pub struct LintReport {
     /// Completeness report, if `completeness` was configured.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub completeness: Option<CompletenessReport>,
+    /// Frontmatter report, if `frontmatter` was configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frontmatter: Option<FrontmatterReport>,
+    /// Headings report, if `headings` was configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headings: Option<HeadingsReport>,
+    /// Links report, if `links` was configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<LinksReport>,
     /// Token count report, if `tokens` was configured.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tokens: Option<TokenReport>,
+    /// Problems with suppression directives: unknown check names, unmatched
+    /// `enable`s, and, when configured, missing reasons and unused suppressions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub directives: Vec<DirectiveIssue>,
     /// Overall pass/fail. `true` only if every check passes its thresholds.
     pub pass: bool,
+    /// Names of the checks that failed, in run order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<String>,
 }
 
 /// Run all checks specified by `resolved` against `content`.
 ///
 /// Settings cascade: rule-level overrides config-level defaults.
-/// The `file_path` is used for reporting only.
-/// The `config` provides project-wide defaults and custom templates.
+/// The `file_path` is used for reporting and as the base for relative
+/// link targets.
+/// The `config` provides project-wide defaults, custom templates, and
+/// frontmatter schemas.
 pub fn run_lint(
     file_path: &str,
     content: &str,
     resolved: &ResolvedChecks,
     config: &Config,
+) -> AnalysisResult<LintReport> {
+    lint(None, file_path, content, resolved, config)
+}
+
+/// Like [`run_lint`], for a `file_path` relative to the canonical directory
+/// `root`.
+///
+/// Relative link targets resolve against `root` and may not leave it, so
+/// linting untrusted paths never looks at files elsewhere on disk.
+pub fn run_lint_within(
+    root: &Utf8Path,
+    file_path: &str,
+    content: &str,
+    resolved: &ResolvedChecks,
+    config: &Config,
+) -> AnalysisResult<LintReport> {
+    lint(Some(root), file_path, content, resolved, config)
+}
+
+fn lint(
+    root: Option<&Utf8Path>,
+    file_path: &str,
+    content: &str,
+    resolved: &ResolvedChecks,
+    config: &Config,
 ) -> AnalysisResult<LintReport> {
     let strip_md = file_path.ends_with(".md");
-    let suppressions = directives::parse_suppressions(content);
-    let mut pass = true;
+    let mut suppressions = directives::parse_suppressions(content);
+    let mut failed: Vec<&str> = Vec::new();
+    // Checks suppressed for the whole file only run when their directives
+    // might be reported as unused.
+    let skip = |suppressions: &SuppressionMap, check: &str| {
+        !config.report_unused_directives && suppressions.is_fully_suppressed(check)
+    };
 
     // --- analyze ---
-    let analyze_report = if let Some(ref ac) = resolved.analyze {
-        // Filter out fully-suppressed sub-checks from the analyze check list.
-        let mut check_list = resolve_analyze_checks(ac)?;
-        if !suppressions.is_empty()
-            && let Some(ref mut checks) = check_list
-        {
-            checks.retain(|c| !suppressions.is_fully_suppressed(c));
-        }
-        // If all requested checks were suppressed, skip analyze entirely.
-        if check_list.as_ref().is_some_and(|c| c.is_empty()) {
-            None
-        } else {
-            let max_grade = ac.max_grade.or(config.max_grade);
-            let passive_max = ac.passive_max.or(config.passive_max_percent);
-            let dialect = ac.dialect.or(config.dialect);
-            let checks_ref = check_list.as_deref();
-
-            let mut report = analysis::run_full_analysis(
-                content,
-                strip_md,
-                checks_ref,
-                max_grade,
-                passive_max,
-                dialect,
-            )?;
-            // Region-level suppression filtering for analysis sub-reports
-            if !suppressions.is_empty() {
-                filter_analysis_report(&mut report, content, &suppressions);
-            }
-            let style_min = ac.style_min.or(config.style_min_score);
-            if let (Some(min), Some(st)) = (style_min, &report.style)
-                && st.style_score < min
-            {
-                pass = false;
+    let mut check_list = match resolved.analyze {
+        Some(ref ac) => resolve_analyze_checks(ac)?,
+        None => None,
+    };
+    if let Some(ref mut checks) = check_list {
+        checks.retain(|c| !skip(&suppressions, c));
+    }
+    // If all requested checks were suppressed, skip analyze entirely.
+    let analyze_report = if let Some(ref ac) = resolved.analyze
+        && !check_list.as_ref().is_some_and(Vec::is_empty)
+    {
+        let options = AnalysisOptions {
+            max_grade: ac.max_grade.or(config.max_grade),
+            passive_max: ac.passive_max.or(config.passive_max_percent),
+            dialect: ac.dialect.or(config.dialect),
+            known_acronyms: ac
+                .known_acronyms
+                .clone()
+                .or_else(|| config.known_acronyms.clone())
+                .unwrap_or_default(),
+            style_score: ac
+                .style_score
+                .clone()
+                .or_else(|| config.style_score.clone()),
+        };
+
+        let mut report =
+            analysis::run_full_analysis(content, strip_md, check_list.as_deref(), &options)?;
+        // Region-level suppression filtering for analysis sub-reports
+        let mut remaining = check_list.as_ref().map_or(ALL_CHECKS.len(), Vec::len);
+        if !suppressions.is_empty() {
+            filter_analysis_report(&mut report, content, &mut suppressions);
+            let default_formula = StyleScoreConfig::default();
+            let formula = options.style_score.as_ref().unwrap_or(&default_formula);
+            rescore_style(&mut report, &suppressions, formula);
+            // Fully-suppressed sub-checks that still ran count as used only if
+            // they found something; now drop their reports.
+            for check in ALL_CHECKS {
+                if suppressions.is_fully_suppressed(check)
+                    && let Some(found) = take_sub_report(&mut report, check)
+                {
+                    if found {
+                        suppressions.mark_used(check, None);
+                    }
+                    remaining -= 1;
+                }
             }
-            Some(report)
         }
+        let style_min = ac.style_min.or(config.style_min_score);
+        if let (Some(min), Some(st)) = (style_min, &report.style)
+            && st.style_score < min
+        {
+            failed.push("analyze");
+        }
+        // If all requested checks were suppressed, drop analyze entirely.
+        (remaining > 0).then_some(report)
     } else {
         None
     };
 
     // --- readability ---
     let readability_report = if let Some(ref rc) = resolved.readability
-        && !suppressions.is_fully_suppressed("readability")
+        && !skip(&suppressions, "readability")
     {
         let max_grade = rc.max_grade.or(config.max_grade);
         let report = readability::check_readability(content, strip_md, max_grade)?;
-        if report.over_max {
-            pass = false;
-        }
-        Some(report)
+        keep_report(
+            &mut suppressions,
+            "readability",
+            !report.over_max,
+            &mut failed,
+        )
+        .then_some(report)
     } else {
         None
     };
 
     // --- grammar ---
     let grammar_report = if let Some(ref gc) = resolved.grammar
-        && !suppressions.is_fully_suppressed("grammar")
+        && !skip(&suppressions, "grammar")
     {
         let passive_max = gc.passive_max.or(config.passive_max_percent);
         let mut report = grammar::check_grammar_full(content, strip_md, passive_max)?;
@@ -129,14 +206,14 @@ pub fn run_lint(
                     .get(issue.sentence_num.saturating_sub(1))
                     .copied()
                     .unwrap_or(0);
-                !suppressions.is_suppressed("grammar", line)
+                !hides(&mut suppressions, "grammar", line)
             });
             report.passive_voice.retain(|pv| {
                 let line = sentence_map
                     .get(pv.sentence_num.saturating_sub(1))
                     .copied()
                     .unwrap_or(0);
-                !suppressions.is_suppressed("grammar", line)
+                !hides(&mut suppressions, "grammar", line)
             });
             // Recompute aggregates
             report.passive_count = report.passive_voice.len();
@@ -147,71 +224,284 @@ pub fn run_lint(
             };
             report.over_max = passive_max.is_some_and(|max| report.passive_percentage > max);
         }
-        if report.over_max {
-            pass = false;
-        }
-        Some(report)
+        keep_report(&mut suppressions, "grammar", !report.over_max, &mut failed).then_some(report)
     } else {
         None
     };
 
     // --- completeness ---
     let completeness_report = if let Some(ref cc) = resolved.completeness
-        && !suppressions.is_fully_suppressed("completeness")
+        && !skip(&suppressions, "completeness")
     {
         let custom_templates = config.templates.as_ref();
-        let report = completeness::check_completeness(content, &cc.template, custom_templates)?;
-        if !report.pass {
-            pass = false;
-        }
-        Some(report)
+        let report = completeness::check_completeness(
+            content,
+            &cc.template,
+            custom_templates,
+            config.fuzzy_builtin_templates,
+        )?;
+        keep_report(&mut suppressions, "completeness", report.pass, &mut failed).then_some(report)
     } else {
         None
     };
 
+    // --- frontmatter ---
+    let frontmatter_report = if let Some(ref fc) = resolved.frontmatter
+        && !skip(&suppressions, "frontmatter")
+    {
+        let schemas = config.frontmatter_schemas.as_ref();
+        let report = frontmatter::check_frontmatter(content, &fc.schema, schemas)?;
+        keep_report(&mut suppressions, "frontmatter", report.pass, &mut failed).then_some(report)
+    } else {
+        None
+    };
+
+    // --- headings ---
+    let headings_report = resolved
+        .headings
+        .as_ref()
+        .filter(|_| !skip(&suppressions, "headings"))
+        .and_then(|hc| {
+            let mut report = headings::check_headings(content, hc);
+            if !suppressions.is_empty() {
+                report
+                    .issues
+                    .retain(|issue| !hides(&mut suppressions, "headings", issue.line));
+                report.pass = report.issues.is_empty();
+            }
+            keep_report(&mut suppressions, "headings", report.pass, &mut failed).then_some(report)
+        });
+
+    // --- links ---
+    let links_report = resolved
+        .links
+        .as_ref()
+        .filter(|_| !skip(&suppressions, "links"))
+        .and_then(|lc| {
+            let parent = Utf8Path::new(file_path).parent();
+            let base_dir = root.map_or_else(
+                || {
+                    parent.map(|p| {
+                        if p.as_str().is_empty() {
+                            Utf8PathBuf::from(".")
+                        } else {
+                            p.to_path_buf()
+                        }
+                    })
+                },
+                |root| Some(root.join(parent.unwrap_or_else(|| Utf8Path::new("")))),
+            );
+            let mut report = links::check_links(content, base_dir.as_deref(), root, lc.check_urls);
+            if !suppressions.is_empty() {
+                report
+                    .issues
+                    .retain(|issue| !hides(&mut suppressions, "links", issue.line));
+                report.pass = report.issues.is_empty();
+            }
+            keep_report(&mut suppressions, "links", report.pass, &mut failed).then_some(report)
+        });
+
     // --- tokens ---
     let tokens_report = if let Some(ref tc) = resolved.tokens
-        && !suppressions.is_fully_suppressed("tokens")
+        && !skip(&suppressions, "tokens")
     {
         let backend = tc.tokenizer.or(config.tokenizer).unwrap_or_default();
         let report = tokens::count_tokens(content, tc.budget, backend)?;
-        if report.over_budget {
-            pass = false;
-        }
-        Some(report)
+        keep_report(
+            &mut suppressions,
+            "tokens",
+            !report.over_budget,
+            &mut failed,
+        )
+        .then_some(report)
     } else {
         None
     };
 
+    // --- directives ---
+    // Last, so every suppression lookup has happened before checking for unused ones.
+    let mut directive_issues = suppressions.issues().to_vec();
+    if config.require_directive_reason {
+        directive_issues.extend(suppressions.missing_reasons());
+    }
+    if config.report_unused_directives {
+        directive_issues.extend(suppressions.unused());
+    }
+    directive_issues.sort_by_key(|issue| issue.line);
+    if !directive_issues.is_empty() {
+        failed.push("directives");
+    }
+
     Ok(LintReport {
         file: file_path.to_string(),
+        matched: resolved.is_empty().then_some(false),
         analyze: analyze_report,
         readability: readability_report,
         grammar: grammar_report,
         completeness: completeness_report,
+        frontmatter: frontmatter_report,
+        headings: headings_report,
+        links: links_report,
         tokens: tokens_report,
-        pass,
+        directives: directive_issues,
+        pass: failed.is_empty(),
+        failed: failed.into_iter().map(String::from).collect(),
     })
 }
 
-/// Replace directive comment lines with empty lines, preserving line count.
+/// Blank out directive comments, preserving line count and the text
+/// around each comment.
 ///
 /// This ensures that `build_sentence_line_map` and `build_paragraph_line_map`
 /// produce maps whose sentence/paragraph indices align with the analysis modules
 /// (which operate on prose stripped of HTML comments/markdown), while preserving
 /// the original line numbers for suppression lookups.
 fn strip_directives_preserving_lines(content: &str) -> String {
-    content
-        .lines()
-        .map(|line| {
-            if directives::is_directive_line(line) {
-                ""
-            } else {
-                line
-            }
+    directives::blank_directives(content).into_owned()
+}
+
+/// Whether to report a check that ran, recording it in `failed` if it did
+/// not `pass`.
+///
+/// A check suppressed for the whole file is not reported, and its suppression
+/// counts as used only if the check failed.
+fn keep_report(
+    suppressions: &mut SuppressionMap,
+    check: &'static str,
+    pass: bool,
+    failed: &mut Vec<&str>,
+) -> bool {
+    let suppressed = suppressions.is_fully_suppressed(check);
+    if !pass {
+        if suppressed {
+            suppressions.mark_used(check, None);
+        } else {
+            failed.push(check);
+        }
+    }
+    !suppressed
+}
+
+/// Whether a finding of `check` at `line` is suppressed, marking the
+/// suppressions that hide it used.
+fn hides(suppressions: &mut SuppressionMap, check: &str, line: usize) -> bool {
+    let suppressed = suppressions.is_suppressed(check, line);
+    if suppressed {
+        suppressions.mark_used(check, Some(line));
+    }
+    suppressed
+}
+
+/// Remove the `check` sub-report, returning whether it found anything, or
+/// `None` if it did not run.
+///
+/// Metric-only checks (`pacing`, `transitions`, `sensory`) never find anything.
+fn take_sub_report(report: &mut FullAnalysisReport, check: &str) -> Option<bool> {
+    match check {
+        "readability" => report.readability.take().map(|r| r.over_max),
+        "grammar" => report
+            .grammar
+            .take()
+            .map(|r| !r.issues.is_empty() || !r.passive_voice.is_empty()),
+        "sticky" => report
+            .sticky_sentences
+            .take()
+            .map(|r| !r.sticky_sentences.is_empty()),
+        "pacing" => report.pacing.take().map(|_| false),
+        "sentence_length" => report
+            .sentence_length
+            .take()
+            .map(|r| !r.very_long.is_empty()),
+        "transitions" => report.transitions.take().map(|_| false),
+        "overused" => report
+            .overused_words
+            .take()
+            .map(|r| !r.overused_words.is_empty()),
+        "repeated" => report.repeated_phrases.take().map(|r| r.total_repeated > 0),
+        "echoes" => report.echoes.take().map(|r| r.total_echoes > 0),
+        "sensory" => report.sensory.take().map(|_| false),
+        "diction" => report.diction.take().map(|r| r.total_vague > 0),
+        "hedging" => report.hedging.take().map(|r| r.total_hedges > 0),
+        "wordiness" => report.wordiness.take().map(|r| r.total_wordy > 0),
+        "ai_tells" => report.ai_tells.take().map(|r| r.total_tells > 0),
+        "cliches" => report.cliches.take().map(|r| r.total_cliches > 0),
+        "consistency" => report.consistency.take().map(|r| r.total_issues > 0),
+        "acronyms" => report.acronyms.take().map(|r| !r.undefined.is_empty()),
+        "jargon" => report.jargon.take().map(|r| r.total_jargon > 0),
+        "complex_paragraphs" => report
+            .complex_paragraphs
+            .take()
+            .map(|r| r.complex_count > 0),
+        "conjunction_starts" => report.conjunction_starts.take().map(|r| r.count > 0),
+        "style" => report.style.take().map(|r| {
+            r.deductions.iter().any(|d| d.points > 0.0)
+                || !r.nominalizations.is_empty()
+                || !r.hidden_verbs.is_empty()
+        }),
+        _ => None,
+    }
+}
+
+/// The check whose findings a style score component counts.
+const fn component_check(component: StyleComponent) -> &'static str {
+    match component {
+        StyleComponent::Passive => "grammar",
+        StyleComponent::Glue => "sticky",
+        StyleComponent::Vague => "diction",
+        StyleComponent::Hedging => "hedging",
+        StyleComponent::Cliches => "cliches",
+        StyleComponent::Jargon => "jargon",
+        StyleComponent::Wordiness => "wordiness",
+        StyleComponent::AiTells => "ai_tells",
+        StyleComponent::Adverbs | StyleComponent::HiddenVerbs => "style",
+    }
+}
+
+/// Recompute the style score from the findings left after suppression.
+///
+/// Components whose check is suppressed for the whole file count nothing.
+/// Otherwise each component takes its count from the filtered sub-report
+/// where one ran; counts with no sub-report or no location data stand.
+fn rescore_style(
+    report: &mut FullAnalysisReport,
+    suppressions: &SuppressionMap,
+    formula: &StyleScoreConfig,
+) {
+    let Some(mut st) = report.style.take() else {
+        return;
+    };
+    let nouns: HashSet<&str> = st
+        .hidden_verbs
+        .iter()
+        .map(|h| h.noun.as_str())
+        .chain(st.nominalizations.iter().map(|n| n.noun.as_str()))
+        .collect();
+    let measured: BTreeMap<StyleComponent, f64> = st
+        .deductions
+        .iter()
+        .map(|d| {
+            let filtered = match d.component {
+                _ if suppressions.is_fully_suppressed(component_check(d.component)) => Some(0),
+                StyleComponent::Passive => report.grammar.as_ref().map(|r| r.passive_count),
+                StyleComponent::Vague => report
+                    .diction
+                    .as_ref()
+                    .map(|r| style::scored_vague(r, formula)),
+                StyleComponent::Hedging => report.hedging.as_ref().map(|r| r.total_hedges),
+                StyleComponent::Cliches => report.cliches.as_ref().map(|r| r.total_cliches),
+                StyleComponent::Jargon => report.jargon.as_ref().map(|r| r.total_jargon),
+                StyleComponent::Wordiness => report.wordiness.as_ref().map(|r| r.total_wordy),
+                StyleComponent::AiTells => report.ai_tells.as_ref().map(|r| r.total_tells),
+                StyleComponent::HiddenVerbs => Some(nouns.len()),
+                StyleComponent::Glue | StyleComponent::Adverbs => None,
+            };
+            (d.component, filtered.map_or(d.measured, |n| n as f64))
         })
-        .collect::<Vec<_>>()
-        .join("\n")
+        .collect();
+    let (style_score, deductions) = style::calculate_style_score(&measured, formula);
+    st.style_score = style_score;
+    st.deductions = deductions;
+    report.style = Some(st);
 }
 
 /// Filter analysis sub-reports using region-level suppressions.
@@ -221,7 +511,7 @@ fn strip_directives_preserving_lines(content: &str) -> String {
 fn filter_analysis_report(
     report: &mut FullAnalysisReport,
     content: &str,
-    suppressions: &SuppressionMap,
+    suppressions: &mut SuppressionMap,
 ) {
     let clean = strip_directives_preserving_lines(content);
     let sentence_map = text::build_sentence_line_map(&clean);
@@ -245,11 +535,10 @@ fn filter_analysis_report(
 
     // --- grammar sub-report ---
     if let Some(ref mut gr) = report.grammar {
-        gr.issues.retain(|issue| {
-            !suppressions.is_suppressed("grammar", sentence_line(issue.sentence_num))
-        });
+        gr.issues
+            .retain(|issue| !hides(suppressions, "grammar", sentence_line(issue.sentence_num)));
         gr.passive_voice
-            .retain(|pv| !suppressions.is_suppressed("grammar", sentence_line(pv.sentence_num)));
+            .retain(|pv| !hides(suppressions, "grammar", sentence_line(pv.sentence_num)));
         gr.passive_count = gr.passive_voice.len();
         gr.passive_percentage = if gr.sentence_count > 0 {
             (gr.passive_count as f64 / gr.sentence_count as f64) * 100.0
@@ -261,11 +550,10 @@ fn filter_analysis_report(
             .is_some_and(|max| gr.passive_percentage > max);
     }
 
-    // --- sticky_sentences sub-report ---
+    // --- sticky sub-report ---
     if let Some(ref mut ss) = report.sticky_sentences {
-        ss.sticky_sentences.retain(|s| {
-            !suppressions.is_suppressed("sticky_sentences", sentence_line(s.sentence_num))
-        });
+        ss.sticky_sentences
+            .retain(|s| !hides(suppressions, "sticky", sentence_line(s.sentence_num)));
         ss.sticky_count = ss.sticky_sentences.len();
         // semi_sticky_count cannot be recomputed — the vec only stores >45%
         // entries, so semi-sticky (35-45%) sentences aren't available for
@@ -275,15 +563,68 @@ fn filter_analysis_report(
     // --- sentence_length sub-report ---
     if let Some(ref mut sl) = report.sentence_length {
         sl.very_long.retain(|ls| {
-            !suppressions.is_suppressed("sentence_length", sentence_line(ls.sentence_num))
+            !hides(
+                suppressions,
+                "sentence_length",
+                sentence_line(ls.sentence_num),
+            )
         });
     }
 
+    // --- hedging sub-report ---
+    if let Some(ref mut hr) = report.hedging {
+        let before = hr.total_hedges;
+        hr.hedges
+            .retain(|h| !hides(suppressions, "hedging", sentence_line(h.sentence_num)));
+        hr.total_hedges = hr.hedges.len();
+        if before > 0 {
+            hr.density *= hr.total_hedges as f64 / before as f64;
+        }
+    }
+
+    // --- wordiness sub-report ---
+    if let Some(ref mut wr) = report.wordiness {
+        wr.phrases
+            .retain(|p| !hides(suppressions, "wordiness", sentence_line(p.sentence_num)));
+        wr.total_wordy = wr.phrases.len();
+        wr.words_saved = wr.phrases.iter().map(|p| p.words_saved).sum();
+        wr.tokens_saved = wr.phrases.iter().map(|p| p.tokens_saved).sum();
+    }
+
+    // --- ai_tells sub-report ---
+    if let Some(ref mut at) = report.ai_tells {
+        let before = at.total_tells;
+        at.tells
+            .retain(|t| !hides(suppressions, "ai_tells", paragraph_line(t.paragraph_num)));
+        at.paragraphs
+            .retain(|p| !hides(suppressions, "ai_tells", paragraph_line(p.paragraph_num)));
+        at.total_tells = at.tells.len();
+        if before > 0 {
+            at.density *= at.total_tells as f64 / before as f64;
+        }
+    }
+
+    // --- style nominalizations ---
+    if let Some(ref mut st) = report.style {
+        st.nominalizations
+            .retain(|n| !hides(suppressions, "style", sentence_line(n.sentence_num)));
+    }
+
+    // --- acronyms sub-report ---
+    if let Some(ref mut ar) = report.acronyms {
+        ar.undefined
+            .retain(|u| !hides(suppressions, "acronyms", sentence_line(u.sentence_num)));
+    }
+
     // --- complex_paragraphs sub-report ---
     if let Some(ref mut cp) = report.complex_paragraphs {
         let total_paragraphs = paragraph_map.len();
         cp.complex_paragraphs.retain(|p| {
-            !suppressions.is_suppressed("complex_paragraphs", paragraph_line(p.paragraph_num))
+            !hides(
+                suppressions,
+                "complex_paragraphs",
+                paragraph_line(p.paragraph_num),
+            )
         });
         cp.complex_count = cp.complex_paragraphs.len();
         cp.percentage = if total_paragraphs > 0 {
@@ -296,7 +637,7 @@ fn filter_analysis_report(
     // --- echoes sub-report ---
     if let Some(ref mut er) = report.echoes {
         er.echoes
-            .retain(|echo| !suppressions.is_suppressed("echoes", paragraph_line(echo.paragraph)));
+            .retain(|echo| !hides(suppressions, "echoes", paragraph_line(echo.paragraph)));
         er.total_echoes = er.echoes.len();
     }
 }
@@ -325,8 +666,8 @@ fn resolve_analyze_checks(ac: &AnalyzeRuleConfig) -> AnalysisResult<Option<Vec<S
 mod tests {
     use super::*;
     use crate::config::{
-        AnalyzeRuleConfig, CompletenessRuleConfig, Config, GrammarRuleConfig,
-        ReadabilityRuleConfig, TokensRuleConfig,
+        AnalyzeRuleConfig, CompletenessRuleConfig, Config, FrontmatterRuleConfig,
+        GrammarRuleConfig, ReadabilityRuleConfig, TokensRuleConfig,
     };
     use crate::rules::ResolvedChecks;
 
@@ -398,6 +739,86 @@ mod tests {
         assert!(!report.pass);
     }
 
+    #[test]
+    fn frontmatter_schema_violation_fails() {
+        let config: Config = serde_yaml::from_str(
+            "frontmatter_schemas:\n  adr:\n    fields:\n      status:\n        enum: [proposed, accepted]\n",
+        )
+        .unwrap();
+        let resolved = ResolvedChecks {
+            frontmatter: Some(FrontmatterRuleConfig {
+                schema: "adr".to_string(),
+            }),
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", "---\nstatus: done\n---\n", &resolved, &config).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.frontmatter.unwrap().issues[0].key, "status");
+
+        let report =
+            run_lint("doc.md", "---\nstatus: accepted\n---\n", &resolved, &config).unwrap();
+        assert!(report.pass);
+    }
+
+    #[test]
+    fn links_check_resolves_against_file_dir_and_honors_suppressions() {
+        let dir = tempfile::tempdir().unwrap();
+        let docs = dir.path().join("docs");
+        std::fs::create_dir_all(&docs).unwrap();
+        std::fs::write(docs.join("guide.md"), "# Guide\n").unwrap();
+        let file = docs.join("index.md");
+        let file = file.to_str().unwrap();
+
+        let resolved = ResolvedChecks {
+            links: Some(crate::config::LinksRuleConfig::default()),
+            ..Default::default()
+        };
+        let content = "[ok](guide.md#guide)\n\n[bad](gone.md)\n";
+        let report = run_lint(file, content, &resolved, &Config::default()).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.links.unwrap().issues[0].line, 3);
+
+        let content =
+            "[ok](guide.md#guide)\n\n<!-- bito-lint disable-next-line links -->\n[bad](gone.md)\n";
+        let report = run_lint(file, content, &resolved, &Config::default()).unwrap();
+        assert!(report.pass, "{:?}", report.links);
+    }
+
+    #[test]
+    fn links_within_root_resolve_against_root_and_stay_inside() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = Utf8PathBuf::try_from(dir.path().canonicalize().unwrap()).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/guide.md"), "# Guide\n").unwrap();
+
+        let resolved = ResolvedChecks {
+            links: Some(crate::config::LinksRuleConfig::default()),
+            ..Default::default()
+        };
+        let content = "[ok](guide.md#guide)\n\n[up](../../etc/hosts)\n";
+        let report = run_lint_within(
+            &root,
+            "docs/index.md",
+            content,
+            &resolved,
+            &Config::default(),
+        )
+        .unwrap();
+        let issues = report.links.unwrap().issues;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, links::LinkIssueKind::OutsideRoot);
+
+        let report = run_lint_within(
+            &root,
+            "../index.md",
+            "[ok](docs/guide.md)",
+            &resolved,
+            &Config::default(),
+        )
+        .unwrap();
+        assert!(!report.pass);
+    }
+
     #[test]
     fn config_defaults_cascade_to_analyze() {
         let config = Config {
@@ -551,6 +972,25 @@ The ball was thrown by the player.";
         }
     }
 
+    #[test]
+    fn disable_line_filters_finding_on_directive_line() {
+        let resolved = ResolvedChecks {
+            analyze: Some(AnalyzeRuleConfig {
+                checks: Some(vec!["hedging".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let content = "\
+We arguably need this. <!-- bito-lint disable-line hedging -- quoted -->
+Perhaps dogs chase cats.";
+
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        let hedging = report.analyze.unwrap().hedging.unwrap();
+        let phrases: Vec<_> = hedging.hedges.iter().map(|h| h.phrase.as_str()).collect();
+        assert_eq!(phrases, ["perhaps"]);
+    }
+
     #[test]
     fn suppression_does_not_affect_unsuppressed_findings() {
         let resolved = ResolvedChecks {
@@ -568,4 +1008,220 @@ The ball was thrown by the player.";
             "both passives should remain without suppressions"
         );
     }
+
+    #[test]
+    fn required_reasons_fail_lint() {
+        let resolved = ResolvedChecks {
+            tokens: Some(TokensRuleConfig {
+                budget: Some(1_000_000),
+                tokenizer: None,
+            }),
+            ..Default::default()
+        };
+        let content = "<!-- bito-lint disable jargon -- marketing copy -->\n\
+                       Synergy.\n\
+                       <!-- bito-lint disable-next-line cliches -->\n\
+                       At the end of the day.";
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        assert!(report.pass);
+        assert!(report.directives.is_empty());
+
+        let config = Config {
+            require_directive_reason: true,
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", content, &resolved, &config).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.failed, ["directives"]);
+        assert_eq!(report.directives.len(), 1);
+        assert_eq!(report.directives[0].line, 3);
+    }
+
+    #[test]
+    fn directive_mistakes_fail_lint() {
+        let resolved = ResolvedChecks {
+            readability: Some(ReadabilityRuleConfig {
+                max_grade: Some(20.0),
+            }),
+            ..Default::default()
+        };
+        let content = "<!-- bito-lint disable-next-line readabilty -->\nThe cat sat on the mat.";
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.failed, ["directives"]);
+        assert!(
+            report.directives[0]
+                .message
+                .contains("did you mean `readability`")
+        );
+    }
+
+    #[test]
+    fn enable_inside_disable_all_is_matched() {
+        let resolved = ResolvedChecks {
+            grammar: Some(GrammarRuleConfig { passive_max: None }),
+            ..Default::default()
+        };
+        let content = "\
+<!-- bito-lint disable all -->
+The report was written by the team.
+<!-- bito-lint enable grammar -->
+The bug was fixed by the team.";
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        assert!(report.directives.is_empty(), "{:?}", report.directives);
+        let grammar = report.grammar.expect("grammar was re-enabled");
+        assert_eq!(grammar.passive_count, 1);
+    }
+
+    #[test]
+    fn unused_directives_reported_when_configured() {
+        let resolved = ResolvedChecks {
+            grammar: Some(GrammarRuleConfig { passive_max: None }),
+            ..Default::default()
+        };
+        let content = "\
+<!-- bito-lint disable-next-line grammar -->
+The report was written by the team.
+<!-- bito-lint disable-next-line grammar -->
+The team wrote the report.";
+
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        assert!(report.pass);
+
+        let config = Config {
+            report_unused_directives: true,
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", content, &resolved, &config).unwrap();
+        assert!(!report.pass);
+        assert_eq!(report.directives.len(), 1);
+        assert_eq!(report.directives[0].line, 3);
+    }
+
+    #[test]
+    fn file_suppressed_check_is_skipped_unless_reporting_unused() {
+        let resolved = ResolvedChecks {
+            completeness: Some(CompletenessRuleConfig {
+                template: "no-such-template".to_string(),
+            }),
+            ..Default::default()
+        };
+        let content = "<!-- bito-lint disable-file completeness -->\n# Notes";
+
+        let report = run_lint("doc.md", content, &resolved, &default_config()).unwrap();
+        assert!(report.pass);
+        assert!(report.completeness.is_none());
+
+        let config = Config {
+            report_unused_directives: true,
+            ..Default::default()
+        };
+        assert!(run_lint("doc.md", content, &resolved, &config).is_err());
+    }
+
+    #[test]
+    fn file_level_suppression_applies_to_default_analyze_checks() {
+        let resolved = ResolvedChecks {
+            analyze: Some(AnalyzeRuleConfig::default()),
+            ..Default::default()
+        };
+        let content = "<!-- bito-lint disable-file cliches -->\nWe had to bite the bullet.";
+        let config = Config {
+            report_unused_directives: true,
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", content, &resolved, &config).unwrap();
+        let analysis = report.analyze.expect("analyze should run");
+        assert!(analysis.cliches.is_none());
+        assert!(analysis.readability.is_some());
+        assert!(report.directives.is_empty());
+    }
+
+    #[test]
+    fn file_level_suppression_hiding_nothing_is_unused() {
+        let resolved = ResolvedChecks {
+            analyze: Some(AnalyzeRuleConfig::default()),
+            readability: Some(ReadabilityRuleConfig {
+                max_grade: Some(20.0),
+            }),
+            ..Default::default()
+        };
+        let content = "\
+<!-- bito-lint disable-file cliches -->
+<!-- bito-lint disable readability -->
+The cat sat on the mat.";
+        let config = Config {
+            report_unused_directives: true,
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", content, &resolved, &config).unwrap();
+        assert!(
+            report
+                .analyze
+                .expect("analyze should run")
+                .cliches
+                .is_none()
+        );
+        assert!(report.readability.is_none());
+        let unused: Vec<(usize, &str)> = report
+            .directives
+            .iter()
+            .map(|d| (d.line, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            unused,
+            [
+                (1, "`disable-file cliches` suppressed nothing"),
+                (2, "`disable readability` suppressed nothing"),
+            ]
+        );
+    }
+
+    #[test]
+    fn file_level_style_suppression_hiding_nothing_is_unused() {
+        let resolved = ResolvedChecks {
+            analyze: Some(AnalyzeRuleConfig::default()),
+            ..Default::default()
+        };
+        let content = "<!-- bito-lint disable-file style -->\nDogs chase cats.";
+        let config = Config {
+            report_unused_directives: true,
+            ..Default::default()
+        };
+        let report = run_lint("doc.md", content, &resolved, &config).unwrap();
+        assert!(report.analyze.expect("analyze should run").style.is_none());
+        assert_eq!(report.directives.len(), 1);
+        assert_eq!(
+            report.directives[0].message,
+            "`disable-file style` suppressed nothing"
+        );
+    }
+
+    #[test]
+    fn suppressed_findings_do_not_lower_style_score() {
+        let resolved = ResolvedChecks {
+            analyze: Some(AnalyzeRuleConfig {
+                style_min: Some(100),
+                style_score: Some(serde_json::from_str(r#"{"hedging": {"weight": 5}}"#).unwrap()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let hedged = "Perhaps dogs chase cats.";
+
+        let report = run_lint("doc.md", hedged, &resolved, &Config::default()).unwrap();
+        assert!(!report.pass);
+
+        for content in [
+            format!(
+                "<!-- bito-lint disable hedging -->\n{hedged}\n<!-- bito-lint enable hedging -->"
+            ),
+            format!("<!-- bito-lint disable-file hedging -->\n{hedged}"),
+        ] {
+            let report = run_lint("doc.md", &content, &resolved, &Config::default()).unwrap();
+            let style = report.analyze.unwrap().style.unwrap();
+            assert_eq!(style.style_score, 100, "{content}");
+            assert!(report.pass, "{content}");
+        }
+    }
 }
@@ -4,8 +4,12 @@
 //! stripping. This handles edge cases (nested code blocks, HTML entities,
 //! reference links) that regex approaches miss.
 
+use std::ops::Range;
+
 use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
 
+use crate::error::{AnalysisError, AnalysisResult};
+
 /// Strip markdown formatting, returning plain prose text.
 ///
 /// Removes:
@@ -72,18 +76,31 @@ pub fn strip_to_prose(text: &str) -> String {
 /// Returns a list of `(level, text)` pairs where level is 1-6.
 #[tracing::instrument(skip_all, fields(input_len = text.len()))]
 pub fn extract_headings(text: &str) -> Vec<(u8, String)> {
-    let text = strip_frontmatter(text);
+    extract_headings_with_lines(text)
+        .into_iter()
+        .map(|(_, level, text)| (level, text))
+        .collect()
+}
+
+/// Extract headings from markdown text along with their source lines.
+///
+/// Returns a list of `(line, level, text)` triples where line is the
+/// 1-indexed line in `text` (frontmatter included) and level is 1-6.
+pub fn extract_headings_with_lines(text: &str) -> Vec<(usize, u8, String)> {
+    let body = strip_frontmatter(text);
+    let frontmatter_lines = text[..text.len() - body.len()].matches('\n').count();
     let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
-    let parser = Parser::new_ext(&text, options);
+    let parser = Parser::new_ext(&body, options);
 
     let mut headings = Vec::new();
-    let mut in_heading: Option<u8> = None;
+    let mut in_heading: Option<(usize, u8)> = None;
     let mut heading_text = String::new();
 
-    for event in parser {
+    for (event, range) in parser.into_offset_iter() {
         match event {
             Event::Start(Tag::Heading { level, .. }) => {
-                in_heading = Some(level as u8);
+                let line = frontmatter_lines + body[..range.start].matches('\n').count() + 1;
+                in_heading = Some((line, level as u8));
                 heading_text.clear();
             }
             Event::Text(t) if in_heading.is_some() => {
@@ -93,8 +110,8 @@ pub fn extract_headings(text: &str) -> Vec<(u8, String)> {
                 heading_text.push_str(&t);
             }
             Event::End(TagEnd::Heading(_)) => {
-                if let Some(level) = in_heading.take() {
-                    headings.push((level, heading_text.clone()));
+                if let Some((line, level)) = in_heading.take() {
+                    headings.push((line, level, heading_text.clone()));
                 }
             }
             _ => {}
@@ -104,8 +121,71 @@ pub fn extract_headings(text: &str) -> Vec<(u8, String)> {
     headings
 }
 
+/// Byte ranges of `text` that hold prose, in document order.
+///
+/// Covers text in paragraphs, headings, lists, blockquotes, and tables,
+/// but not frontmatter, code, or HTML. Text whose source differs from its
+/// rendered form (escapes, entities) is left out, so every range can be
+/// edited in place.
+pub fn prose_ranges(text: &str) -> Vec<Range<usize>> {
+    let body = strip_frontmatter(text);
+    let offset = text.len() - body.len();
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let mut in_code_block = false;
+    let mut ranges = Vec::new();
+
+    for (event, range) in Parser::new_ext(&body, options).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Text(t) if !in_code_block && body[range.clone()] == *t => {
+                ranges.push(range.start + offset..range.end + offset);
+            }
+            _ => {}
+        }
+    }
+
+    ranges
+}
+
+/// Extract the raw YAML frontmatter block delimited by `---` lines.
+///
+/// Returns the text between the opening and closing delimiters, or `None`
+/// if the document has no frontmatter.
+pub fn extract_frontmatter(text: &str) -> Option<&str> {
+    let trimmed = text.trim_start();
+    let after_opening = trimmed.strip_prefix("---")?;
+    let close_pos = after_opening.find("\n---")?;
+    let block = &after_opening[..close_pos];
+    Some(block.strip_prefix('\n').unwrap_or(block))
+}
+
+/// Parse YAML frontmatter into a map of top-level keys to values.
+///
+/// Returns `Ok(None)` if the document has no frontmatter. Values are
+/// converted to JSON so callers can inspect them without a YAML dependency.
+pub fn parse_frontmatter(
+    text: &str,
+) -> AnalysisResult<Option<serde_json::Map<String, serde_json::Value>>> {
+    let Some(block) = extract_frontmatter(text) else {
+        return Ok(None);
+    };
+    if block.trim().is_empty() {
+        return Ok(Some(serde_json::Map::new()));
+    }
+    let value: serde_json::Value = serde_yaml::from_str(block)
+        .map_err(|e| AnalysisError::InvalidFrontmatter(e.to_string()))?;
+    match value {
+        serde_json::Value::Object(map) => Ok(Some(map)),
+        serde_json::Value::Null => Ok(Some(serde_json::Map::new())),
+        _ => Err(AnalysisError::InvalidFrontmatter(
+            "frontmatter must be a mapping of keys to values".to_string(),
+        )),
+    }
+}
+
 /// Strip YAML frontmatter delimited by `---` lines.
-fn strip_frontmatter(text: &str) -> String {
+pub(crate) fn strip_frontmatter(text: &str) -> String {
     let trimmed = text.trim_start();
     if !trimmed.starts_with("---") {
         return text.to_string();
@@ -209,6 +289,19 @@ mod tests {
         assert_eq!(headings[2], (3, "H3".to_string()));
     }
 
+    #[test]
+    fn extract_headings_with_lines_counts_frontmatter() {
+        let input = "---\ntitle: Test\n---\n\n# Real Heading\n\nText.\n\n## Next\n";
+        let headings = extract_headings_with_lines(input);
+        assert_eq!(
+            headings,
+            vec![
+                (5, 1, "Real Heading".to_string()),
+                (9, 2, "Next".to_string())
+            ]
+        );
+    }
+
     #[test]
     fn extract_headings_skips_frontmatter() {
         let input = "---\ntitle: Test\n---\n\n# Real Heading\n\nText.";
@@ -217,9 +310,35 @@ mod tests {
         assert_eq!(headings[0].1, "Real Heading");
     }
 
+    #[test]
+    fn parse_frontmatter_reads_keys() {
+        let input = "---\nstatus: accepted\ndeciders: [alice, bob]\n---\n\n# Title";
+        let fm = parse_frontmatter(input).unwrap().unwrap();
+        assert_eq!(fm["status"], "accepted");
+        assert_eq!(fm["deciders"][1], "bob");
+    }
+
+    #[test]
+    fn parse_frontmatter_absent_returns_none() {
+        assert!(parse_frontmatter("# Title\n\nText.").unwrap().is_none());
+    }
+
+    #[test]
+    fn parse_frontmatter_rejects_non_mapping() {
+        assert!(parse_frontmatter("---\n- a\n- b\n---\n").is_err());
+    }
+
     #[test]
     fn empty_input_returns_empty() {
         assert!(strip_to_prose("").is_empty());
         assert!(extract_headings("").is_empty());
     }
+
+    #[test]
+    fn prose_ranges_skip_code_and_frontmatter() {
+        let input =
+            "---\ntitle: x\n---\n# Intro\n\nUse `code` here &amp; now.\n\n```\nlet a = 1;\n```\n";
+        let prose: Vec<&str> = prose_ranges(input).into_iter().map(|r| &input[r]).collect();
+        assert_eq!(prose, vec!["Intro", "Use ", " here ", " now."]);
+    }
 }
@@ -6,11 +6,15 @@
 //! by specificity: the pattern with more literal (non-wildcard) path
 //! segments wins. Ties go to the earlier rule.
 
+use std::collections::BTreeMap;
+
 use globset::{Glob, GlobMatcher};
+use schemars::JsonSchema;
+use serde::Serialize;
 
 use crate::config::{
-    AnalyzeRuleConfig, CompletenessRuleConfig, GrammarRuleConfig, ReadabilityRuleConfig, Rule,
-    RuleChecks, TokensRuleConfig,
+    AnalyzeRuleConfig, CompletenessRuleConfig, FrontmatterRuleConfig, GrammarRuleConfig,
+    HeadingsRuleConfig, LinksRuleConfig, ReadabilityRuleConfig, Rule, RuleChecks, TokensRuleConfig,
 };
 
 /// Compiled rule set for efficient matching.
@@ -20,12 +24,13 @@ pub struct RuleSet {
 
 /// A single rule with pre-compiled glob matchers.
 struct CompiledRule {
+    index: usize,                        // position in the configured rule list
     matchers: Vec<(GlobMatcher, usize)>, // (matcher, specificity)
     checks: RuleChecks,
 }
 
 /// Accumulated check configurations after rule resolution.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
 pub struct ResolvedChecks {
     /// Resolved analyze check configuration.
     pub analyze: Option<AnalyzeRuleConfig>,
@@ -35,6 +40,12 @@ pub struct ResolvedChecks {
     pub grammar: Option<GrammarRuleConfig>,
     /// Resolved completeness check configuration.
     pub completeness: Option<CompletenessRuleConfig>,
+    /// Resolved frontmatter check configuration.
+    pub frontmatter: Option<FrontmatterRuleConfig>,
+    /// Resolved headings check configuration.
+    pub headings: Option<HeadingsRuleConfig>,
+    /// Resolved links check configuration.
+    pub links: Option<LinksRuleConfig>,
     /// Resolved tokens check configuration.
     pub tokens: Option<TokensRuleConfig>,
 }
@@ -46,10 +57,48 @@ impl ResolvedChecks {
             && self.readability.is_none()
             && self.grammar.is_none()
             && self.completeness.is_none()
+            && self.frontmatter.is_none()
+            && self.headings.is_none()
+            && self.links.is_none()
             && self.tokens.is_none()
     }
 }
 
+/// Why a set of checks applies to a file path.
+#[derive(Debug, Clone, Default, Serialize, JsonSchema)]
+pub struct RuleExplanation {
+    /// Rules with a pattern matching the path, in configured order.
+    pub matched: Vec<RuleMatch>,
+    /// The rule each resolved check came from, keyed by check type.
+    pub winners: BTreeMap<String, CheckSource>,
+    /// The checks that apply, as [`RuleSet::resolve`] returns them.
+    pub resolved: ResolvedChecks,
+}
+
+/// A rule that matched a path.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct RuleMatch {
+    /// 0-based index of the rule in the configured rule list.
+    pub rule: usize,
+    /// The most specific of the rule's patterns that matched.
+    pub pattern: String,
+    /// Literal segment count of `pattern`.
+    pub specificity: usize,
+    /// Check types the rule configures.
+    pub checks: Vec<String>,
+}
+
+/// The rule a resolved check's configuration came from.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct CheckSource {
+    /// 0-based index of the rule in the configured rule list.
+    pub rule: usize,
+    /// The pattern that matched.
+    pub pattern: String,
+    /// Literal segment count of `pattern`; the highest wins.
+    pub specificity: usize,
+}
+
 /// Count literal (non-wildcard) path segments in a glob pattern.
 ///
 /// `docs/decisions/*.md` → 2 (`docs`, `decisions`)
@@ -69,7 +118,8 @@ impl RuleSet {
     pub fn compile(rules: &[Rule]) -> Self {
         let compiled = rules
             .iter()
-            .filter_map(|rule| {
+            .enumerate()
+            .filter_map(|(index, rule)| {
                 let matchers: Vec<(GlobMatcher, usize)> = rule
                     .paths
                     .iter()
@@ -85,6 +135,7 @@ impl RuleSet {
                     None
                 } else {
                     Some(CompiledRule {
+                        index,
                         matchers,
                         checks: rule.checks.clone(),
                     })
@@ -100,54 +151,132 @@ impl RuleSet {
     /// check type, the one matched by the higher-specificity pattern wins.
     /// Ties go to the earlier rule (lower index).
     pub fn resolve(&self, file_path: &str) -> ResolvedChecks {
-        let mut result = ResolvedChecks::default();
+        self.explain(file_path).resolved
+    }
 
-        // Track the specificity of the winning rule for each check type.
-        let mut analyze_spec: Option<usize> = None;
-        let mut readability_spec: Option<usize> = None;
-        let mut grammar_spec: Option<usize> = None;
-        let mut completeness_spec: Option<usize> = None;
-        let mut tokens_spec: Option<usize> = None;
+    /// Resolve a file path like [`resolve`](Self::resolve), also reporting
+    /// which rules matched and which one each check came from.
+    pub fn explain(&self, file_path: &str) -> RuleExplanation {
+        let mut explanation = RuleExplanation::default();
 
         for rule in &self.compiled {
-            let max_spec = rule
+            // Most specific matching pattern; the first one on ties.
+            let best = rule
                 .matchers
                 .iter()
                 .filter(|(m, _)| m.is_match(file_path))
-                .map(|(_, s)| *s)
-                .max();
-
-            let Some(spec) = max_spec else {
+                .fold(
+                    None,
+                    |best: Option<&(GlobMatcher, usize)>, candidate| match best {
+                        Some(b) if b.1 >= candidate.1 => Some(b),
+                        _ => Some(candidate),
+                    },
+                );
+            let Some((matcher, spec)) = best else {
                 continue;
             };
 
-            if rule.checks.analyze.is_some() && analyze_spec.is_none_or(|prev| spec > prev) {
-                result.analyze = rule.checks.analyze.clone();
-                analyze_spec = Some(spec);
-            }
-            if rule.checks.readability.is_some() && readability_spec.is_none_or(|prev| spec > prev)
-            {
-                result.readability = rule.checks.readability.clone();
-                readability_spec = Some(spec);
-            }
-            if rule.checks.grammar.is_some() && grammar_spec.is_none_or(|prev| spec > prev) {
-                result.grammar = rule.checks.grammar.clone();
-                grammar_spec = Some(spec);
-            }
-            if rule.checks.completeness.is_some()
-                && completeness_spec.is_none_or(|prev| spec > prev)
-            {
-                result.completeness = rule.checks.completeness.clone();
-                completeness_spec = Some(spec);
-            }
-            if rule.checks.tokens.is_some() && tokens_spec.is_none_or(|prev| spec > prev) {
-                result.tokens = rule.checks.tokens.clone();
-                tokens_spec = Some(spec);
-            }
+            let checks = &rule.checks;
+            let source = CheckSource {
+                rule: rule.index,
+                pattern: matcher.glob().glob().to_string(),
+                specificity: *spec,
+            };
+            let resolved = &mut explanation.resolved;
+            let winners = &mut explanation.winners;
+            let contributed = [
+                contribute(
+                    "analyze",
+                    &checks.analyze,
+                    &mut resolved.analyze,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "readability",
+                    &checks.readability,
+                    &mut resolved.readability,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "grammar",
+                    &checks.grammar,
+                    &mut resolved.grammar,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "completeness",
+                    &checks.completeness,
+                    &mut resolved.completeness,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "frontmatter",
+                    &checks.frontmatter,
+                    &mut resolved.frontmatter,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "headings",
+                    &checks.headings,
+                    &mut resolved.headings,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "links",
+                    &checks.links,
+                    &mut resolved.links,
+                    winners,
+                    &source,
+                ),
+                contribute(
+                    "tokens",
+                    &checks.tokens,
+                    &mut resolved.tokens,
+                    winners,
+                    &source,
+                ),
+            ];
+
+            explanation.matched.push(RuleMatch {
+                rule: source.rule,
+                pattern: source.pattern,
+                specificity: source.specificity,
+                checks: contributed
+                    .into_iter()
+                    .flatten()
+                    .map(str::to_string)
+                    .collect(),
+            });
         }
 
-        result
+        explanation
+    }
+}
+
+/// Take a rule's configuration for one check type if it beats the current
+/// winner. Returns the check name if the rule configures the check at all.
+fn contribute<T: Clone>(
+    name: &'static str,
+    value: &Option<T>,
+    slot: &mut Option<T>,
+    winners: &mut BTreeMap<String, CheckSource>,
+    source: &CheckSource,
+) -> Option<&'static str> {
+    let value = value.as_ref()?;
+    if winners
+        .get(name)
+        .is_none_or(|prev| source.specificity > prev.specificity)
+    {
+        *slot = Some(value.clone());
+        winners.insert(name.to_string(), source.clone());
     }
+    Some(name)
 }
 
 #[cfg(test)]
@@ -327,4 +456,42 @@ mod tests {
         let set = RuleSet::compile(&rules);
         assert!(set.resolve("docs/guide.md").analyze.is_some());
     }
+
+    #[test]
+    fn explain_reports_matches_and_winners() {
+        let rules = make_rules(&[
+            (
+                &["**/*.md"],
+                RuleChecks {
+                    analyze: Some(AnalyzeRuleConfig::default()),
+                    tokens: Some(TokensRuleConfig {
+                        budget: Some(100),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ),
+            (
+                &["src/**", "docs/decisions/*.md", "docs/**"],
+                RuleChecks {
+                    tokens: Some(TokensRuleConfig {
+                        budget: Some(500),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let set = RuleSet::compile(&rules);
+        let explanation = set.explain("docs/decisions/0001.md");
+
+        assert_eq!(explanation.matched.len(), 2);
+        assert_eq!(explanation.matched[0].checks, vec!["analyze", "tokens"]);
+        assert_eq!(explanation.matched[1].pattern, "docs/decisions/*.md");
+        assert_eq!(explanation.matched[1].specificity, 2);
+
+        assert_eq!(explanation.winners["analyze"].rule, 0);
+        assert_eq!(explanation.winners["tokens"].rule, 1);
+        assert_eq!(explanation.resolved.tokens.unwrap().budget, Some(500));
+    }
 }
@@ -0,0 +1,364 @@
+//! Document scaffolding from completeness templates.
+//!
+//! Renders a new markdown document from a built-in template, a custom
+//! section list, or a skeleton file, so new documents start from the
+//! template rather than from a copy of an old document.
+//!
+//! Built-in and section-list templates get a title heading and one `TBD`
+//! placeholder per section; the placeholders fail completeness until they
+//! are replaced. Skeleton files are copied with these substitutions:
+//!
+//! - `NNNN` becomes the document number
+//! - `YYYY-MM-DD` becomes the date
+//! - `Title` in the H1 heading becomes the document title
+//! - `(optional)` markers are dropped from headings
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use regex::Regex;
+
+use crate::completeness::{self, strip_optional_marker};
+use crate::config::TemplateConfig;
+use crate::error::{AnalysisError, AnalysisResult};
+
+/// Placeholder body for generated sections.
+const SECTION_PLACEHOLDER: &str = "TBD";
+
+/// Number placeholder in skeleton files.
+const NUMBER_PLACEHOLDER: &str = "NNNN";
+
+/// Date placeholder in skeleton files.
+const DATE_PLACEHOLDER: &str = "YYYY-MM-DD";
+
+/// Title placeholder in a skeleton's H1 heading.
+const TITLE_PLACEHOLDER: &str = "Title";
+
+/// Default digit count for document numbers when none exist yet.
+const DEFAULT_NUMBER_WIDTH: usize = 4;
+
+/// Leading document number in a file name (`0007-use-postgres.md`, `ADR-0007.md`).
+static NUMBERED_FILE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(?:adr-)?(\d+)(?:[-_.]|$)").expect("valid number pattern"));
+
+/// Values substituted into a rendered document.
+#[derive(Debug, Clone, Default)]
+pub struct ScaffoldValues {
+    /// Document title.
+    pub title: String,
+    /// Date in `YYYY-MM-DD` form.
+    pub date: String,
+    /// Formatted document number (e.g. `0007`), for numbered templates.
+    pub number: Option<String>,
+}
+
+/// A template ready to render.
+#[derive(Debug, Clone)]
+pub struct Scaffold {
+    name: String,
+    source: Source,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    Sections(Vec<String>),
+    Skeleton(String),
+}
+
+impl Scaffold {
+    /// Resolve a template name to something that can be rendered.
+    ///
+    /// Custom templates take precedence over built-ins if names collide.
+    pub fn load(
+        name: &str,
+        custom_templates: Option<&HashMap<String, TemplateConfig>>,
+    ) -> AnalysisResult<Self> {
+        let source = match custom_templates.and_then(|c| c.get(name)) {
+            Some(TemplateConfig::Sections(sections)) => Source::Sections(
+                sections
+                    .iter()
+                    .map(|section| section.name().to_string())
+                    .collect(),
+            ),
+            Some(TemplateConfig::Skeleton(skeleton)) => Source::Skeleton(
+                std::fs::read_to_string(skeleton.file.as_std_path()).map_err(|e| {
+                    AnalysisError::TemplateFile {
+                        path: skeleton.file.clone(),
+                        source: e,
+                    }
+                })?,
+            ),
+            None => {
+                let sections = completeness::builtin_sections(name).ok_or_else(|| {
+                    AnalysisError::UnknownTemplate {
+                        name: name.to_string(),
+                        available: completeness::available_templates(custom_templates).join(", "),
+                    }
+                })?;
+                Source::Sections(sections.iter().map(|s| (*s).to_string()).collect())
+            }
+        };
+        Ok(Self {
+            name: name.to_string(),
+            source,
+        })
+    }
+
+    /// Whether documents from this template carry a sequence number.
+    ///
+    /// True for the `adr` template and for skeletons containing `NNNN`.
+    pub fn is_numbered(&self) -> bool {
+        match &self.source {
+            Source::Sections(_) => self.name == "adr",
+            Source::Skeleton(text) => text.contains(NUMBER_PLACEHOLDER),
+        }
+    }
+
+    /// Render the document.
+    pub fn render(&self, values: &ScaffoldValues) -> String {
+        match &self.source {
+            Source::Sections(sections) => self.render_sections(sections, values),
+            Source::Skeleton(text) => render_skeleton(text, values),
+        }
+    }
+
+    fn render_sections(&self, sections: &[String], values: &ScaffoldValues) -> String {
+        let title = &values.title;
+        let mut out = match (self.name.as_str(), &values.number) {
+            ("adr", number) => format!(
+                "---\nstatus: proposed\ndate: {}\n---\n\n# ADR-{}: {title}\n",
+                values.date,
+                number.as_deref().unwrap_or(NUMBER_PLACEHOLDER),
+            ),
+            ("handoff", _) => format!("# Handoff: {title}\n\n**Date:** {}\n", values.date),
+            _ => format!("# {title}\n"),
+        };
+        for section in sections {
+            out.push_str(&format!("\n## {section}\n\n{SECTION_PLACEHOLDER}\n"));
+        }
+        out
+    }
+}
+
+/// Copy a skeleton, filling placeholders and dropping optional markers.
+fn render_skeleton(text: &str, values: &ScaffoldValues) -> String {
+    let number = values.number.as_deref().unwrap_or(NUMBER_PLACEHOLDER);
+    let mut out = String::with_capacity(text.len());
+    let mut in_frontmatter = false;
+    let mut in_fence = false;
+    let mut seen_title = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if line.trim_end() == "---" && (i == 0 || in_frontmatter) {
+            in_frontmatter = i == 0;
+        } else if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        let mut line = line
+            .replace(NUMBER_PLACEHOLDER, number)
+            .replace(DATE_PLACEHOLDER, &values.date);
+
+        if !in_fence
+            && !in_frontmatter
+            && let Some(heading) = line.strip_prefix('#')
+        {
+            let hashes = 1 + heading.chars().take_while(|c| *c == '#').count();
+            let heading_text = &line[hashes..];
+            if heading_text.starts_with(' ') {
+                if hashes == 1 && !seen_title {
+                    seen_title = true;
+                    line = line.replacen(TITLE_PLACEHOLDER, &values.title, 1);
+                } else {
+                    let (name, _) = strip_optional_marker(heading_text);
+                    line = format!("{} {name}", "#".repeat(hashes));
+                }
+            }
+        }
+
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Find the next document number in `dir`.
+///
+/// Scans file names for a leading number (`0007-use-postgres.md`,
+/// `ADR-0007.md`) and returns one past the highest, along with the digit
+/// width to pad to (the widest existing number, or 4 if there are none). A missing directory
+/// starts numbering at 1.
+pub fn next_number(dir: &Utf8Path) -> std::io::Result<(u32, usize)> {
+    let entries = match std::fs::read_dir(dir.as_std_path()) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Ok((1, DEFAULT_NUMBER_WIDTH));
+        }
+        Err(e) => return Err(e),
+    };
+
+    let mut highest = 0;
+    let mut width = 0;
+    for entry in entries {
+        let name = entry?.file_name();
+        let Some(caps) = name.to_str().and_then(|n| NUMBERED_FILE.captures(n)) else {
+            continue;
+        };
+        let digits = &caps[1];
+        if let Ok(n) = digits.parse::<u32>() {
+            highest = highest.max(n);
+            width = width.max(digits.len());
+        }
+    }
+    if width == 0 {
+        width = DEFAULT_NUMBER_WIDTH;
+    }
+    Ok((highest + 1, width))
+}
+
+/// Whether a file name already starts with a document number.
+pub fn has_number(file_name: &str) -> bool {
+    NUMBERED_FILE.is_match(file_name)
+}
+
+/// The document number a file name starts with, if any.
+pub fn file_number(file_name: &str) -> Option<u32> {
+    NUMBERED_FILE
+        .captures(file_name)
+        .and_then(|caps| caps[1].parse().ok())
+}
+
+/// Prefix a path's file name with a document number (`use-pg.md` → `0007-use-pg.md`).
+pub fn numbered_path(path: &Utf8Path, number: &str) -> Utf8PathBuf {
+    let file_name = path.file_name().unwrap_or_default();
+    path.with_file_name(format!("{number}-{file_name}"))
+}
+
+/// Derive a title from a file name (`0007-use-postgres.md` → `Use postgres`).
+pub fn title_from_path(path: &Utf8Path) -> String {
+    let stem = path.file_stem().unwrap_or_default();
+    let stem = NUMBERED_FILE.find(stem).map_or(stem, |m| &stem[m.end()..]);
+    let words = stem.replace(['-', '_'], " ");
+    let mut chars = words.trim().chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::completeness::{SectionStatus, check_completeness};
+
+    fn values() -> ScaffoldValues {
+        ScaffoldValues {
+            title: "Use Postgres".to_string(),
+            date: "2026-03-01".to_string(),
+            number: Some("0007".to_string()),
+        }
+    }
+
+    #[test]
+    fn builtin_adr_has_frontmatter_number_and_placeholders() {
+        let scaffold = Scaffold::load("adr", None).unwrap();
+        assert!(scaffold.is_numbered());
+        let doc = scaffold.render(&values());
+        assert!(doc.starts_with("---\nstatus: proposed\ndate: 2026-03-01\n---\n"));
+        assert!(doc.contains("# ADR-0007: Use Postgres\n"));
+        assert!(doc.contains("## Decision Outcome\n\nTBD\n"));
+
+        // Placeholders fail until filled in.
+        let report = check_completeness(&doc, "adr", None, false).unwrap();
+        assert!(!report.pass);
+        assert!(
+            report
+                .sections
+                .iter()
+                .all(|s| s.status == SectionStatus::Empty)
+        );
+        let filled = doc.replace("TBD", "Real content.");
+        assert!(
+            check_completeness(&filled, "adr", None, false)
+                .unwrap()
+                .pass
+        );
+    }
+
+    #[test]
+    fn handoff_is_not_numbered() {
+        let scaffold = Scaffold::load("handoff", None).unwrap();
+        assert!(!scaffold.is_numbered());
+        let doc = scaffold.render(&values());
+        assert!(doc.starts_with("# Handoff: Use Postgres\n\n**Date:** 2026-03-01\n"));
+    }
+
+    #[test]
+    fn skeleton_placeholders_are_filled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Utf8PathBuf::try_from(dir.path().join("adr.md")).unwrap();
+        std::fs::write(
+            &path,
+            "---\n# Title placeholder lives in the heading, not here\nstatus: proposed\ndate: YYYY-MM-DD\n---\n\n# ADR-NNNN: Title\n\n## Context\n\nDescribe the context.\n\n## Drivers (optional)\n\n```md\n## Title (optional)\n```\n",
+        )
+        .unwrap();
+        let mut custom = HashMap::new();
+        custom.insert(
+            "adr".to_string(),
+            TemplateConfig::Skeleton(crate::config::TemplateSkeleton {
+                file: path,
+                ..Default::default()
+            }),
+        );
+
+        let scaffold = Scaffold::load("adr", Some(&custom)).unwrap();
+        assert!(scaffold.is_numbered());
+        let doc = scaffold.render(&values());
+        assert!(doc.contains("date: 2026-03-01\n"));
+        assert!(doc.contains("# Title placeholder lives in the heading"));
+        assert!(doc.contains("# ADR-0007: Use Postgres\n"));
+        assert!(doc.contains("\n## Drivers\n"));
+        // Fenced code is copied verbatim.
+        assert!(doc.contains("## Title (optional)\n```"));
+    }
+
+    #[test]
+    fn unknown_template_errors() {
+        let err = Scaffold::load("nope", None).unwrap_err();
+        assert!(err.to_string().contains("unknown template"));
+    }
+
+    #[test]
+    fn next_number_scans_existing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        assert_eq!(next_number(&root).unwrap(), (1, 4));
+        for name in ["0001-first.md", "0003-third.md", "README.md", "template.md"] {
+            std::fs::write(root.join(name), "").unwrap();
+        }
+        assert_eq!(next_number(&root).unwrap(), (4, 4));
+        std::fs::write(root.join("ADR-00012.md"), "").unwrap();
+        assert_eq!(next_number(&root).unwrap(), (13, 5));
+        assert_eq!(next_number(&root.join("missing")).unwrap(), (1, 4));
+
+        let short = tempfile::tempdir().unwrap();
+        let short = Utf8PathBuf::try_from(short.path().to_path_buf()).unwrap();
+        std::fs::write(short.join("009-ninth.md"), "").unwrap();
+        assert_eq!(next_number(&short).unwrap(), (10, 3));
+    }
+
+    #[test]
+    fn file_name_helpers() {
+        assert!(has_number("0007-use-postgres.md"));
+        assert!(!has_number("use-postgres.md"));
+        assert_eq!(
+            numbered_path(Utf8Path::new("docs/decisions/use-pg.md"), "0007"),
+            Utf8PathBuf::from("docs/decisions/0007-use-pg.md")
+        );
+        assert_eq!(
+            title_from_path(Utf8Path::new("docs/0007-use-postgres.md")),
+            "Use postgres"
+        );
+        assert_eq!(title_from_path(Utf8Path::new("sprint_42.md")), "Sprint 42");
+    }
+}
@@ -97,6 +97,117 @@ pub static VAGUE_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
 pub static VAGUE_PHRASES: LazyLock<HashSet<&'static str>> =
     LazyLock::new(|| ["kind of", "sort of", "a bit"].into_iter().collect());
 
+/// Hedges: words and phrases that soften a claim until it says little.
+pub static HEDGES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "arguably",
+        "apparently",
+        "presumably",
+        "seemingly",
+        "somewhat",
+        "perhaps",
+        "possibly",
+        "probably",
+        "relatively",
+        "fairly",
+        "it seems",
+        "it appears",
+        "seems to",
+        "appears to",
+        "might possibly",
+        "could possibly",
+        "may possibly",
+        "generally speaking",
+        "more or less",
+        "to some extent",
+        "to a certain extent",
+        "in some ways",
+        "i think",
+        "i believe",
+        "i feel",
+        "we think",
+        "we believe",
+        "in my opinion",
+        "tends to",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Weasel quantifiers: unattributed appeals to authority or consensus.
+pub static WEASEL_PHRASES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "many people say",
+        "some people say",
+        "people say",
+        "it is widely believed",
+        "it is widely known",
+        "it is generally accepted",
+        "it is said",
+        "it has been said",
+        "it is thought",
+        "experts say",
+        "experts agree",
+        "studies show",
+        "research shows",
+        "critics say",
+        "some would say",
+        "some argue",
+        "many believe",
+        "most people",
+        "everyone knows",
+        "it is commonly known",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Stock phrases characteristic of machine-drafted prose.
+pub static AI_TELL_PHRASES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "delve into",
+        "delves into",
+        "delving into",
+        "it's worth noting that",
+        "it is worth noting that",
+        "it's important to note that",
+        "it is important to note that",
+        "in today's fast-paced world",
+        "in today's digital age",
+        "in the ever-evolving",
+        "ever-evolving landscape",
+        "rich tapestry",
+        "a testament to",
+        "navigate the complexities",
+        "navigating the complexities",
+        "unlock the potential",
+        "harness the power",
+        "embark on a journey",
+        "at its core",
+        "in the realm of",
+        "plays a crucial role",
+        "plays a pivotal role",
+        "a game-changer",
+        "look no further",
+        "let's dive in",
+        "dive deep into",
+        "whether you're a",
+        "elevate your",
+        "it's not just about",
+        "the intricacies of",
+        "a myriad of",
+        "underscores the importance",
+        "in conclusion",
+        "when it comes to",
+        "a seamless experience",
+        "robust and scalable",
+        "cutting-edge",
+        "treasure trove",
+    ]
+    .into_iter()
+    .collect()
+});
+
 /// Business jargon words.
 pub static BUSINESS_JARGON: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     [
@@ -368,6 +479,129 @@ pub static SENSORY_WORDS: LazyLock<HashMap<&'static str, HashSet<&'static str>>>
         map
     });
 
+/// Light verbs that pair with a nominalization ("make a decision").
+pub static LIGHT_VERBS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "make",
+        "makes",
+        "made",
+        "making",
+        "perform",
+        "performs",
+        "performed",
+        "performing",
+        "conduct",
+        "conducts",
+        "conducted",
+        "conducting",
+        "give",
+        "gives",
+        "gave",
+        "given",
+        "giving",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Nouns with nominalization suffixes that do not hide a verb.
+pub static NOT_NOMINALIZATIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "apartment",
+        "audience",
+        "authority",
+        "balance",
+        "city",
+        "community",
+        "department",
+        "distance",
+        "element",
+        "entity",
+        "environment",
+        "equipment",
+        "evidence",
+        "finance",
+        "government",
+        "identity",
+        "instance",
+        "majority",
+        "minority",
+        "moment",
+        "nation",
+        "opportunity",
+        "quality",
+        "quantity",
+        "science",
+        "segment",
+        "sentence",
+        "station",
+        "university",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Wordy phrases and redundancies with their concise replacements.
+pub static WORDY_PHRASES: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
+    [
+        ("in order to", "to"),
+        ("at this point in time", "now"),
+        ("at the present time", "now"),
+        ("at this moment in time", "now"),
+        ("due to the fact that", "because"),
+        ("owing to the fact that", "because"),
+        ("in light of the fact that", "because"),
+        ("in spite of the fact that", "although"),
+        ("despite the fact that", "although"),
+        ("for the purpose of", "to"),
+        ("in the event that", "if"),
+        ("with regard to", "about"),
+        ("with respect to", "about"),
+        ("in regard to", "about"),
+        ("in reference to", "about"),
+        ("a large number of", "many"),
+        ("a majority of", "most"),
+        ("in the near future", "soon"),
+        ("prior to", "before"),
+        ("subsequent to", "after"),
+        ("has the ability to", "can"),
+        ("have the ability to", "can"),
+        ("is able to", "can"),
+        ("are able to", "can"),
+        ("in close proximity to", "near"),
+        ("on a daily basis", "daily"),
+        ("on a regular basis", "regularly"),
+        ("in a timely manner", "promptly"),
+        ("at all times", "always"),
+        ("in the absence of", "without"),
+        ("make use of", "use"),
+        ("take into consideration", "consider"),
+        ("until such time as", "until"),
+        ("each and every", "each"),
+        ("first and foremost", "first"),
+        ("whether or not", "whether"),
+        ("past history", "history"),
+        ("end result", "result"),
+        ("final outcome", "outcome"),
+        ("future plans", "plans"),
+        ("advance planning", "planning"),
+        ("added bonus", "bonus"),
+        ("free gift", "gift"),
+        ("basic fundamentals", "fundamentals"),
+        ("absolutely essential", "essential"),
+        ("completely eliminate", "eliminate"),
+        ("close proximity", "proximity"),
+        ("unexpected surprise", "surprise"),
+        ("join together", "join"),
+        ("collaborate together", "collaborate"),
+        ("repeat again", "repeat"),
+        ("revert back", "revert"),
+        ("return back", "return"),
+    ]
+    .into_iter()
+    .collect()
+});
+
 /// Hidden verbs: noun forms that could be replaced with their verb equivalents.
 pub static HIDDEN_VERBS: LazyLock<HashMap<&'static str, &'static str>> = LazyLock::new(|| {
     [
@@ -390,6 +624,73 @@ pub static HIDDEN_VERBS: LazyLock<HashMap<&'static str, &'static str>> = LazyLoc
     .collect()
 });
 
+/// Verbs for nominalizations outside [`HIDDEN_VERBS`].
+///
+/// A nominalization whose noun is in neither table is reported without a
+/// suggested verb.
+pub static NOMINALIZATION_VERBS: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| {
+        [
+            ("acceptance", "accept"),
+            ("adjustment", "adjust"),
+            ("agreement", "agree"),
+            ("allocation", "allocate"),
+            ("announcement", "announce"),
+            ("application", "apply"),
+            ("arrangement", "arrange"),
+            ("assessment", "assess"),
+            ("assistance", "assist"),
+            ("calculation", "calculate"),
+            ("clarification", "clarify"),
+            ("collection", "collect"),
+            ("comparison", "compare"),
+            ("completion", "complete"),
+            ("confirmation", "confirm"),
+            ("connection", "connect"),
+            ("contribution", "contribute"),
+            ("correction", "correct"),
+            ("declaration", "declare"),
+            ("definition", "define"),
+            ("demonstration", "demonstrate"),
+            ("determination", "determine"),
+            ("development", "develop"),
+            ("distribution", "distribute"),
+            ("enhancement", "enhance"),
+            ("evaluation", "evaluate"),
+            ("execution", "execute"),
+            ("expansion", "expand"),
+            ("experiment", "experiment"),
+            ("extension", "extend"),
+            ("identification", "identify"),
+            ("improvement", "improve"),
+            ("information", "inform"),
+            ("inspection", "inspect"),
+            ("installation", "install"),
+            ("introduction", "introduce"),
+            ("maintenance", "maintain"),
+            ("measurement", "measure"),
+            ("modification", "modify"),
+            ("notification", "notify"),
+            ("optimization", "optimize"),
+            ("payment", "pay"),
+            ("permission", "permit"),
+            ("preparation", "prepare"),
+            ("presentation", "present"),
+            ("reduction", "reduce"),
+            ("replacement", "replace"),
+            ("reservation", "reserve"),
+            ("revision", "revise"),
+            ("selection", "select"),
+            ("simplification", "simplify"),
+            ("specification", "specify"),
+            ("synthesis", "synthesize"),
+            ("validation", "validate"),
+            ("verification", "verify"),
+        ]
+        .into_iter()
+        .collect()
+    });
+
 /// Coordinating conjunctions.
 pub static CONJUNCTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     ["and", "but", "or", "so", "yet", "for", "nor"]
@@ -397,6 +698,28 @@ pub static CONJUNCTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
         .collect()
 });
 
+/// Articles, conjunctions, and short prepositions left lowercase in title case.
+pub static TITLE_CASE_MINOR_WORDS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "a", "an", "the", "and", "but", "or", "nor", "for", "so", "yet", "as", "at", "by", "in",
+        "of", "off", "on", "per", "to", "up", "via", "vs",
+    ]
+    .into_iter()
+    .collect()
+});
+
+/// Acronyms familiar enough to use without a definition.
+pub static WELL_KNOWN_ACRONYMS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    [
+        "AI", "API", "ASCII", "CD", "CI", "CLI", "CPU", "CSS", "CSV", "DNS", "EU", "FAQ", "GPU",
+        "GUI", "HTML", "HTTP", "HTTPS", "ID", "IDE", "IO", "IP", "JSON", "OK", "OS", "PDF", "PR",
+        "RAM", "README", "REST", "SDK", "SQL", "SSH", "SSL", "TCP", "TLS", "TODO", "UDP", "UI",
+        "UK", "URI", "URL", "US", "USB", "UTF", "UX", "XML", "YAML",
+    ]
+    .into_iter()
+    .collect()
+});
+
 /// Categorizes spelling differences between US and UK English.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SpellingPattern {
@@ -0,0 +1,324 @@
+//! Sandboxed path resolution and reads under a project root.
+//!
+//! Callers such as the MCP server accept workspace-relative paths and globs
+//! from untrusted clients. Every path is checked lexically (no absolute
+//! paths, no `..`) and again after symlinks are resolved, so nothing outside
+//! the root can be listed or read.
+//!
+//! Directory walks skip hidden entries and build output directories, and do
+//! not follow symlinked directories. Naming a hidden file explicitly still
+//! works.
+
+use camino::{Utf8Component, Utf8Path, Utf8PathBuf};
+use globset::Glob;
+
+use crate::error::{AnalysisError, AnalysisResult};
+
+/// Directory names never descended into while walking.
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// A project root that paths are resolved and read against.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    root: Utf8PathBuf,
+}
+
+/// Files matched by a set of paths and globs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathExpansion {
+    /// Matched files, relative to the root, sorted and deduplicated.
+    pub files: Vec<String>,
+    /// Paths and globs that matched no file, in the order given.
+    pub unmatched: Vec<String>,
+}
+
+impl Workspace {
+    /// Open a workspace rooted at `root`, which must be an existing directory.
+    pub fn open(root: &Utf8Path) -> AnalysisResult<Self> {
+        let root = root
+            .canonicalize_utf8()
+            .map_err(|source| AnalysisError::WorkspaceRead {
+                path: root.to_path_buf(),
+                source,
+            })?;
+        Ok(Self { root })
+    }
+
+    /// The canonical root directory.
+    pub fn root(&self) -> &Utf8Path {
+        &self.root
+    }
+
+    /// Expand workspace-relative paths and globs into a list of files.
+    ///
+    /// A directory expands to every file beneath it. Globs use the same
+    /// syntax as rule `paths`.
+    pub fn expand(&self, patterns: &[String]) -> AnalysisResult<PathExpansion> {
+        let mut expansion = PathExpansion::default();
+
+        for pattern in patterns {
+            let relative = normalize(pattern)?;
+            let before = expansion.files.len();
+
+            if is_glob(&relative) {
+                let matcher = Glob::new(&relative)
+                    .map_err(|e| AnalysisError::InvalidPattern {
+                        pattern: pattern.clone(),
+                        reason: e.kind().to_string(),
+                    })?
+                    .compile_matcher();
+                let base = literal_prefix(&relative);
+                let mut found = Vec::new();
+                if self
+                    .resolve(&base)
+                    .is_some_and(|dir| dir.starts_with(&self.root) && dir.is_dir())
+                {
+                    self.walk(&base, &mut found)?;
+                }
+                expansion
+                    .files
+                    .extend(found.into_iter().filter(|f| matcher.is_match(f)));
+            } else if let Some(full) = self.resolve(&relative) {
+                if !full.starts_with(&self.root) {
+                    return Err(AnalysisError::OutsideWorkspace(pattern.clone()));
+                }
+                if full.is_dir() {
+                    self.walk(&relative, &mut expansion.files)?;
+                } else if full.is_file() {
+                    expansion.files.push(relative);
+                }
+            }
+
+            if expansion.files.len() == before {
+                expansion.unmatched.push(pattern.clone());
+            }
+        }
+
+        expansion.files.sort();
+        expansion.files.dedup();
+        Ok(expansion)
+    }
+
+    /// Read a workspace-relative file as UTF-8.
+    ///
+    /// Fails with [`AnalysisError::OutsideWorkspace`] if the path, once
+    /// symlinks are resolved, lies outside the root.
+    pub fn read(&self, relative: &str) -> AnalysisResult<String> {
+        let relative = normalize(relative)?;
+        let joined = self.root.join(&relative);
+        let read_error = |source| AnalysisError::WorkspaceRead {
+            path: Utf8PathBuf::from(&relative),
+            source,
+        };
+        let full = joined.canonicalize_utf8().map_err(read_error)?;
+        if !full.starts_with(&self.root) {
+            return Err(AnalysisError::OutsideWorkspace(relative));
+        }
+        std::fs::read_to_string(&full).map_err(read_error)
+    }
+
+    /// Canonical path of a workspace-relative path, or `None` if it does not
+    /// exist. The result may lie outside the root.
+    fn resolve(&self, relative: &str) -> Option<Utf8PathBuf> {
+        self.root.join(relative).canonicalize_utf8().ok()
+    }
+
+    /// Collect files under the relative directory `dir` into `out`.
+    ///
+    /// `dir` must resolve inside the root; symlinked subdirectories are not
+    /// followed, and symlinked files are kept only if they resolve inside it.
+    fn walk(&self, dir: &str, out: &mut Vec<String>) -> AnalysisResult<()> {
+        let full = self.root.join(dir);
+        let dir_error = |source| AnalysisError::WorkspaceRead {
+            path: Utf8PathBuf::from(dir),
+            source,
+        };
+
+        for entry in full.read_dir_utf8().map_err(dir_error)? {
+            let entry = entry.map_err(dir_error)?;
+            let name = entry.file_name();
+            if name.starts_with('.') {
+                continue;
+            }
+            let relative = if dir.is_empty() {
+                name.to_string()
+            } else {
+                format!("{dir}/{name}")
+            };
+            let file_type = entry.file_type().map_err(dir_error)?;
+            if file_type.is_dir() {
+                if !SKIPPED_DIRS.contains(&name) {
+                    self.walk(&relative, out)?;
+                }
+            } else if file_type.is_file()
+                || (file_type.is_symlink()
+                    && self
+                        .resolve(&relative)
+                        .is_some_and(|target| target.starts_with(&self.root) && target.is_file()))
+            {
+                out.push(relative);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Check that `pattern` stays inside the root and normalize it to
+/// `/`-separated components without `./`.
+fn normalize(pattern: &str) -> AnalysisResult<String> {
+    let mut parts = Vec::new();
+    for component in Utf8Path::new(pattern).components() {
+        match component {
+            Utf8Component::Normal(part) => parts.push(part),
+            Utf8Component::CurDir => {}
+            Utf8Component::ParentDir | Utf8Component::RootDir | Utf8Component::Prefix(_) => {
+                return Err(AnalysisError::OutsideWorkspace(pattern.to_string()));
+            }
+        }
+    }
+    Ok(parts.join("/"))
+}
+
+/// Whether a path contains glob metacharacters.
+fn is_glob(path: &str) -> bool {
+    path.contains(['*', '?', '[', '{'])
+}
+
+/// Leading path segments of a glob that contain no metacharacters.
+///
+/// `docs/decisions/*.md` → `docs/decisions`
+/// `**/*.md` → ``
+fn literal_prefix(glob: &str) -> String {
+    let segments: Vec<&str> = glob.split('/').collect();
+    segments[..segments.len() - 1]
+        .iter()
+        .take_while(|seg| !is_glob(seg))
+        .copied()
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (tempfile::TempDir, Workspace) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        for (path, content) in [
+            ("README.md", "# Readme\n"),
+            ("docs/guide.md", "# Guide\n"),
+            ("docs/decisions/0001-a.md", "# A\n"),
+            ("docs/notes.txt", "notes\n"),
+            ("docs/.draft.md", "# Draft\n"),
+            ("target/doc/out.md", "# Built\n"),
+        ] {
+            let full = root.join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        }
+        let workspace = Workspace::open(&root).unwrap();
+        (dir, workspace)
+    }
+
+    fn expand(workspace: &Workspace, patterns: &[&str]) -> PathExpansion {
+        let patterns: Vec<String> = patterns.iter().map(|s| (*s).to_string()).collect();
+        workspace.expand(&patterns).unwrap()
+    }
+
+    #[test]
+    fn literal_paths_and_directories() {
+        let (_dir, ws) = fixture();
+        let expansion = expand(&ws, &["./README.md", "docs/decisions", "missing.md"]);
+        assert_eq!(
+            expansion.files,
+            vec!["README.md", "docs/decisions/0001-a.md"]
+        );
+        assert_eq!(expansion.unmatched, vec!["missing.md"]);
+    }
+
+    #[test]
+    fn globs_skip_hidden_and_build_dirs() {
+        let (_dir, ws) = fixture();
+        let expansion = expand(&ws, &["**/*.md", "docs/*.md"]);
+        assert_eq!(
+            expansion.files,
+            vec!["README.md", "docs/decisions/0001-a.md", "docs/guide.md"]
+        );
+        assert!(expansion.unmatched.is_empty());
+    }
+
+    #[test]
+    fn escaping_paths_rejected() {
+        let (_dir, ws) = fixture();
+        for pattern in ["../secret.md", "/etc/passwd", "docs/../../x.md"] {
+            let err = ws.expand(&[pattern.to_string()]).unwrap_err();
+            assert!(
+                matches!(err, AnalysisError::OutsideWorkspace(_)),
+                "{pattern}"
+            );
+            assert!(ws.read(pattern).is_err(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn read_returns_contents() {
+        let (_dir, ws) = fixture();
+        assert_eq!(ws.read("docs/guide.md").unwrap(), "# Guide\n");
+        assert!(matches!(
+            ws.read("docs/missing.md"),
+            Err(AnalysisError::WorkspaceRead { .. })
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_out_of_root_not_readable() {
+        let (dir, ws) = fixture();
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::write(outside.path().join("secret.md"), "secret").unwrap();
+        std::os::unix::fs::symlink(
+            outside.path().join("secret.md"),
+            dir.path().join("docs/link.md"),
+        )
+        .unwrap();
+        assert!(matches!(
+            ws.read("docs/link.md"),
+            Err(AnalysisError::OutsideWorkspace(_))
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_out_of_root_not_walked() {
+        let (dir, ws) = fixture();
+        let outside = tempfile::tempdir().unwrap();
+        std::fs::create_dir(outside.path().join("private")).unwrap();
+        std::fs::write(outside.path().join("private/secret.md"), "secret").unwrap();
+        std::fs::write(outside.path().join("loose.md"), "secret").unwrap();
+        let link = |target: &str, name: &str| {
+            std::os::unix::fs::symlink(outside.path().join(target), dir.path().join(name)).unwrap();
+        };
+        link("private", "docs/private");
+        link("private", "escape");
+        link("loose.md", "docs/loose.md");
+
+        let expansion = expand(&ws, &["**/*.md", "docs", "escape/*.md"]);
+        assert_eq!(
+            expansion.files,
+            vec![
+                "README.md",
+                "docs/decisions/0001-a.md",
+                "docs/guide.md",
+                "docs/notes.txt"
+            ]
+        );
+        assert_eq!(expansion.unmatched, vec!["escape/*.md"]);
+        for pattern in ["escape", "docs/private/secret.md"] {
+            assert!(matches!(
+                ws.expand(&[pattern.to_string()]),
+                Err(AnalysisError::OutsideWorkspace(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! ADR command — validate a directory of Architecture Decision Records.
+
+use anyhow::{Context, bail};
+use camino::Utf8PathBuf;
+use clap::Args;
+use owo_colors::OwoColorize;
+use tracing::{debug, instrument};
+
+use bito_lint_core::adr::{self, AdrIssueKind};
+use bito_lint_core::config::AdrConfig;
+
+/// Arguments for the `adr` subcommand.
+#[derive(Args, Debug)]
+pub struct AdrArgs {
+    /// Directory containing the ADRs (default: `adr.dir` from config).
+    pub dir: Option<Utf8PathBuf>,
+}
+
+/// Check numbering, supersede links, and statuses across an ADR directory.
+#[instrument(name = "cmd_adr", skip_all)]
+pub fn cmd_adr(
+    args: AdrArgs,
+    global_json: bool,
+    adr_config: Option<&AdrConfig>,
+) -> anyhow::Result<()> {
+    let config = match (args.dir, adr_config) {
+        (Some(dir), Some(config)) => AdrConfig {
+            dir,
+            ..config.clone()
+        },
+        (Some(dir), None) => AdrConfig {
+            dir,
+            statuses: None,
+            first_number: None,
+        },
+        (None, Some(config)) => config.clone(),
+        (None, None) => bail!("no ADR directory given (pass DIR or set adr.dir in config)"),
+    };
+    debug!(dir = %config.dir, "executing adr command");
+
+    let report = adr::check_adr_dir(&config.dir, &config)
+        .with_context(|| format!("failed to check ADRs in {}", config.dir))?;
+
+    if global_json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        for file in &report.files {
+            if file.issues.is_empty() {
+                println!("{} {}", "PASS:".green(), file.file);
+                continue;
+            }
+            println!("{} {}", "FAIL:".red(), file.file);
+            for issue in &file.issues {
+                println!("  {} {}", issue_label(issue.kind).yellow(), issue.message);
+            }
+        }
+    }
+
+    if !report.pass {
+        let failing = report.files.iter().filter(|f| !f.issues.is_empty()).count();
+        bail!(
+            "{} of {} ADRs in {} have problems",
+            failing,
+            report.count,
+            report.dir
+        );
+    }
+    if !global_json {
+        println!(
+            "{} {} ADRs in {}",
+            "PASS:".green(),
+            report.count,
+            report.dir
+        );
+    }
+
+    Ok(())
+}
+
+/// Short uppercase label for an ADR issue kind.
+const fn issue_label(kind: AdrIssueKind) -> &'static str {
+    match kind {
+        AdrIssueKind::Numbering => "NUMBERING:",
+        AdrIssueKind::BrokenLink => "BROKEN LINK:",
+        AdrIssueKind::NotReciprocal => "ONE-SIDED LINK:",
+        AdrIssueKind::Status => "STATUS:",
+    }
+}
@@ -9,8 +9,8 @@ use owo_colors::OwoColorize;
 use tracing::{debug, instrument};
 
 use bito_lint_core::analysis;
-use bito_lint_core::analysis::ALL_CHECKS;
-use bito_lint_core::config::Dialect;
+use bito_lint_core::analysis::{ALL_CHECKS, AnalysisOptions};
+use bito_lint_core::config::{Config, Dialect};
 
 use super::read_input_file;
 
@@ -43,6 +43,10 @@ pub struct AnalyzeArgs {
     /// English dialect for spelling enforcement (en-us, en-gb, en-ca, en-au).
     #[arg(long)]
     pub dialect: Option<Dialect>,
+
+    /// Acronyms that need no definition (comma-separated), beyond the built-in list.
+    #[arg(long, value_delimiter = ',')]
+    pub known_acronyms: Option<Vec<String>>,
 }
 
 /// Run comprehensive writing analysis on a file.
@@ -50,10 +54,7 @@ pub struct AnalyzeArgs {
 pub fn cmd_analyze(
     args: AnalyzeArgs,
     global_json: bool,
-    config_style_min: Option<i32>,
-    config_max_grade: Option<f64>,
-    config_passive_max: Option<f64>,
-    config_dialect: Option<Dialect>,
+    config: &Config,
     max_input_bytes: Option<usize>,
 ) -> anyhow::Result<()> {
     debug!(file = %args.file, checks = ?args.checks, exclude = ?args.exclude, "executing analyze command");
@@ -61,23 +62,23 @@ pub fn cmd_analyze(
     let content = read_input_file(&args.file, max_input_bytes)?;
 
     let strip_md = args.file.extension() == Some("md");
-    let style_min = args.style_min.or(config_style_min);
-    let max_grade = args.max_grade.or(config_max_grade);
-    let passive_max = args.passive_max.or(config_passive_max);
-    let dialect = args.dialect.or(config_dialect);
+    let style_min = args.style_min.or(config.style_min_score);
+    let options = AnalysisOptions {
+        max_grade: args.max_grade.or(config.max_grade),
+        passive_max: args.passive_max.or(config.passive_max_percent),
+        dialect: args.dialect.or(config.dialect),
+        known_acronyms: args
+            .known_acronyms
+            .or_else(|| config.known_acronyms.clone())
+            .unwrap_or_default(),
+        style_score: config.style_score.clone(),
+    };
 
     // Resolve --checks / --exclude into the final check list.
     let resolved_checks = resolve_checks(args.checks, args.exclude)?;
     let checks_ref = resolved_checks.as_deref();
-    let report = analysis::run_full_analysis(
-        &content,
-        strip_md,
-        checks_ref,
-        max_grade,
-        passive_max,
-        dialect,
-    )
-    .with_context(|| format!("failed to analyze {}", args.file))?;
+    let report = analysis::run_full_analysis(&content, strip_md, checks_ref, &options)
+        .with_context(|| format!("failed to analyze {}", args.file))?;
 
     if global_json {
         println!("{}", serde_json::to_string_pretty(&report)?);
@@ -162,6 +163,52 @@ pub fn cmd_analyze(
         println!("\n  {} {} vague words", "Diction:".cyan(), d.total_vague,);
     }
 
+    if let Some(ref h) = report.hedging
+        && h.total_hedges > 0
+    {
+        println!(
+            "\n  {} {} hedges ({:.1} per 100 words)",
+            "Hedging:".yellow(),
+            h.total_hedges,
+            h.density,
+        );
+        for found in &h.hedges {
+            println!("    sentence {}: \"{}\"", found.sentence_num, found.phrase);
+        }
+    }
+
+    if let Some(ref w) = report.wordiness
+        && w.total_wordy > 0
+    {
+        println!(
+            "\n  {} {} wordy phrases ({} words, ~{} tokens to save)",
+            "Wordiness:".yellow(),
+            w.total_wordy,
+            w.words_saved,
+            w.tokens_saved,
+        );
+        for p in &w.phrases {
+            println!(
+                "    sentence {}: \"{}\" → \"{}\"",
+                p.sentence_num, p.phrase, p.replacement
+            );
+        }
+    }
+
+    if let Some(ref a) = report.ai_tells
+        && a.total_tells > 0
+    {
+        println!(
+            "\n  {} {} tells ({:.1} per 100 words)",
+            "AI tells:".yellow(),
+            a.total_tells,
+            a.density,
+        );
+        for t in &a.tells {
+            println!("    paragraph {}: \"{}\"", t.paragraph_num, t.text);
+        }
+    }
+
     if let Some(ref c) = report.cliches
         && c.total_cliches > 0
     {
@@ -190,6 +237,25 @@ pub fn cmd_analyze(
         }
     }
 
+    if let Some(ref a) = report.acronyms
+        && !a.undefined.is_empty()
+    {
+        println!(
+            "\n  {} {} undefined acronyms",
+            "Acronyms:".yellow(),
+            a.undefined.len(),
+        );
+        for u in &a.undefined {
+            let later = u
+                .defined_at
+                .map_or(String::new(), |n| format!(" (defined in sentence {n})"));
+            println!(
+                "    {} first used in sentence {}{}",
+                u.acronym, u.sentence_num, later
+            );
+        }
+    }
+
     if let Some(ref j) = report.jargon
         && j.total_jargon > 0
     {
@@ -211,6 +277,25 @@ pub fn cmd_analyze(
             st.adverb_count,
             st.hidden_verbs.len(),
         );
+        let costs: Vec<_> = st
+            .deductions
+            .iter()
+            .filter(|d| d.points > 0.0)
+            .map(|d| format!("{} −{:.1}", d.component, d.points))
+            .collect();
+        if !costs.is_empty() {
+            println!("    deductions: {}", costs.join(", "));
+        }
+        for n in &st.nominalizations {
+            let suggestion = n
+                .verb
+                .as_deref()
+                .map_or(String::new(), |v| format!(" → \"{v}\""));
+            println!(
+                "    sentence {}: \"{}\"{}",
+                n.sentence_num, n.phrase, suggestion
+            );
+        }
     }
 
     // Check style score gate
@@ -7,6 +7,7 @@ use owo_colors::OwoColorize;
 use tracing::{debug, instrument};
 
 use bito_lint_core::completeness::{self, SectionStatus};
+use bito_lint_core::config::TemplateConfig;
 
 use super::read_input_file;
 
@@ -26,15 +27,21 @@ pub struct CompletenessArgs {
 pub fn cmd_completeness(
     args: CompletenessArgs,
     global_json: bool,
-    custom_templates: Option<&std::collections::HashMap<String, Vec<String>>>,
+    custom_templates: Option<&std::collections::HashMap<String, TemplateConfig>>,
+    fuzzy_builtins: bool,
     max_input_bytes: Option<usize>,
 ) -> anyhow::Result<()> {
     debug!(file = %args.file, template = %args.template, "executing completeness command");
 
     let content = read_input_file(&args.file, max_input_bytes)?;
 
-    let report = completeness::check_completeness(&content, &args.template, custom_templates)
-        .with_context(|| format!("failed to check completeness of {}", args.file))?;
+    let report = completeness::check_completeness(
+        &content,
+        &args.template,
+        custom_templates,
+        fuzzy_builtins,
+    )
+    .with_context(|| format!("failed to check completeness of {}", args.file))?;
 
     if global_json {
         println!("{}", serde_json::to_string_pretty(&report)?);
@@ -47,7 +54,7 @@ pub fn cmd_completeness(
         );
     } else {
         let mut issues = Vec::new();
-        for section in &report.sections {
+        for section in report.sections.iter().filter(|s| s.required) {
             match section.status {
                 SectionStatus::Missing => {
                     issues.push(format!("  {} ## {}", "MISSING:".red(), section.name));
@@ -60,8 +67,22 @@ pub fn cmd_completeness(
                     ));
                 }
                 SectionStatus::Present => {}
+                status => {
+                    issues.push(format!(
+                        "  {} ## {} ({})",
+                        status_label(status).yellow(),
+                        section.name,
+                        section
+                            .detail
+                            .as_deref()
+                            .unwrap_or("does not meet template"),
+                    ));
+                }
             }
         }
+        for key in &report.missing_frontmatter {
+            issues.push(format!("  {} frontmatter key '{}'", "MISSING:".red(), key));
+        }
         let detail = issues.join("\n");
         bail!(
             "{} ({} completeness check)\n{}",
@@ -73,3 +94,20 @@ pub fn cmd_completeness(
 
     Ok(())
 }
+
+/// Short uppercase label for a failing section status.
+const fn status_label(status: SectionStatus) -> &'static str {
+    match status {
+        SectionStatus::Present => "PRESENT:",
+        SectionStatus::Empty => "EMPTY:",
+        SectionStatus::Missing => "MISSING:",
+        SectionStatus::OutOfOrder => "OUT OF ORDER:",
+        SectionStatus::WrongLevel => "WRONG LEVEL:",
+        SectionStatus::NearMiss => "NEAR MISS:",
+        SectionStatus::TooShort => "TOO SHORT:",
+        SectionStatus::TooFewListItems => "TOO FEW ITEMS:",
+        SectionStatus::MissingTable => "NO TABLE:",
+        SectionStatus::MissingCodeBlock => "NO CODE BLOCK:",
+        SectionStatus::MissingLink => "NO LINK:",
+    }
+}
@@ -4,6 +4,8 @@
 //! resolves which checks apply, and runs them all. This is the
 //! CLI counterpart of the `lint_file` MCP tool.
 
+use std::borrow::Cow;
+
 use anyhow::{Context, bail};
 use camino::Utf8PathBuf;
 use clap::Args;
@@ -21,6 +23,9 @@ use super::read_input_file;
 pub struct LintArgs {
     /// File to lint.
     pub file: Utf8PathBuf,
+    /// Fail on suppression directives that hid no findings.
+    #[arg(long)]
+    pub report_unused_directives: bool,
 }
 
 /// Lint a file according to project rules.
@@ -57,7 +62,15 @@ pub fn cmd_lint(
 
     let content = read_input_file(&args.file, max_input_bytes)?;
 
-    let report = lint::run_lint(file_str, &content, &resolved, config)
+    let config = if args.report_unused_directives {
+        Cow::Owned(Config {
+            report_unused_directives: true,
+            ..config.clone()
+        })
+    } else {
+        Cow::Borrowed(config)
+    };
+    let report = lint::run_lint(file_str, &content, &resolved, &config)
         .with_context(|| format!("failed to lint {}", args.file))?;
 
     if global_json {
@@ -121,6 +134,52 @@ pub fn cmd_lint(
         println!("  {} {} ({})", "completeness:".cyan(), status, c.template);
     }
 
+    if let Some(ref f) = report.frontmatter {
+        let status = if f.pass {
+            "PASS".green().to_string()
+        } else {
+            "FAIL".red().to_string()
+        };
+        println!("  {} {} ({})", "frontmatter:".cyan(), status, f.schema);
+        for issue in &f.issues {
+            println!("    {}", issue.message);
+        }
+    }
+
+    if let Some(ref h) = report.headings {
+        let status = if h.pass {
+            "PASS".green().to_string()
+        } else {
+            "FAIL".red().to_string()
+        };
+        println!(
+            "  {} {} ({} headings)",
+            "headings:".cyan(),
+            status,
+            h.headings
+        );
+        for issue in &h.issues {
+            println!("    line {}: {}", issue.line, issue.message);
+        }
+    }
+
+    if let Some(ref l) = report.links {
+        let status = if l.pass {
+            "PASS".green().to_string()
+        } else {
+            "FAIL".red().to_string()
+        };
+        println!(
+            "  {} {} ({} checked)",
+            "links:".cyan(),
+            status,
+            l.links_checked
+        );
+        for issue in &l.issues {
+            println!("    line {}: {}", issue.line, issue.message);
+        }
+    }
+
     if let Some(ref t) = report.tokens {
         let status = if t.over_budget {
             "FAIL".red().to_string()
@@ -134,6 +193,13 @@ pub fn cmd_lint(
         }
     }
 
+    if !report.directives.is_empty() {
+        println!("  {} {}", "directives:".cyan(), "FAIL".red());
+        for issue in &report.directives {
+            println!("    line {}: {}", issue.line, issue.message);
+        }
+    }
+
     if !report.pass {
         bail!("{} failed lint checks", args.file);
     }
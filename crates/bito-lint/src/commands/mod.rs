@@ -3,6 +3,7 @@
 use anyhow::Context;
 use camino::Utf8Path;
 
+pub mod adr;
 pub mod analyze;
 pub mod completeness;
 pub mod custom;
@@ -10,6 +11,7 @@ pub mod doctor;
 pub mod grammar;
 pub mod info;
 pub mod lint;
+pub mod new;
 pub mod readability;
 #[cfg(feature = "mcp")]
 pub mod serve;
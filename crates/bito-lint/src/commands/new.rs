@@ -0,0 +1,155 @@
+//! New command — scaffold a document from a completeness template.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use clap::Args;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use tracing::{debug, instrument};
+
+use bito_lint_core::config::TemplateConfig;
+use bito_lint_core::scaffold::{self, Scaffold, ScaffoldValues};
+
+/// Arguments for the `new` subcommand.
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    /// Template to scaffold from (adr, handoff, design-doc, or custom).
+    pub template: String,
+
+    /// Path of the document to create.
+    ///
+    /// For numbered templates (adr), the next free number in the target
+    /// directory is prefixed to the file name unless it already has one.
+    pub path: Utf8PathBuf,
+
+    /// Document title (default: derived from the file name).
+    #[arg(long)]
+    pub title: Option<String>,
+
+    /// Overwrite the file if it already exists.
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// JSON output for the `new` command.
+#[derive(Serialize)]
+struct NewOutput<'a> {
+    path: &'a Utf8Path,
+    template: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    number: Option<&'a str>,
+}
+
+/// Create a new document from a template.
+#[instrument(name = "cmd_new", skip_all, fields(template = %args.template, path = %args.path))]
+pub fn cmd_new(
+    args: NewArgs,
+    global_json: bool,
+    custom_templates: Option<&HashMap<String, TemplateConfig>>,
+) -> anyhow::Result<()> {
+    debug!(template = %args.template, path = %args.path, "executing new command");
+
+    let scaffold = Scaffold::load(&args.template, custom_templates)
+        .with_context(|| format!("failed to load template {}", args.template))?;
+
+    let mut path = args.path.clone();
+    let number = if scaffold.is_numbered() {
+        let file_name = path.file_name().unwrap_or_default();
+        if scaffold::has_number(file_name) {
+            let digits: String = file_name
+                .trim_start_matches(|c: char| !c.is_ascii_digit())
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            Some(digits)
+        } else {
+            let dir = path.parent().filter(|p| !p.as_str().is_empty());
+            let dir = dir.unwrap_or_else(|| Utf8Path::new("."));
+            let (next, width) = scaffold::next_number(dir)
+                .with_context(|| format!("failed to scan {dir} for existing numbers"))?;
+            let number = format!("{next:0width$}");
+            path = scaffold::numbered_path(&path, &number);
+            Some(number)
+        }
+    } else {
+        None
+    };
+
+    if path.exists() && !args.force {
+        bail!("{path} already exists (use --force to overwrite)");
+    }
+
+    let values = ScaffoldValues {
+        title: args
+            .title
+            .unwrap_or_else(|| scaffold::title_from_path(&path)),
+        date: today(),
+        number,
+    };
+    let content = scaffold.render(&values);
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_str().is_empty()) {
+        std::fs::create_dir_all(parent.as_std_path())
+            .with_context(|| format!("failed to create {parent}"))?;
+    }
+    std::fs::write(path.as_std_path(), content)
+        .with_context(|| format!("failed to write {path}"))?;
+
+    if global_json {
+        let output = NewOutput {
+            path: &path,
+            template: &args.template,
+            number: values.number.as_deref(),
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else {
+        println!(
+            "{} {} ({} template)",
+            "CREATED:".green(),
+            path,
+            args.template
+        );
+    }
+
+    Ok(())
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) civil date.
+///
+/// Howard Hinnant's `civil_from_days` algorithm.
+const fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_from_days_known_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(20_513), (2026, 3, 1));
+    }
+}
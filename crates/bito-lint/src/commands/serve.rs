@@ -1,33 +1,77 @@
 //! MCP server command implementation
 
-use anyhow::Result;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::extract::{Request, State};
+use axum::http::{StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use bito_lint_core::config::ConfigSources;
 use clap::Args;
 use rmcp::ServiceExt;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::streamable_http_server::{StreamableHttpServerConfig, StreamableHttpService};
+use tokio_util::sync::CancellationToken;
 
 use crate::server::ProjectServer;
 
+/// Environment variable holding the bearer token required by the HTTP transport.
+pub const TOKEN_ENV: &str = "BITO_LINT_MCP_TOKEN";
+
+/// Path the streamable HTTP endpoint is mounted at.
+const MCP_PATH: &str = "/mcp";
+
 /// Arguments for the `serve` subcommand.
 #[derive(Args, Debug)]
-pub struct ServeArgs {}
+pub struct ServeArgs {
+    /// Serve streamable HTTP on ADDR (e.g. 127.0.0.1:8808) instead of stdio
+    #[arg(long, value_name = "ADDR")]
+    pub http: Option<SocketAddr>,
+}
 
-/// Start the MCP server on stdio.
+/// Start the MCP server on stdio, or on HTTP with `--http`.
+///
+/// On stdio the server communicates over stdin/stdout using JSON-RPC, and
+/// all logging goes to stderr to keep the transport clean. On HTTP it
+/// serves any number of concurrent sessions, all sharing one loaded
+/// config, until interrupted.
 ///
-/// This launches a Model Context Protocol server that communicates over
-/// stdin/stdout using JSON-RPC. All logging goes to stderr to keep the
-/// stdio transport clean.
+/// Either way, `config` must pass validation before the server starts, and
+/// the config files in `config_sources` are watched and the config is
+/// reloaded when one changes.
 #[tracing::instrument(skip_all)]
 pub async fn cmd_serve(
-    _args: ServeArgs,
+    args: ServeArgs,
     max_input_bytes: Option<usize>,
     config: bito_lint_core::Config,
-    config_dir: camino::Utf8PathBuf,
+    config_sources: ConfigSources,
 ) -> Result<()> {
-    tracing::info!("starting MCP server on stdio");
-
+    config.validate().context("invalid configuration")?;
+    let config_dir = config_sources
+        .primary_file()
+        .and_then(|p| p.parent())
+        .map_or_else(|| camino::Utf8PathBuf::from("."), |p| p.to_path_buf());
     let server = ProjectServer::new()
         .with_max_input_bytes(max_input_bytes)
         .with_config(config)
-        .with_config_dir(config_dir);
+        .with_config_dir(config_dir)
+        .with_config_sources(config_sources.clone());
+    let watcher = server.watch_config(config_sources);
+    let result = serve(server, args).await;
+    watcher.abort();
+    result
+}
+
+/// Run the server on the transport `args` selects.
+async fn serve(server: ProjectServer, args: ServeArgs) -> Result<()> {
+    if let Some(addr) = args.http {
+        let token = std::env::var(TOKEN_ENV).ok().filter(|t| !t.is_empty());
+        return serve_http(server, addr, token).await;
+    }
+
+    tracing::info!("starting MCP server on stdio");
     let service = server.serve(rmcp::transport::stdio()).await?;
     tracing::info!("MCP server ready, waiting for client");
     service.waiting().await?;
@@ -35,3 +79,305 @@ pub async fn cmd_serve(
 
     Ok(())
 }
+
+/// Serve streamable HTTP on `addr` until Ctrl-C or SIGTERM.
+///
+/// Without a token, only loopback addresses may be bound.
+async fn serve_http(server: ProjectServer, addr: SocketAddr, token: Option<String>) -> Result<()> {
+    if token.is_none() && !addr.ip().is_loopback() {
+        anyhow::bail!("serving MCP over HTTP on non-loopback address {addr} requires {TOKEN_ENV}");
+    }
+
+    let shutdown = CancellationToken::new();
+    let router = http_router(server, token, shutdown.child_token());
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind {addr}"))?;
+    let local = listener.local_addr()?;
+    tracing::info!(addr = %local, "MCP server listening on HTTP");
+    eprintln!("MCP server listening on http://{local}{MCP_PATH}");
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            shutdown.cancel();
+        })
+        .await?;
+    tracing::info!("MCP server shutting down");
+
+    Ok(())
+}
+
+/// Wait for Ctrl-C or, on Unix, SIGTERM.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!(error = %e, "failed to listen for Ctrl-C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Build the HTTP router: the MCP endpoint, behind bearer auth if `token` is set.
+///
+/// Requests from a non-loopback `Origin` are always rejected. Without a
+/// token, so are requests naming a non-loopback `Host`, which blocks DNS
+/// rebinding from web pages.
+fn http_router(
+    server: ProjectServer,
+    token: Option<String>,
+    cancellation_token: CancellationToken,
+) -> axum::Router {
+    let service = StreamableHttpService::new(
+        move || Ok(server.for_session()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig {
+            cancellation_token,
+            ..Default::default()
+        },
+    );
+    let router = axum::Router::new().nest_service(MCP_PATH, service);
+    let check_host = token.is_none();
+    let router = match token {
+        Some(token) => router.layer(middleware::from_fn_with_state(
+            Arc::<str>::from(token),
+            require_bearer,
+        )),
+        None => router,
+    };
+    router.layer(middleware::from_fn_with_state(check_host, require_local))
+}
+
+/// Reject requests with a non-loopback `Origin`, or, if `check_host` is set,
+/// a missing or non-loopback `Host`.
+async fn require_local(State(check_host): State<bool>, request: Request, next: Next) -> Response {
+    let headers = request.headers();
+    let origin_ok = headers.get(header::ORIGIN).is_none_or(|origin| {
+        origin
+            .to_str()
+            .ok()
+            .and_then(|origin| origin.split_once("://"))
+            .is_some_and(|(_, authority)| is_loopback_host(authority))
+    });
+    let host_ok = !check_host
+        || headers
+            .get(header::HOST)
+            .and_then(|host| host.to_str().ok())
+            .is_some_and(is_loopback_host);
+    if origin_ok && host_ok {
+        next.run(request).await
+    } else {
+        StatusCode::FORBIDDEN.into_response()
+    }
+}
+
+/// Whether an `authority` (`host[:port]`) names a loopback host.
+fn is_loopback_host(authority: &str) -> bool {
+    // `[::1]:8808` brackets IPv6 hosts; otherwise the port follows the `:`.
+    let host = authority.strip_prefix('[').map_or_else(
+        || authority.split(':').next().unwrap_or_default(),
+        |rest| rest.split_once(']').map_or(rest, |(host, _)| host),
+    );
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Reject requests whose `Authorization` header does not carry the token.
+async fn require_bearer(State(token): State<Arc<str>>, request: Request, next: Next) -> Response {
+    let authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| constant_time_eq(given.as_bytes(), token.as_bytes()));
+    if authorized {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+        )
+            .into_response()
+    }
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    const INITIALIZE: &str = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2025-06-18","capabilities":{},"clientInfo":{"name":"test","version":"0"}}}"#;
+
+    /// Serve `router` on an ephemeral port and return its address.
+    async fn spawn(router: axum::Router) -> SocketAddr {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        addr
+    }
+
+    /// POST an initialize request and return the response status line.
+    async fn initialize(addr: SocketAddr, auth: Option<&str>) -> String {
+        let auth = auth.map_or_else(String::new, |t| format!("Authorization: Bearer {t}\r\n"));
+        initialize_with(addr, &format!("Host: {addr}\r\n{auth}")).await
+    }
+
+    /// POST an initialize request with `headers` and return the response status line.
+    async fn initialize_with(addr: SocketAddr, headers: &str) -> String {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "POST {MCP_PATH} HTTP/1.1\r\n{headers}\
+             Content-Type: application/json\r\n\
+             Accept: application/json, text/event-stream\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{INITIALIZE}",
+            INITIALIZE.len()
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut buf = vec![0; 64];
+        let n = stream.read(&mut buf).await.unwrap();
+        String::from_utf8_lossy(&buf[..n])
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn http_serves_initialize_without_token() {
+        let router = http_router(ProjectServer::new(), None, CancellationToken::new());
+        let addr = spawn(router).await;
+        assert_eq!(initialize(addr, None).await, "HTTP/1.1 200 OK");
+    }
+
+    #[tokio::test]
+    async fn http_requires_bearer_token_when_set() {
+        let router = http_router(
+            ProjectServer::new(),
+            Some("s3cret".to_string()),
+            CancellationToken::new(),
+        );
+        let addr = spawn(router).await;
+        assert_eq!(initialize(addr, None).await, "HTTP/1.1 401 Unauthorized");
+        assert_eq!(
+            initialize(addr, Some("wrong")).await,
+            "HTTP/1.1 401 Unauthorized"
+        );
+        assert_eq!(initialize(addr, Some("s3cret")).await, "HTTP/1.1 200 OK");
+    }
+
+    #[tokio::test]
+    async fn http_rejects_non_loopback_host_and_origin() {
+        let router = http_router(ProjectServer::new(), None, CancellationToken::new());
+        let addr = spawn(router).await;
+        let forbidden = "HTTP/1.1 403 Forbidden";
+        assert_eq!(
+            initialize_with(addr, "Host: evil.example:8808\r\n").await,
+            forbidden
+        );
+        assert_eq!(
+            initialize_with(
+                addr,
+                &format!("Host: {addr}\r\nOrigin: http://evil.example\r\n")
+            )
+            .await,
+            forbidden
+        );
+        assert_eq!(
+            initialize_with(
+                addr,
+                &format!(
+                    "Host: localhost:{}\r\nOrigin: http://localhost:3000\r\n",
+                    addr.port()
+                )
+            )
+            .await,
+            "HTTP/1.1 200 OK"
+        );
+    }
+
+    #[tokio::test]
+    async fn http_with_token_accepts_any_host_but_not_foreign_origin() {
+        let router = http_router(
+            ProjectServer::new(),
+            Some("s3cret".to_string()),
+            CancellationToken::new(),
+        );
+        let addr = spawn(router).await;
+        let auth = "Authorization: Bearer s3cret\r\n";
+        assert_eq!(
+            initialize_with(addr, &format!("Host: mcp.example\r\n{auth}")).await,
+            "HTTP/1.1 200 OK"
+        );
+        assert_eq!(
+            initialize_with(
+                addr,
+                &format!("Host: mcp.example\r\nOrigin: https://evil.example\r\n{auth}")
+            )
+            .await,
+            "HTTP/1.1 403 Forbidden"
+        );
+    }
+
+    #[tokio::test]
+    async fn http_refuses_non_loopback_bind_without_token() {
+        let err = serve_http(ProjectServer::new(), "0.0.0.0:0".parse().unwrap(), None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains(TOKEN_ENV));
+    }
+
+    #[test]
+    fn loopback_hosts_recognized() {
+        for host in [
+            "localhost",
+            "LOCALHOST:80",
+            "127.0.0.1:8808",
+            "[::1]:8808",
+            "[::1]",
+        ] {
+            assert!(is_loopback_host(host), "{host}");
+        }
+        for host in [
+            "evil.example",
+            "localhost.evil.example",
+            "10.0.0.1:80",
+            "[fe80::1]",
+        ] {
+            assert!(!is_loopback_host(host), "{host}");
+        }
+    }
+
+    #[test]
+    fn constant_time_eq_compares_contents_and_length() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"abcd"));
+    }
+}
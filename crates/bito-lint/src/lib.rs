@@ -16,6 +16,8 @@
 //! and shell completions via `xtask`.
 pub mod commands;
 
+#[cfg(feature = "mcp")]
+pub mod response;
 #[cfg(feature = "mcp")]
 pub mod server;
 
@@ -53,6 +55,7 @@ ENVIRONMENT VARIABLES:
     BITO_LINT_LOG_PATH     Log file path (rotated daily)
     BITO_LINT_LOG_DIR      Log directory
     BITO_LINT_TOKENIZER    Tokenizer backend (claude, openai)
+    BITO_LINT_MCP_TOKEN    Bearer token required by `serve --http`
 ";
 /// Command-line interface definition for bito-lint.
 #[derive(Parser)]
@@ -109,6 +112,12 @@ pub enum Commands {
     /// Check document completeness against a template
     Completeness(commands::completeness::CompletenessArgs),
 
+    /// Create a new document from a completeness template
+    New(commands::new::NewArgs),
+
+    /// Validate a directory of Architecture Decision Records
+    Adr(commands::adr::AdrArgs),
+
     /// Check grammar and passive voice
     Grammar(commands::grammar::GrammarArgs),
 
@@ -122,7 +131,7 @@ pub enum Commands {
     Doctor(commands::doctor::DoctorArgs),
     /// Show package information
     Info(commands::info::InfoArgs),
-    /// Start MCP (Model Context Protocol) server on stdio
+    /// Start MCP (Model Context Protocol) server on stdio or HTTP
     #[cfg(feature = "mcp")]
     Serve(commands::serve::ServeArgs),
 }
@@ -76,15 +76,9 @@ fn main() -> anyhow::Result<()> {
 
     // Execute command
     let result = match command {
-        Commands::Analyze(args) => commands::analyze::cmd_analyze(
-            args,
-            cli.json,
-            config.style_min_score,
-            config.max_grade,
-            config.passive_max_percent,
-            config.dialect,
-            max_input,
-        ),
+        Commands::Analyze(args) => {
+            commands::analyze::cmd_analyze(args, cli.json, &config, max_input)
+        }
         Commands::Tokens(args) => commands::tokens::cmd_tokens(
             args,
             cli.json,
@@ -99,8 +93,11 @@ fn main() -> anyhow::Result<()> {
             args,
             cli.json,
             config.templates.as_ref(),
+            config.fuzzy_builtin_templates,
             max_input,
         ),
+        Commands::New(args) => commands::new::cmd_new(args, cli.json, config.templates.as_ref()),
+        Commands::Adr(args) => commands::adr::cmd_adr(args, cli.json, config.adr.as_ref()),
         Commands::Grammar(args) => {
             commands::grammar::cmd_grammar(args, cli.json, config.passive_max_percent, max_input)
         }
@@ -114,15 +111,13 @@ fn main() -> anyhow::Result<()> {
         Commands::Info(args) => commands::info::cmd_info(args, cli.json, &config, &config_sources),
         #[cfg(feature = "mcp")]
         Commands::Serve(args) => {
-            let config_dir = config_sources
-                .primary_file()
-                .and_then(|p| p.parent())
-                .map(|p| p.to_path_buf())
-                .unwrap_or_else(|| camino::Utf8PathBuf::from("."));
             let rt = tokio::runtime::Runtime::new()
                 .context("failed to create async runtime for MCP server")?;
             rt.block_on(commands::serve::cmd_serve(
-                args, max_input, config, config_dir,
+                args,
+                max_input,
+                config,
+                config_sources,
             ))
         }
     };
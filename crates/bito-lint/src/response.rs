@@ -0,0 +1,612 @@
+//! MCP tool response rendering at a chosen verbosity.
+//!
+//! At `full`, a report renders as pretty-printed JSON, unchanged. At
+//! `findings` and `summary`, it is condensed to its headline numbers (the
+//! report's top-level scalar fields), a pass/fail verdict, the thresholds it
+//! failed, and its findings: list entries such as grammar issues, wordy
+//! phrases, or sticky sentences, each tagged with the check that found it.
+//! Statistics nested in sub-reports are dropped. `summary` keeps only the
+//! first [`SUMMARY_FINDINGS`] findings, taken from each check in turn.
+//!
+//! Reference data (config, rules, check lists) has no findings, so below
+//! `full` it only loses its whitespace.
+//!
+//! Tool results carry the same JSON as `structuredContent`, without
+//! `response_tokens`, so a compact verbosity keeps both small: the complete
+//! report at `full`, the condensed one below it.
+//!
+//! Every response's text carries a `response_tokens` field with the
+//! approximate token cost of the whole result, text and structured content
+//! together, not counting the field itself.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::common::schema_for_output;
+use rmcp::model::{CallToolResult, Content, JsonObject};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use bito_lint_core::tokens::{self, Backend};
+
+/// Most findings a `summary` response includes.
+pub const SUMMARY_FINDINGS: usize = 10;
+
+/// Lists that hold statistics rather than findings.
+const STAT_LISTS: &[&str] = &[
+    "most_common",
+    "acronym_list",
+    "paragraphs",
+    "deductions",
+    "failed",
+];
+
+/// Measured value and limit fields that accompany an `over_max` or
+/// `over_budget` verdict.
+const GATE_FIELDS: &[(&str, &str)] = &[
+    ("grade", "max_grade"),
+    ("passive_percentage", "passive_max"),
+    ("count", "budget"),
+];
+
+/// How much of a report a tool response includes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verbosity {
+    /// Verdict, failing thresholds, and the top findings.
+    Summary,
+    /// Verdict, failing thresholds, and every finding.
+    Findings,
+    /// The complete report.
+    #[default]
+    Full,
+}
+
+// Every tool takes a verbosity, so its schema is a bare inline enum: the
+// derived one, with a definition and a description per variant, would cost
+// hundreds of tokens in each tool listing.
+impl schemars::JsonSchema for Verbosity {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "Verbosity".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "enum": ["summary", "findings", "full"],
+        })
+    }
+}
+
+/// A tool result with `report` at `verbosity`, as text and as structured
+/// content.
+///
+/// `check` names findings and failures at the report's top level, such as
+/// a completeness report's missing sections.
+pub fn report_result(
+    report: &impl Serialize,
+    check: &str,
+    verbosity: Verbosity,
+) -> Result<CallToolResult, McpError> {
+    structured(shape_report(report, check, verbosity)?, verbosity)
+}
+
+/// A tool result with `data` at `verbosity`, as text and as structured
+/// content. The text is pretty at `full` and compact otherwise.
+pub fn data_result(
+    data: &impl Serialize,
+    verbosity: Verbosity,
+) -> Result<CallToolResult, McpError> {
+    structured(to_value(data)?, verbosity)
+}
+
+/// The output schema a tool returning `T` declares.
+///
+/// The schema describes `T` completely, with nested types under `$defs`,
+/// but without titles, descriptions, or number formats: output schemas ship
+/// in every tool listing, and the report types document their fields. It also describes
+/// the `failing`, `findings`, and `omitted_findings` fields of a condensed
+/// report, and requires only top-level scalar fields, since a condensed
+/// report keeps just those.
+///
+/// # Panics
+///
+/// If `T`'s schema is not an object, which MCP requires of output schemas.
+pub fn output_schema<T: schemars::JsonSchema + 'static>() -> Arc<JsonObject> {
+    let full =
+        schema_for_output::<T>().unwrap_or_else(|e| panic!("{}: {e}", std::any::type_name::<T>()));
+    let mut schema = Value::Object((*full).clone());
+    strip_annotations(&mut schema);
+    let Value::Object(mut schema) = schema else {
+        unreachable!("output schemas are objects");
+    };
+
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        let condensed = serde_json::json!({
+            "failing": { "type": "array", "items": { "type": "object" } },
+            "findings": { "type": "array", "items": { "type": "object" } },
+            "omitted_findings": { "type": "integer", "minimum": 0 },
+        });
+        if let Value::Object(condensed) = condensed {
+            for (name, field) in condensed {
+                properties.entry(name).or_insert(field);
+            }
+        }
+    }
+    let scalar = |name: &&Value| {
+        name.as_str()
+            .and_then(|name| schema.get("properties")?.get(name)?.get("type")?.as_str())
+            .is_some_and(|ty| ["string", "number", "integer", "boolean"].contains(&ty))
+    };
+    let required: Option<Vec<Value>> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|required| required.iter().filter(scalar).cloned().collect());
+    if let Some(required) = required {
+        schema.insert("required".to_string(), required.into());
+    }
+    Arc::new(schema)
+}
+
+/// Remove titles, descriptions, and formats throughout `schema`.
+fn strip_annotations(schema: &mut Value) {
+    match schema {
+        Value::Object(map) => {
+            for annotation in ["title", "description", "format"] {
+                map.remove(annotation);
+            }
+            for (key, child) in map.iter_mut() {
+                // Property and definition names are not keywords.
+                if let ("properties" | "$defs", Value::Object(named)) = (key.as_str(), &mut *child)
+                {
+                    named.values_mut().for_each(strip_annotations);
+                } else {
+                    strip_annotations(child);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_annotations),
+        _ => {}
+    }
+}
+
+/// `report` at `verbosity`: complete at `full`, condensed below it.
+fn shape_report(
+    report: &impl Serialize,
+    check: &str,
+    verbosity: Verbosity,
+) -> Result<Value, McpError> {
+    let value = to_value(report)?;
+    let limit = match verbosity {
+        Verbosity::Full => return Ok(value),
+        Verbosity::Findings => None,
+        Verbosity::Summary => Some(SUMMARY_FINDINGS),
+    };
+    Ok(condense(&value, check, limit))
+}
+
+fn structured(value: Value, verbosity: Verbosity) -> Result<CallToolResult, McpError> {
+    let content = serde_json::to_string(&value)
+        .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+    let text = finish(value.clone(), verbosity == Verbosity::Full, &content)?;
+    let mut result = CallToolResult::success(vec![Content::text(text)]);
+    result.structured_content = Some(value);
+    Ok(result)
+}
+
+fn to_value(data: &impl Serialize) -> Result<Value, McpError> {
+    serde_json::to_value(data)
+        .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))
+}
+
+/// Serialize `value` with its `response_tokens` field, the token cost of
+/// the serialized `value` and of `structured`, the structured content sent
+/// alongside it.
+fn finish(mut value: Value, pretty: bool, structured: &str) -> Result<String, McpError> {
+    let serialize = |value: &Value| {
+        if pretty {
+            serde_json::to_string_pretty(value)
+        } else {
+            serde_json::to_string(value)
+        }
+        .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))
+    };
+
+    let body = serialize(&value)?;
+    let Value::Object(map) = &mut value else {
+        return Ok(body);
+    };
+    let count = |text: &str| {
+        tokens::count_tokens(text, None, Backend::default())
+            .map(|cost| cost.count)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))
+    };
+    let cost = count(&body)? + count(structured)?;
+    map.insert("response_tokens".to_string(), cost.into());
+    serialize(&value)
+}
+
+/// Failures and findings gathered from a report.
+#[derive(Default)]
+struct Condensed {
+    saw_gate: bool,
+    failing: Vec<Value>,
+    /// Findings grouped by the list they came from, in report order.
+    groups: Vec<Vec<Value>>,
+}
+
+/// Condense `report` to headline numbers, verdict, failures, and findings.
+fn condense(report: &Value, check: &str, limit: Option<usize>) -> Value {
+    let mut condensed = Condensed::default();
+    walk(report, &[], None, false, check, &mut condensed);
+
+    let mut out = Map::new();
+    if let Value::Object(map) = report {
+        for (key, value) in map {
+            if !value.is_object() && !value.is_array() && !value.is_null() {
+                out.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    if condensed.saw_gate || !condensed.failing.is_empty() {
+        out.entry("pass")
+            .or_insert_with(|| condensed.failing.is_empty().into());
+    }
+    if !condensed.failing.is_empty() {
+        out.insert("failing".to_string(), condensed.failing.into());
+    }
+
+    let total: usize = condensed.groups.iter().map(Vec::len).sum();
+    let findings = take_round_robin(condensed.groups, limit.unwrap_or(total));
+    if findings.len() < total {
+        out.insert(
+            "omitted_findings".to_string(),
+            (total - findings.len()).into(),
+        );
+    }
+    if !findings.is_empty() {
+        out.insert("findings".to_string(), findings.into());
+    }
+    Value::Object(out)
+}
+
+/// Collect failures and findings beneath `value`, found at `path`.
+fn walk(
+    value: &Value,
+    path: &[&str],
+    file: Option<&str>,
+    in_list: bool,
+    check: &str,
+    out: &mut Condensed,
+) {
+    match value {
+        Value::Object(map) => {
+            // Entries of a list that carry a file, such as `lint_paths`
+            // results, scope what is beneath them to that file.
+            let file = match map.get("file").and_then(Value::as_str) {
+                Some(f) if in_list => Some(f),
+                _ => file,
+            };
+            if in_list && let Some(error) = map.get("error") {
+                out.failing.push(tagged(
+                    Map::from_iter([("error".to_string(), error.clone())]),
+                    path,
+                    file,
+                    check,
+                ));
+                return;
+            }
+
+            let failing_before = out.failing.len();
+            for (key, child) in map {
+                let mut child_path = path.to_vec();
+                child_path.push(key);
+                match child {
+                    Value::Object(_) => walk(child, &child_path, file, false, check, out),
+                    Value::Array(items) => list(key, items, path, file, check, out),
+                    _ => {}
+                }
+            }
+            // An aggregate verdict whose parts already failed adds nothing.
+            if out.failing.len() == failing_before {
+                gate(map, path, file, check, out);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk(item, path, file, true, check, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Record the findings in the list `key` of the object at `path`.
+fn list(
+    key: &str,
+    items: &[Value],
+    path: &[&str],
+    file: Option<&str>,
+    check: &str,
+    out: &mut Condensed,
+) {
+    if STAT_LISTS.contains(&key) {
+        return;
+    }
+    if items
+        .iter()
+        .any(|item| item.get("file").is_some_and(Value::is_string))
+    {
+        for item in items {
+            walk(item, path, file, true, check, out);
+        }
+        return;
+    }
+
+    let findings: Vec<Value> = items
+        .iter()
+        .filter(|item| item.get("status").is_none_or(|s| s != "present"))
+        .filter_map(|item| match item {
+            Value::Object(map) => Some(tagged(map.clone(), path, file, check)),
+            Value::String(_) => Some(tagged(
+                Map::from_iter([("finding".to_string(), item.clone())]),
+                path,
+                file,
+                check,
+            )),
+            _ => None,
+        })
+        .collect();
+    if !findings.is_empty() {
+        out.groups.push(findings);
+    }
+}
+
+/// Record the object at `path` as failing if its verdict says so.
+fn gate(
+    map: &Map<String, Value>,
+    path: &[&str],
+    file: Option<&str>,
+    check: &str,
+    out: &mut Condensed,
+) {
+    let over = ["over_max", "over_budget", "pass"]
+        .iter()
+        .find_map(|key| map.get(*key).and_then(Value::as_bool).map(|v| (*key, v)));
+    let Some((key, verdict)) = over else {
+        return;
+    };
+    out.saw_gate = true;
+    let failed = if key == "pass" { !verdict } else { verdict };
+    if !failed {
+        return;
+    }
+
+    let mut failure = Map::new();
+    if let Some((value, limit)) = GATE_FIELDS
+        .iter()
+        .find(|(value, limit)| map.contains_key(*value) && map.contains_key(*limit))
+    {
+        failure.insert("value".to_string(), map[*value].clone());
+        failure.insert("limit".to_string(), map[*limit].clone());
+    }
+    out.failing.push(tagged(failure, path, file, check));
+}
+
+/// Tag `entry` with the check at `path` and the file, unless already set.
+fn tagged(mut entry: Map<String, Value>, path: &[&str], file: Option<&str>, check: &str) -> Value {
+    let name = if path.is_empty() {
+        check.to_string()
+    } else {
+        path.join(".")
+    };
+    let mut out = Map::new();
+    if !entry.contains_key("check") {
+        out.insert("check".to_string(), name.into());
+    }
+    if let Some(file) = file
+        && !entry.contains_key("file")
+    {
+        out.insert("file".to_string(), file.into());
+    }
+    out.append(&mut entry);
+    Value::Object(out)
+}
+
+/// Take up to `limit` items, one from each group in turn.
+fn take_round_robin(groups: Vec<Vec<Value>>, limit: usize) -> Vec<Value> {
+    let mut iters: Vec<_> = groups.into_iter().map(Vec::into_iter).collect();
+    let mut out = Vec::new();
+    while out.len() < limit {
+        let before = out.len();
+        for iter in &mut iters {
+            if out.len() == limit {
+                break;
+            }
+            out.extend(iter.next());
+        }
+        if out.len() == before {
+            break;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn text(result: &CallToolResult) -> &str {
+        result.content[0].as_text().unwrap().text.as_str()
+    }
+
+    fn render(report: Value, verbosity: Verbosity) -> Value {
+        serde_json::from_str(text(&report_result(&report, "lint", verbosity).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn full_keeps_everything_and_reports_cost() {
+        let report = json!({ "count": 12, "budget": 100, "over_budget": false });
+        let out = render(report, Verbosity::Full);
+        assert_eq!(out["count"], 12);
+        assert!(out["response_tokens"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn cost_covers_text_and_structured_content() {
+        let report = json!({ "file": "a.md", "pass": true, "grade": 7.5 });
+        let result = report_result(&report, "lint", Verbosity::Full).unwrap();
+        let mut out: Value = serde_json::from_str(text(&result)).unwrap();
+        let cost = out
+            .as_object_mut()
+            .unwrap()
+            .remove("response_tokens")
+            .unwrap();
+
+        let count = |text: &str| {
+            tokens::count_tokens(text, None, Backend::default())
+                .unwrap()
+                .count
+        };
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured, out);
+        let expected = count(&serde_json::to_string_pretty(&out).unwrap())
+            + count(&serde_json::to_string(&structured).unwrap());
+        assert_eq!(cost, expected);
+    }
+
+    #[test]
+    fn summary_keeps_failures_and_top_findings() {
+        let report = json!({
+            "file": "a.md",
+            "pass": false,
+            "failed": ["readability", "analyze"],
+            "readability": { "grade": 12.5, "sentences": 4, "max_grade": 8.0, "over_max": true },
+            "analyze": {
+                "wordiness": {
+                    "total_wordy": 12,
+                    "phrases": (0..12).map(|i| json!({ "phrase": format!("p{i}") })).collect::<Vec<_>>(),
+                },
+                "consistency": { "total_issues": 1, "issues": ["Mixed spelling"] },
+                "diction": { "most_common": [{ "word": "thing", "count": 3 }] },
+            },
+        });
+
+        let out = render(report.clone(), Verbosity::Summary);
+        assert_eq!(out["pass"], false);
+        assert_eq!(
+            out["failing"],
+            json!([{ "check": "readability", "value": 12.5, "limit": 8.0 }])
+        );
+        let findings = out["findings"].as_array().unwrap();
+        assert_eq!(findings.len(), SUMMARY_FINDINGS);
+        assert_eq!(
+            findings[0],
+            json!({ "check": "analyze.consistency", "finding": "Mixed spelling" })
+        );
+        assert_eq!(findings[1]["check"], "analyze.wordiness");
+        assert_eq!(out["omitted_findings"], 3);
+        assert!(out.get("readability").is_none());
+
+        let out = render(report, Verbosity::Findings);
+        assert_eq!(out["findings"].as_array().unwrap().len(), 13);
+        assert!(out.get("omitted_findings").is_none());
+    }
+
+    #[test]
+    fn summary_leaves_style_deductions_out_of_findings() {
+        let report = json!({
+            "file": "a.md",
+            "pass": true,
+            "analyze": {
+                "style": {
+                    "adverb_count": 3,
+                    "style_score": 96,
+                    "deductions": [
+                        { "component": "adverbs", "measured": 3.0, "points": 1.5 },
+                        { "component": "hedging", "measured": 2.0, "points": 2.0 },
+                    ],
+                    "nominalizations": [{ "phrase": "made a decision", "noun": "decision" }],
+                },
+            },
+        });
+
+        let out = render(report, Verbosity::Summary);
+        assert_eq!(
+            out["findings"],
+            json!([{ "check": "analyze.style", "phrase": "made a decision", "noun": "decision" }])
+        );
+        assert!(out.get("omitted_findings").is_none());
+    }
+
+    #[test]
+    fn summary_scopes_listed_files() {
+        let report = json!({
+            "checked": 3,
+            "files": [
+                { "file": "ok.md", "pass": true },
+                { "file": "bad.md", "failed": ["completeness"], "completeness": {
+                    "template": "adr", "pass": false,
+                    "sections": [
+                        { "name": "Context", "status": "present" },
+                        { "name": "Decision", "status": "missing" },
+                    ],
+                }},
+                { "file": "gone.md", "error": "not found" },
+            ],
+        });
+
+        let out = render(report, Verbosity::Summary);
+        assert_eq!(out["checked"], 3);
+        assert_eq!(out["pass"], false);
+        assert_eq!(
+            out["failing"],
+            json!([
+                { "check": "completeness", "file": "bad.md" },
+                { "check": "lint", "file": "gone.md", "error": "not found" },
+            ])
+        );
+        assert_eq!(
+            out["findings"],
+            json!([{ "check": "completeness", "file": "bad.md", "name": "Decision", "status": "missing" }])
+        );
+    }
+
+    #[test]
+    fn data_is_compact_below_full() {
+        let data = json!({ "name": "voice", "content": "Be brief." });
+        let compact = data_result(&data, Verbosity::Summary).unwrap();
+        assert!(!text(&compact).contains('\n'));
+        assert!(text(&data_result(&data, Verbosity::Full).unwrap()).contains('\n'));
+    }
+
+    #[test]
+    fn output_schema_describes_nested_reports() {
+        let schema = output_schema::<bito_lint_core::lint::LintReport>();
+        assert_eq!(schema["properties"]["pass"], json!({ "type": "boolean" }));
+        assert!(schema["$defs"]["FullAnalysisReport"]["properties"].is_object());
+        assert_eq!(schema["required"], json!(["file", "pass"]));
+        assert!(
+            !serde_json::to_string(&*schema)
+                .unwrap()
+                .contains("\"description\"")
+        );
+
+        // A condensed report still matches.
+        let report = json!({
+            "file": "a.md",
+            "pass": false,
+            "failed": ["readability"],
+            "readability": { "grade": 12.5, "max_grade": 8.0, "over_max": true },
+        });
+        let condensed = condense(&report, "lint", Some(SUMMARY_FINDINGS));
+        let validator = jsonschema::validator_for(&Value::Object((*schema).clone())).unwrap();
+        assert!(validator.is_valid(&condensed), "{condensed}");
+    }
+}
@@ -1,7 +1,8 @@
 //! MCP (Model Context Protocol) server implementation.
 //!
 //! This module exposes project functionality over the MCP protocol, making it
-//! available to AI assistants (Claude Code, Cursor, etc.) via stdio transport.
+//! available to AI assistants (Claude Code, Cursor, etc.) via stdio or streamable
+//! HTTP transport (see `commands::serve`).
 //!
 //! # Architecture
 //!
@@ -11,19 +12,42 @@
 //!
 //! # Adding Tools
 //!
-//! 1. Define a parameter struct with `Deserialize` + `JsonSchema`
+//! 1. Define a parameter struct with `Deserialize` + `JsonSchema`, including
+//!    a `verbosity` field
 //! 2. Add a `#[tool(description = "...")]` method to the `#[tool_router]` impl
 //! 3. Call core library functions, convert errors to `McpError`
-//! 4. Return `CallToolResult::success(vec![Content::text(...)])`
+//! 4. Return the result with [`response::report_result`] (or `data_result`
+//!    for reference data)
+//! 5. Declare the result's type with `output_schema = response::output_schema::<T>()`
+//!    on the `#[tool]`; output schemas have their own share of the listing's
+//!    token budget
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::{Duration, SystemTime};
 
 use rmcp::handler::server::wrapper::Parameters;
-use rmcp::model::{CallToolResult, Content, Implementation, ServerCapabilities, ServerInfo};
+use rmcp::model::{
+    AnnotateAble, CallToolResult, Content, GetPromptRequestParams, GetPromptResult, Implementation,
+    JsonObject, ListPromptsResult, ListResourcesResult, LoggingLevel,
+    LoggingMessageNotificationParam, PaginatedRequestParams, Prompt, PromptArgument, PromptMessage,
+    PromptMessageRole, RawResource, ReadResourceRequestParams, ReadResourceResult, Resource,
+    ResourceContents, ServerCapabilities, ServerInfo, SetLevelRequestParams,
+};
 use rmcp::schemars;
-use rmcp::{ErrorData as McpError, ServerHandler, tool, tool_handler, tool_router};
-
-use bito_lint_core::config::Dialect;
+use rmcp::service::{NotificationContext, RequestContext};
+use rmcp::{
+    ErrorData as McpError, Peer, RoleServer, ServerHandler, tool, tool_handler, tool_router,
+};
+
+use bito_lint_core::analysis::AnalysisOptions;
+use bito_lint_core::config::{ConfigSources, Dialect};
+use bito_lint_core::lint::LintReport;
+use bito_lint_core::rules::RuleExplanation;
 use bito_lint_core::tokens::Backend;
-use bito_lint_core::{self as core, analysis, completeness, grammar, readability, tokens};
+use bito_lint_core::{self as core, analysis, completeness, edits, grammar, readability, tokens};
+
+use crate::response::{self, Verbosity};
 
 /// Parameters for the `get_info` tool.
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -31,6 +55,9 @@ pub struct GetInfoParams {
     /// Output format: "text" or "json"
     #[serde(default = "default_format")]
     pub format: String,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 fn default_format() -> String {
@@ -46,6 +73,9 @@ pub struct CountTokensParams {
     pub budget: Option<usize>,
     /// Tokenizer backend: "claude" (default) or "openai".
     pub tokenizer: Option<Backend>,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parameters for the `check_readability` tool.
@@ -58,6 +88,9 @@ pub struct CheckReadabilityParams {
     /// Whether to strip markdown formatting before analysis.
     #[serde(default)]
     pub strip_markdown: bool,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parameters for the `check_completeness` tool.
@@ -65,8 +98,12 @@ pub struct CheckReadabilityParams {
 pub struct CheckCompletenessParams {
     /// The markdown document text.
     pub text: String,
-    /// Template to validate against: "adr", "handoff", or "design-doc".
+    /// Template to validate against: "adr", "handoff", "design-doc", or a
+    /// template defined in the project config.
     pub template: String,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parameters for the `check_grammar` tool.
@@ -79,6 +116,9 @@ pub struct CheckGrammarParams {
     pub strip_markdown: bool,
     /// Maximum acceptable passive voice percentage (0-100).
     pub passive_max: Option<f64>,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parameters for the `analyze_writing` tool.
@@ -97,6 +137,11 @@ pub struct AnalyzeWritingParams {
     pub passive_max: Option<f64>,
     /// English dialect for spelling enforcement (en-us, en-gb, en-ca, en-au).
     pub dialect: Option<String>,
+    /// Acronyms that need no definition. Omit to use the project config.
+    pub known_acronyms: Option<Vec<String>>,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parse a dialect string into a `Dialect` enum value.
@@ -116,6 +161,21 @@ fn parse_dialect(s: Option<&str>) -> Result<Option<Dialect>, McpError> {
     }
 }
 
+/// Parameters for the `suggest_edits` tool.
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct SuggestEditsParams {
+    /// The text to rewrite.
+    pub text: String,
+    /// Whether to treat the text as markdown and edit only its prose.
+    #[serde(default)]
+    pub strip_markdown: bool,
+    /// Dialect for spelling edits (en-us, en-gb, en-ca, en-au). Omit to use the project config.
+    pub dialect: Option<String>,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
+}
+
 /// Parameters for the `lint_file` tool.
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
 pub struct LintFileParams {
@@ -123,6 +183,49 @@ pub struct LintFileParams {
     pub file_path: String,
     /// The file contents to lint.
     pub text: String,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
+}
+
+/// Parameters for the `lint_paths` tool.
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct LintPathsParams {
+    /// Workspace-relative file paths, directories, or globs (e.g. "docs/**/*.md").
+    pub paths: Vec<String>,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
+}
+
+/// Most files a single `lint_paths` call may lint.
+const MAX_LINT_PATHS: usize = 500;
+
+/// URI prefix for custom entries exposed as resources.
+const CUSTOM_URI_PREFIX: &str = "bito://custom/";
+
+/// Name prefix for template drafting prompts.
+const DRAFT_PROMPT_PREFIX: &str = "draft-";
+
+/// How often the config watcher checks config files for changes.
+const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Parameters for tools that take no input besides verbosity.
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct VerbosityParams {
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
+}
+
+/// Parameters for the `explain_rules` tool.
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ExplainRulesParams {
+    /// File path (relative to project root) to resolve rules for.
+    pub file_path: String,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
 }
 
 /// Parameters for the `get_custom` tool.
@@ -130,18 +233,126 @@ pub struct LintFileParams {
 pub struct GetCustomParams {
     /// Name of the custom content entry to retrieve.
     pub name: String,
+    /// Response detail: summary, findings, or full (default).
+    #[serde(default)]
+    pub verbosity: Verbosity,
+}
+
+/// Output of the `lint_paths` tool.
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct LintPathsReport {
+    /// One result per matched file, in path order.
+    pub files: Vec<FileLintResult>,
+    /// Paths and globs that matched no file.
+    pub unmatched: Vec<String>,
+    /// Number of files linted.
+    pub checked: usize,
+    /// Number of linted files that failed or could not be read.
+    pub failed: usize,
+    /// Whether every linted file passed.
+    pub pass: bool,
+}
+
+/// One file's result in a [`LintPathsReport`].
+#[derive(Debug, serde::Serialize, serde::Deserialize, schemars::JsonSchema)]
+pub struct FileLintResult {
+    /// Workspace-relative file path.
+    pub file: String,
+    /// `false` if no rule matches the file; omitted otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matched: Option<bool>,
+    /// Whether the file passed, if it was linted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pass: Option<bool>,
+    /// Why the file could not be linted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Names of the checks that failed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<String>,
+    /// Reports of the failed checks, keyed by check name.
+    #[serde(flatten)]
+    pub reports: BTreeMap<String, serde_json::Value>,
+}
+
+/// Output of the `list_checks` tool.
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+pub struct CheckList {
+    /// Number of checks.
+    pub total: usize,
+    /// Every analysis check.
+    pub checks: &'static [analysis::CheckInfo],
+}
+
+/// Output of the `explain_rules` tool.
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+pub struct RulesReport {
+    /// The file path rules were resolved for.
+    pub file: String,
+    /// Matching rules, winning sources, and resolved checks.
+    #[serde(flatten)]
+    pub explanation: RuleExplanation,
+}
+
+/// Output of the `get_info` tool.
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+pub struct ProjectInfo {
+    /// Package name.
+    pub name: &'static str,
+    /// Package version.
+    pub version: &'static str,
+    /// Package description.
+    pub description: &'static str,
+}
+
+/// Output of the `get_custom` tool.
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+pub struct CustomContent {
+    /// Name of the entry.
+    pub name: String,
+    /// The entry's content, read from its file if it names one.
+    pub content: String,
+}
+
+/// Output of the `get_effective_config` tool.
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+pub struct EffectiveConfig {
+    /// The merged config, as loaded.
+    #[schemars(with = "serde_json::Map<String, serde_json::Value>")]
+    pub config: Arc<bito_lint_core::Config>,
+    /// The config files it was loaded from.
+    #[schemars(with = "serde_json::Map<String, serde_json::Value>")]
+    pub sources: Arc<ConfigSources>,
+    /// Directory relative config paths resolve against.
+    #[schemars(with = "String")]
+    pub config_dir: camino::Utf8PathBuf,
 }
 
+/// A client's log level, shared between its session and the peer list.
+type SharedLevel = Arc<Mutex<LoggingLevel>>;
+
+/// A connected client and the least severe log level it wants.
+type Client = (Peer<RoleServer>, SharedLevel);
+
 /// MCP server exposing project functionality to AI assistants.
 ///
 /// Each `#[tool]` method in the `#[tool_router]` impl block is automatically
 /// registered and callable via the MCP protocol.
+///
+/// Clones share the loaded config and the list of connected clients, so a
+/// reload through any clone reaches every session. Use
+/// [`for_session`](Self::for_session) for each new client.
 #[derive(Clone)]
 pub struct ProjectServer {
     tool_router: rmcp::handler::server::router::tool::ToolRouter<Self>,
     max_input_bytes: Option<usize>,
-    config: bito_lint_core::Config,
+    config: Arc<RwLock<Arc<bito_lint_core::Config>>>,
     config_dir: camino::Utf8PathBuf,
+    config_sources: Arc<ConfigSources>,
+    /// Connected clients.
+    peers: Arc<Mutex<Vec<Client>>>,
+    /// This session's client log level, set by `logging/setLevel`.
+    log_level: SharedLevel,
 }
 
 impl Default for ProjectServer {
@@ -157,8 +368,21 @@ impl ProjectServer {
         Self {
             tool_router: Self::tool_router(),
             max_input_bytes: Some(core::DEFAULT_MAX_INPUT_BYTES),
-            config: bito_lint_core::Config::default(),
+            config: Arc::default(),
             config_dir: camino::Utf8PathBuf::from("."),
+            config_sources: Arc::default(),
+            peers: Arc::default(),
+            log_level: Arc::new(Mutex::new(LoggingLevel::Debug)),
+        }
+    }
+
+    /// A clone for a new client session, sharing everything but the log
+    /// level the client sets.
+    #[must_use]
+    pub fn for_session(&self) -> Self {
+        Self {
+            log_level: Arc::new(Mutex::new(LoggingLevel::Debug)),
+            ..self.clone()
         }
     }
 
@@ -170,7 +394,7 @@ impl ProjectServer {
 
     /// Create a new MCP server with project configuration.
     pub fn with_config(mut self, config: bito_lint_core::Config) -> Self {
-        self.config = config;
+        self.config = Arc::new(RwLock::new(Arc::new(config)));
         self
     }
 
@@ -180,6 +404,17 @@ impl ProjectServer {
         self
     }
 
+    /// Record which config files the config was loaded from.
+    pub fn with_config_sources(mut self, sources: ConfigSources) -> Self {
+        self.config_sources = Arc::new(sources);
+        self
+    }
+
+    /// The current config. Tools take one snapshot per call.
+    fn config(&self) -> Arc<bito_lint_core::Config> {
+        Arc::clone(&self.config.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
     /// Validate input text size against the configured limit.
     fn validate_input(&self, text: &str) -> Result<(), McpError> {
         core::validate_input_size(text, self.max_input_bytes)
@@ -187,7 +422,10 @@ impl ProjectServer {
     }
 
     /// Get project information.
-    #[tool(description = "Get project name, version, and description")]
+    #[tool(
+        description = "Get project name, version, and description",
+        output_schema = response::output_schema::<ProjectInfo>(),
+    )]
     #[tracing::instrument(skip(self), fields(otel.kind = "server"))]
     fn get_info(
         &self,
@@ -195,31 +433,28 @@ impl ProjectServer {
     ) -> Result<CallToolResult, McpError> {
         tracing::debug!(tool = "get_info", format = %params.format, "executing MCP tool");
 
-        let info = serde_json::json!({
-            "name": env!("CARGO_PKG_NAME"),
-            "version": env!("CARGO_PKG_VERSION"),
-            "description": env!("CARGO_PKG_DESCRIPTION"),
-        });
+        let info = ProjectInfo {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            description: env!("CARGO_PKG_DESCRIPTION"),
+        };
 
-        let text = if params.format == "json" {
-            serde_json::to_string_pretty(&info)
-                .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?
-        } else {
-            format!(
+        let mut result = response::data_result(&info, params.verbosity)?;
+        if params.format != "json" {
+            result.content = vec![Content::text(format!(
                 "{} v{}\n{}",
-                env!("CARGO_PKG_NAME"),
-                env!("CARGO_PKG_VERSION"),
-                env!("CARGO_PKG_DESCRIPTION"),
-            )
-        };
+                info.name, info.version, info.description
+            ))];
+        }
 
         tracing::info!(tool = "get_info", "MCP tool completed");
-        Ok(CallToolResult::success(vec![Content::text(text)]))
+        Ok(result)
     }
 
     /// Count tokens in text using the specified backend (default: claude).
     #[tool(
-        description = "Count tokens in text. Returns token count and optional budget check. Supports 'claude' (default, conservative) and 'openai' (exact cl100k_base) backends."
+        description = "Count tokens in text. Returns token count and optional budget check. Supports 'claude' (default, conservative) and 'openai' (exact cl100k_base) backends.",
+        output_schema = response::output_schema::<tokens::TokenReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server"))]
     fn count_tokens(
@@ -233,20 +468,20 @@ impl ProjectServer {
         let report = tokens::count_tokens(&params.text, params.budget, backend)
             .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "tokens", params.verbosity)?;
 
         tracing::info!(
             tool = "count_tokens",
             count = report.count,
             "MCP tool completed"
         );
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
     }
 
     /// Score readability using Flesch-Kincaid Grade Level.
     #[tool(
-        description = "Check readability of text. Returns Flesch-Kincaid grade level and statistics."
+        description = "Check readability of text. Returns Flesch-Kincaid grade level and statistics.",
+        output_schema = response::output_schema::<readability::ReadabilityReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server"))]
     fn check_readability(
@@ -264,20 +499,20 @@ impl ProjectServer {
             readability::check_readability(&params.text, params.strip_markdown, params.max_grade)
                 .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "readability", params.verbosity)?;
 
         tracing::info!(
             tool = "check_readability",
             grade = report.grade,
             "MCP tool completed"
         );
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
     }
 
     /// Check document completeness against a template.
     #[tool(
-        description = "Validate that a markdown document has all required sections for a template (adr, handoff, design-doc)."
+        description = "Validate that a markdown document has all required sections for a template (adr, handoff, design-doc, or one from config).",
+        output_schema = response::output_schema::<completeness::CompletenessReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server", template = %params.template))]
     fn check_completeness(
@@ -287,23 +522,30 @@ impl ProjectServer {
         tracing::debug!(tool = "check_completeness", template = %params.template, "executing MCP tool");
         self.validate_input(&params.text)?;
 
-        let report = completeness::check_completeness(&params.text, &params.template, None)
-            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        let config = self.config();
+        let custom_templates = config.templates.as_ref();
+        let report = completeness::check_completeness(
+            &params.text,
+            &params.template,
+            custom_templates,
+            config.fuzzy_builtin_templates,
+        )
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "completeness", params.verbosity)?;
 
         tracing::info!(
             tool = "check_completeness",
             pass = report.pass,
             "MCP tool completed"
         );
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
     }
 
     /// Run comprehensive writing analysis.
     #[tool(
-        description = "Analyze writing quality across 18 dimensions: readability, grammar, style, pacing, transitions, overused words, cliches, jargon, and more."
+        description = "Analyze writing quality across 21 dimensions: readability, grammar, style, pacing, transitions, overused words, hedging, wordiness, cliches, jargon, and more.",
+        output_schema = response::output_schema::<analysis::FullAnalysisReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server"))]
     fn analyze_writing(
@@ -320,26 +562,31 @@ impl ProjectServer {
 
         let dialect = parse_dialect(params.dialect.as_deref())?;
         let checks_ref = params.checks.as_deref();
-        let report = analysis::run_full_analysis(
-            &params.text,
-            params.strip_markdown,
-            checks_ref,
-            params.max_grade,
-            params.passive_max,
+        let config = self.config();
+        let options = AnalysisOptions {
+            max_grade: params.max_grade,
+            passive_max: params.passive_max,
             dialect,
-        )
-        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+            known_acronyms: params
+                .known_acronyms
+                .or_else(|| config.known_acronyms.clone())
+                .unwrap_or_default(),
+            style_score: config.style_score.clone(),
+        };
+        let report =
+            analysis::run_full_analysis(&params.text, params.strip_markdown, checks_ref, &options)
+                .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "analysis", params.verbosity)?;
 
         tracing::info!(tool = "analyze_writing", "MCP tool completed");
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
     }
 
     /// Check grammar and passive voice in text.
     #[tool(
-        description = "Check grammar issues and passive voice usage. Returns grammar issues with severity and passive voice statistics."
+        description = "Check grammar issues and passive voice usage. Returns grammar issues with severity and passive voice statistics.",
+        output_schema = response::output_schema::<grammar::GrammarReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server"))]
     fn check_grammar(
@@ -357,8 +604,7 @@ impl ProjectServer {
             grammar::check_grammar_full(&params.text, params.strip_markdown, params.passive_max)
                 .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "grammar", params.verbosity)?;
 
         tracing::info!(
             tool = "check_grammar",
@@ -366,12 +612,13 @@ impl ProjectServer {
             issue_count = report.issues.len(),
             "MCP tool completed"
         );
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
     }
 
     /// Lint a file according to project rules.
     #[tool(
-        description = "Lint a file against configured project rules. Matches file path to rules, runs applicable checks, returns results with pass/fail."
+        description = "Lint a file against configured project rules. Matches file path to rules, runs applicable checks, returns results with pass/fail.",
+        output_schema = response::output_schema::<LintReport>(),
     )]
     #[tracing::instrument(skip(self, params), fields(otel.kind = "server", file = %params.file_path))]
     fn lint_file(
@@ -381,39 +628,219 @@ impl ProjectServer {
         tracing::debug!(tool = "lint_file", file = %params.file_path, "executing MCP tool");
         self.validate_input(&params.text)?;
 
-        let rules = self.config.rules.as_deref().unwrap_or_default();
+        let config = self.config();
+        let rules = config.rules.as_deref().unwrap_or_default();
         let rule_set = bito_lint_core::rules::RuleSet::compile(rules);
         let resolved = rule_set.resolve(&params.file_path);
 
-        if resolved.is_empty() {
-            return Ok(CallToolResult::success(vec![Content::text(
-                serde_json::json!({
-                    "file": params.file_path,
-                    "matched": false,
-                    "message": "no rules match this file path"
-                })
-                .to_string(),
-            )]));
-        }
-
-        let report = bito_lint_core::lint::run_lint(
+        // Links resolve inside the project root, never against the server's cwd.
+        let workspace = bito_lint_core::workspace::Workspace::open(&self.config_dir)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        // With no checks resolved, the report is an empty pass.
+        let report = bito_lint_core::lint::run_lint_within(
+            workspace.root(),
             &params.file_path,
             &params.text,
             &resolved,
-            &self.config,
+            &config,
         )
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
-
-        let json = serde_json::to_string_pretty(&report)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let result = response::report_result(&report, "lint", params.verbosity)?;
 
         tracing::info!(tool = "lint_file", pass = report.pass, "MCP tool completed");
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+        Ok(result)
+    }
+
+    /// Lint workspace files by path, reading them server-side.
+    #[tool(
+        description = "Lint files in the project workspace by relative path, directory, or glob. Reads files under the project root, runs the checks each file's rules select, and returns compact per-file results: failing checks with their reports, or pass.",
+        output_schema = response::output_schema::<LintPathsReport>(),
+    )]
+    #[tracing::instrument(skip(self, params), fields(otel.kind = "server", patterns = params.paths.len()))]
+    fn lint_paths(
+        &self,
+        #[allow(unused_variables)] Parameters(params): Parameters<LintPathsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::debug!(tool = "lint_paths", paths = ?params.paths, "executing MCP tool");
+
+        let workspace = bito_lint_core::workspace::Workspace::open(&self.config_dir)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+        let expansion = workspace
+            .expand(&params.paths)
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
+        if expansion.files.len() > MAX_LINT_PATHS {
+            return Err(McpError::invalid_params(
+                format!(
+                    "{} files matched; narrow the paths to at most {MAX_LINT_PATHS}",
+                    expansion.files.len()
+                ),
+                None,
+            ));
+        }
+
+        let config = self.config();
+        let rules = config.rules.as_deref().unwrap_or_default();
+        let rule_set = bito_lint_core::rules::RuleSet::compile(rules);
+        let mut files = Vec::with_capacity(expansion.files.len());
+        let (mut checked, mut failed) = (0, 0);
+
+        for file in &expansion.files {
+            let resolved = rule_set.resolve(file);
+            if resolved.is_empty() {
+                files.push(FileLintResult::unmatched(file));
+                continue;
+            }
+            let report = workspace.read(file).and_then(|content| {
+                core::validate_input_size(&content, self.max_input_bytes)?;
+                bito_lint_core::lint::run_lint_within(
+                    workspace.root(),
+                    file,
+                    &content,
+                    &resolved,
+                    &config,
+                )
+            });
+            checked += 1;
+            match report {
+                Ok(report) => {
+                    if !report.pass {
+                        failed += 1;
+                    }
+                    files.push(FileLintResult::from_report(file, &report)?);
+                }
+                Err(e) => {
+                    failed += 1;
+                    files.push(FileLintResult::error(file, &e));
+                }
+            }
+        }
+
+        let report = LintPathsReport {
+            files,
+            unmatched: expansion.unmatched,
+            checked,
+            failed,
+            pass: failed == 0,
+        };
+        let result = response::report_result(&report, "lint", params.verbosity)?;
+
+        tracing::info!(tool = "lint_paths", checked, failed, "MCP tool completed");
+        Ok(result)
+    }
+
+    /// Suggest machine-applicable rewrites for text.
+    #[tool(
+        description = "Suggest located edits for text: hidden verbs, wordy phrases, dialect spellings, and double spaces. Each edit has a byte span, original, replacement, rationale, and check. Edits never overlap; apply them from last to first.",
+        output_schema = response::output_schema::<edits::EditsReport>(),
+    )]
+    #[tracing::instrument(skip(self, params), fields(otel.kind = "server"))]
+    fn suggest_edits(
+        &self,
+        #[allow(unused_variables)] Parameters(params): Parameters<SuggestEditsParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::debug!(
+            tool = "suggest_edits",
+            strip_md = params.strip_markdown,
+            dialect = ?params.dialect,
+            "executing MCP tool"
+        );
+        self.validate_input(&params.text)?;
+
+        let dialect = parse_dialect(params.dialect.as_deref())?.or(self.config().dialect);
+        let report = edits::suggest_edits(&params.text, params.strip_markdown, dialect);
+
+        let result = response::report_result(&report, "edits", params.verbosity)?;
+
+        tracing::info!(
+            tool = "suggest_edits",
+            total_edits = report.total_edits,
+            "MCP tool completed"
+        );
+        Ok(result)
+    }
+
+    /// List the analysis checks.
+    #[tool(
+        description = "List the writing analysis checks with descriptions and default thresholds. Names work in analyze_writing `checks`, rule configs, and suppression directives.",
+        output_schema = response::output_schema::<CheckList>(),
+    )]
+    #[tracing::instrument(skip(self), fields(otel.kind = "server"))]
+    fn list_checks(
+        &self,
+        #[allow(unused_variables)] Parameters(params): Parameters<VerbosityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::debug!(tool = "list_checks", "executing MCP tool");
+
+        let result = response::data_result(
+            &CheckList {
+                total: analysis::CHECK_INFO.len(),
+                checks: analysis::CHECK_INFO,
+            },
+            params.verbosity,
+        )?;
+
+        tracing::info!(tool = "list_checks", "MCP tool completed");
+        Ok(result)
+    }
+
+    /// Explain which rules apply to a path.
+    #[tool(
+        description = "Explain which configured rules match a file path, which rule each check's settings come from (most specific pattern wins), and the resolved checks lint_file would run.",
+        output_schema = response::output_schema::<RulesReport>(),
+    )]
+    #[tracing::instrument(skip(self, params), fields(otel.kind = "server", file = %params.file_path))]
+    fn explain_rules(
+        &self,
+        #[allow(unused_variables)] Parameters(params): Parameters<ExplainRulesParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::debug!(tool = "explain_rules", file = %params.file_path, "executing MCP tool");
+
+        let config = self.config();
+        let rules = config.rules.as_deref().unwrap_or_default();
+        let explanation = bito_lint_core::rules::RuleSet::compile(rules).explain(&params.file_path);
+
+        let matched = explanation.matched.len();
+        let result = response::data_result(
+            &RulesReport {
+                file: params.file_path,
+                explanation,
+            },
+            params.verbosity,
+        )?;
+
+        tracing::info!(tool = "explain_rules", matched, "MCP tool completed");
+        Ok(result)
+    }
+
+    /// Return the merged configuration and where it came from.
+    #[tool(
+        description = "Get the effective project config, merged from all config files, and the files it was loaded from (low to high precedence).",
+        output_schema = response::output_schema::<EffectiveConfig>(),
+    )]
+    #[tracing::instrument(skip(self), fields(otel.kind = "server"))]
+    fn get_effective_config(
+        &self,
+        #[allow(unused_variables)] Parameters(params): Parameters<VerbosityParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tracing::debug!(tool = "get_effective_config", "executing MCP tool");
+
+        let result = response::data_result(
+            &EffectiveConfig {
+                config: self.config(),
+                sources: Arc::clone(&self.config_sources),
+                config_dir: self.config_dir.clone(),
+            },
+            params.verbosity,
+        )?;
+
+        tracing::info!(tool = "get_effective_config", "MCP tool completed");
+        Ok(result)
     }
 
     /// Retrieve a custom content entry by name.
     #[tool(
-        description = "Get a custom content entry (persona, voice guide, style rules) defined in project config."
+        description = "Get a custom content entry (persona, voice guide, style rules) defined in project config.",
+        output_schema = response::output_schema::<CustomContent>(),
     )]
     #[tracing::instrument(skip(self), fields(otel.kind = "server", name = %params.name))]
     fn get_custom(
@@ -422,7 +849,8 @@ impl ProjectServer {
     ) -> Result<CallToolResult, McpError> {
         tracing::debug!(tool = "get_custom", name = %params.name, "executing MCP tool");
 
-        let entries = self.config.custom.as_ref().ok_or_else(|| {
+        let config = self.config();
+        let entries = config.custom.as_ref().ok_or_else(|| {
             McpError::invalid_params("no custom entries defined in config".to_string(), None)
         })?;
 
@@ -442,16 +870,261 @@ impl ProjectServer {
             McpError::internal_error(format!("failed to resolve custom entry: {e}"), None)
         })?;
 
-        let output = serde_json::json!({
-            "name": params.name,
-            "content": content,
+        let result = response::data_result(
+            &CustomContent {
+                name: params.name.clone(),
+                content,
+            },
+            params.verbosity,
+        )?;
+
+        tracing::info!(tool = "get_custom", name = %params.name, "MCP tool completed");
+        Ok(result)
+    }
+}
+
+impl ProjectServer {
+    /// Reload config from `sources`, keeping the current config if the new
+    /// one fails to load or validate.
+    ///
+    /// Every connected client gets a log message describing the outcome and,
+    /// on success, resource and prompt list-changed notifications.
+    /// Returns whether the new config was applied.
+    pub async fn reload_config(&self, sources: &ConfigSources) -> bool {
+        let reloaded = sources.reload().and_then(|config| {
+            config.validate()?;
+            Ok(config)
         });
+        let (applied, level, message) = match reloaded {
+            Ok(config) => {
+                *self.config.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(config);
+                tracing::info!("configuration reloaded");
+                (
+                    true,
+                    LoggingLevel::Info,
+                    "configuration reloaded".to_string(),
+                )
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "configuration reload failed; keeping previous config");
+                (
+                    false,
+                    LoggingLevel::Warning,
+                    format!("configuration reload failed; keeping previous config: {e}"),
+                )
+            }
+        };
 
-        let json = serde_json::to_string_pretty(&output)
-            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?;
+        let files: Vec<&str> = sources.files().into_iter().map(|f| f.as_str()).collect();
+        let peers = self
+            .peers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        for (peer, min_level) in &peers {
+            let wanted = {
+                let min_level = *min_level.lock().unwrap_or_else(PoisonError::into_inner);
+                level as u8 >= min_level as u8
+            };
+            let mut sent = if wanted {
+                let log = LoggingMessageNotificationParam {
+                    level,
+                    logger: Some(env!("CARGO_PKG_NAME").to_string()),
+                    data: serde_json::json!({ "message": message, "files": files }),
+                };
+                peer.notify_logging_message(log).await
+            } else {
+                Ok(())
+            };
+            if applied && sent.is_ok() {
+                sent = peer.notify_resource_list_changed().await;
+            }
+            if applied && sent.is_ok() {
+                sent = peer.notify_prompt_list_changed().await;
+            }
+            if let Err(e) = sent {
+                tracing::debug!(error = %e, "failed to notify client of config reload");
+            }
+        }
+        self.peers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|(peer, _)| !peer.is_transport_closed());
+        applied
+    }
 
-        tracing::info!(tool = "get_custom", name = %params.name, "MCP tool completed");
-        Ok(CallToolResult::success(vec![Content::text(json)]))
+    /// Watch the files in `sources` and reload whenever one changes.
+    ///
+    /// Polls modification times, so editors that replace files on save are
+    /// handled the same as in-place writes. Runs until the task is aborted.
+    pub fn watch_config(&self, sources: ConfigSources) -> tokio::task::JoinHandle<()> {
+        let server = self.clone();
+        tokio::spawn(async move {
+            let mut last = config_mtimes(&sources);
+            let mut interval = tokio::time::interval(CONFIG_POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+                let current = config_mtimes(&sources);
+                if current != last {
+                    last = current;
+                    server.reload_config(&sources).await;
+                }
+            }
+        })
+    }
+
+    /// Custom entries as resources, sorted by name.
+    fn custom_resources(&self) -> Vec<Resource> {
+        let config = self.config();
+        let mut names: Vec<&String> = config.custom.iter().flat_map(|c| c.keys()).collect();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                let mut resource =
+                    RawResource::new(format!("{CUSTOM_URI_PREFIX}{name}"), name.clone());
+                resource.description = Some(format!("Custom content entry '{name}'"));
+                resource.mime_type = Some("text/markdown".to_string());
+                resource.no_annotation()
+            })
+            .collect()
+    }
+
+    /// Read the custom entry behind a `bito://custom/<name>` URI.
+    fn read_custom_resource(&self, uri: &str) -> Result<ReadResourceResult, McpError> {
+        let config = self.config();
+        let entry = uri
+            .strip_prefix(CUSTOM_URI_PREFIX)
+            .and_then(|name| config.custom.as_ref()?.get(name))
+            .ok_or_else(|| {
+                McpError::resource_not_found(format!("unknown resource: {uri}"), None)
+            })?;
+        let text = entry.resolve(&self.config_dir).map_err(|e| {
+            McpError::internal_error(format!("failed to resolve custom entry: {e}"), None)
+        })?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: uri.to_string(),
+                mime_type: Some("text/markdown".to_string()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
+    /// One drafting prompt per completeness template, sorted by name.
+    fn template_prompts(&self) -> Vec<Prompt> {
+        let mut names = completeness::available_templates(self.config().templates.as_ref());
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                Prompt::new(
+                    format!("{DRAFT_PROMPT_PREFIX}{name}"),
+                    Some(format!(
+                        "Draft a document with every section the '{name}' template requires."
+                    )),
+                    Some(vec![PromptArgument {
+                        name: "topic".to_string(),
+                        title: None,
+                        description: Some("What the document is about.".to_string()),
+                        required: Some(false),
+                    }]),
+                )
+            })
+            .collect()
+    }
+
+    /// Build the drafting prompt for a `draft-<template>` name.
+    fn template_prompt(
+        &self,
+        name: &str,
+        arguments: Option<&JsonObject>,
+    ) -> Result<GetPromptResult, McpError> {
+        let template_name = name
+            .strip_prefix(DRAFT_PROMPT_PREFIX)
+            .ok_or_else(|| McpError::invalid_params(format!("unknown prompt: {name}"), None))?;
+        let template = completeness::load_template(
+            template_name,
+            self.config().templates.as_ref(),
+            self.config().fuzzy_builtin_templates,
+        )
+        .map_err(|e| McpError::invalid_params(e.to_string(), None))?;
+
+        let mut text = completeness::draft_instructions(template_name, &template);
+        if let Some(topic) = arguments
+            .and_then(|args| args.get("topic"))
+            .and_then(|topic| topic.as_str())
+        {
+            text.push_str(&format!("\nThe document is about: {topic}\n"));
+        }
+
+        Ok(GetPromptResult {
+            description: Some(format!("Draft a '{template_name}' document")),
+            messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+        })
+    }
+}
+
+/// Modification time and size of each config file; `None` for files that are gone.
+///
+/// Size catches edits that land within the filesystem's timestamp granularity.
+fn config_mtimes(sources: &ConfigSources) -> Vec<Option<(SystemTime, u64)>> {
+    sources
+        .files()
+        .into_iter()
+        .map(|f| {
+            std::fs::metadata(f)
+                .and_then(|m| Ok((m.modified()?, m.len())))
+                .ok()
+        })
+        .collect()
+}
+
+impl FileLintResult {
+    /// A file no rule matches.
+    fn unmatched(file: &str) -> Self {
+        Self {
+            file: file.to_string(),
+            matched: Some(false),
+            pass: None,
+            error: None,
+            failed: Vec::new(),
+            reports: BTreeMap::new(),
+        }
+    }
+
+    /// A file that could not be linted.
+    fn error(file: &str, error: &impl std::fmt::Display) -> Self {
+        Self {
+            matched: None,
+            error: Some(error.to_string()),
+            ..Self::unmatched(file)
+        }
+    }
+
+    /// Reduce a lint report to its pass flag and the reports of failing checks.
+    fn from_report(file: &str, report: &LintReport) -> Result<Self, McpError> {
+        let mut result = Self {
+            matched: None,
+            pass: Some(report.pass),
+            ..Self::unmatched(file)
+        };
+        if report.pass {
+            return Ok(result);
+        }
+        let serde_json::Value::Object(mut full) = serde_json::to_value(report)
+            .map_err(|e| McpError::internal_error(format!("serialization error: {e}"), None))?
+        else {
+            return Ok(result);
+        };
+        result.failed.clone_from(&report.failed);
+        for check in &report.failed {
+            if let Some(check_report) = full.remove(check) {
+                result.reports.insert(check.clone(), check_report);
+            }
+        }
+        Ok(result)
     }
 }
 
@@ -460,18 +1133,81 @@ impl ServerHandler for ProjectServer {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: Default::default(),
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_logging()
+                .enable_resources()
+                .enable_resources_list_changed()
+                .enable_prompts()
+                .enable_prompts_list_changed()
+                .build(),
             server_info: Implementation {
                 name: env!("CARGO_PKG_NAME").to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
                 ..Default::default()
             },
             instructions: Some(format!(
-                "{} MCP server. Use tools to interact with project functionality.",
+                "{} MCP server. Use tools to check writing, read custom entries as resources, \
+                 and draft documents from template prompts.",
                 env!("CARGO_PKG_NAME"),
             )),
         }
     }
+
+    async fn on_initialized(&self, context: NotificationContext<RoleServer>) {
+        tracing::info!("client initialized");
+        self.peers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((context.peer, Arc::clone(&self.log_level)));
+    }
+
+    async fn set_level(
+        &self,
+        request: SetLevelRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<(), McpError> {
+        tracing::debug!(level = ?request.level, "client set log level");
+        *self
+            .log_level
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = request.level;
+        Ok(())
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        Ok(ListResourcesResult::with_all_items(self.custom_resources()))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        tracing::debug!(uri = %request.uri, "reading MCP resource");
+        self.read_custom_resource(&request.uri)
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult::with_all_items(self.template_prompts()))
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParams,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        tracing::debug!(prompt = %request.name, "building MCP prompt");
+        self.template_prompt(&request.name, request.arguments.as_ref())
+    }
 }
 
 #[cfg(test)]
@@ -518,6 +1254,7 @@ mod tests {
         let server = ProjectServer::new();
         let params = Parameters(GetInfoParams {
             format: "text".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server.get_info(params).expect("get_info should succeed");
@@ -528,6 +1265,8 @@ mod tests {
         let text = extract_text(&result).expect("should have text content");
         assert!(text.contains(env!("CARGO_PKG_NAME")));
         assert!(text.contains(env!("CARGO_PKG_VERSION")));
+        let info = structured(&server, "get_info", &result);
+        assert_eq!(info["version"], env!("CARGO_PKG_VERSION"));
     }
 
     #[test]
@@ -535,6 +1274,7 @@ mod tests {
         let server = ProjectServer::new();
         let params = Parameters(GetInfoParams {
             format: "json".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server.get_info(params).expect("get_info should succeed");
@@ -558,6 +1298,7 @@ mod tests {
             text: "Hello, world!".to_string(),
             budget: Some(100),
             tokenizer: None,
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -578,6 +1319,7 @@ mod tests {
             text: "The cat sat on the mat. The dog ran fast.".to_string(),
             max_grade: None,
             strip_markdown: false,
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -598,6 +1340,7 @@ mod tests {
         let params = Parameters(CheckCompletenessParams {
             text: doc.to_string(),
             template: "handoff".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -621,6 +1364,8 @@ mod tests {
             max_grade: None,
             passive_max: None,
             dialect: None,
+            known_acronyms: None,
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -641,6 +1386,7 @@ mod tests {
             text: "The report was written by the team. She codes every day.".to_string(),
             strip_markdown: false,
             passive_max: None,
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -654,6 +1400,42 @@ mod tests {
         assert!(json["passive_count"].as_u64().is_some());
     }
 
+    #[test]
+    fn analyze_writing_summary_is_compact() {
+        let text = "In order to win, we basically made a decision. \
+                    It is very important to note that the plan was reviewed by the team. "
+            .repeat(5);
+        let run = |verbosity| {
+            let params = Parameters(AnalyzeWritingParams {
+                text: text.clone(),
+                strip_markdown: false,
+                checks: None,
+                max_grade: Some(4.0),
+                passive_max: None,
+                dialect: None,
+                known_acronyms: None,
+                verbosity,
+            });
+            let result = ProjectServer::new().analyze_writing(params).unwrap();
+            extract_text(&result).unwrap().to_string()
+        };
+
+        let full = run(Verbosity::Full);
+        let summary = run(Verbosity::Summary);
+        let json: serde_json::Value = serde_json::from_str(&summary).expect("valid JSON");
+        assert_eq!(json["pass"], false);
+        assert_eq!(json["failing"][0]["check"], "readability");
+        assert_eq!(
+            json["findings"].as_array().unwrap().len(),
+            response::SUMMARY_FINDINGS
+        );
+        let full_tokens =
+            serde_json::from_str::<serde_json::Value>(&full).unwrap()["response_tokens"]
+                .as_u64()
+                .unwrap();
+        assert!(json["response_tokens"].as_u64().unwrap() * 4 < full_tokens);
+    }
+
     #[test]
     fn analyze_writing_with_dialect() {
         let server = ProjectServer::new();
@@ -664,6 +1446,8 @@ mod tests {
             max_grade: None,
             passive_max: None,
             dialect: Some("en-us".to_string()),
+            known_acronyms: None,
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -688,51 +1472,392 @@ mod tests {
             max_grade: None,
             passive_max: None,
             dialect: Some("fr-fr".to_string()),
+            known_acronyms: None,
+            verbosity: Verbosity::default(),
         });
 
         let result = server.analyze_writing(params);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn suggest_edits_tool_returns_applicable_edits() {
+        let server = ProjectServer::new();
+        let text = "We made a decision in order to ship.";
+        let params = Parameters(SuggestEditsParams {
+            text: text.to_string(),
+            strip_markdown: false,
+            dialect: None,
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server
+            .suggest_edits(params)
+            .expect("suggest_edits should succeed");
+        let json: serde_json::Value =
+            serde_json::from_str(extract_text(&result).unwrap()).expect("valid JSON");
+        assert_eq!(json["total_edits"], 2);
+
+        let mut rewritten = text.to_string();
+        for edit in json["edits"].as_array().unwrap().iter().rev() {
+            let start = edit["start"].as_u64().unwrap() as usize;
+            let end = edit["end"].as_u64().unwrap() as usize;
+            rewritten.replace_range(start..end, edit["replacement"].as_str().unwrap());
+        }
+        assert_eq!(rewritten, "We decided to ship.");
+    }
+
+    #[test]
+    fn suggest_edits_uses_config_dialect() {
+        let config = bito_lint_core::Config {
+            dialect: Some(Dialect::EnGb),
+            ..Default::default()
+        };
+        let server = ProjectServer::new().with_config(config);
+        let params = Parameters(SuggestEditsParams {
+            text: "The color is nice.".to_string(),
+            strip_markdown: false,
+            dialect: None,
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server.suggest_edits(params).expect("should succeed");
+        let json: serde_json::Value =
+            serde_json::from_str(extract_text(&result).unwrap()).expect("valid JSON");
+        assert_eq!(json["edits"][0]["replacement"], "colour");
+        assert_eq!(json["edits"][0]["check"], "consistency");
+    }
+
+    #[test]
+    fn list_checks_covers_all_checks() {
+        let server = ProjectServer::new();
+        let result = server
+            .list_checks(Parameters(VerbosityParams {
+                verbosity: Verbosity::default(),
+            }))
+            .expect("list_checks should succeed");
+        let json: serde_json::Value =
+            serde_json::from_str(extract_text(&result).unwrap()).expect("valid JSON");
+        assert_eq!(json["total"], analysis::ALL_CHECKS.len());
+        let sticky = &json["checks"][2];
+        assert_eq!(sticky["name"], "sticky");
+        assert_eq!(sticky["thresholds"][0]["default"], 45.0);
+    }
+
+    #[test]
+    fn explain_rules_reports_winning_rule() {
+        let config: bito_lint_core::Config = serde_json::from_value(serde_json::json!({
+            "rules": [
+                { "paths": ["**/*.md"], "checks": { "tokens": { "budget": 100 } } },
+                { "paths": ["docs/*.md"], "checks": { "tokens": { "budget": 500 } } },
+            ]
+        }))
+        .unwrap();
+        let server = ProjectServer::new().with_config(config);
+        let params = Parameters(ExplainRulesParams {
+            file_path: "docs/guide.md".to_string(),
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server.explain_rules(params).expect("should succeed");
+        let json: serde_json::Value =
+            serde_json::from_str(extract_text(&result).unwrap()).expect("valid JSON");
+        assert_eq!(json["matched"].as_array().unwrap().len(), 2);
+        assert_eq!(json["winners"]["tokens"]["rule"], 1);
+        assert_eq!(json["winners"]["tokens"]["pattern"], "docs/*.md");
+        assert_eq!(json["resolved"]["tokens"]["budget"], 500);
+    }
+
+    #[test]
+    fn get_effective_config_includes_sources() {
+        let config = bito_lint_core::Config {
+            dialect: Some(Dialect::EnGb),
+            ..Default::default()
+        };
+        let sources = ConfigSources {
+            explicit_files: vec![camino::Utf8PathBuf::from("/project/.bito-lint.toml")],
+            ..Default::default()
+        };
+        let server = ProjectServer::new()
+            .with_config(config)
+            .with_config_sources(sources);
+
+        let result = server
+            .get_effective_config(Parameters(VerbosityParams {
+                verbosity: Verbosity::default(),
+            }))
+            .expect("should succeed");
+        let json: serde_json::Value =
+            serde_json::from_str(extract_text(&result).unwrap()).expect("valid JSON");
+        assert_eq!(json["config"]["dialect"], "en-gb");
+        assert_eq!(
+            json["sources"]["explicit_files"][0],
+            "/project/.bito-lint.toml"
+        );
+        let content = structured(&server, "get_effective_config", &result);
+        assert_eq!(content["config"]["dialect"], "en-gb");
+    }
+
+    /// `result`'s structured content, validated against `tool`'s output
+    /// schema if it declares one.
+    fn structured(
+        server: &ProjectServer,
+        tool: &str,
+        result: &CallToolResult,
+    ) -> serde_json::Value {
+        let content = result
+            .structured_content
+            .clone()
+            .unwrap_or_else(|| panic!("{tool} should return structured content"));
+        let schema = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .find(|t| t.name == tool)
+            .and_then(|t| t.output_schema);
+        if let Some(schema) = schema {
+            let validator =
+                jsonschema::validator_for(&serde_json::Value::Object((*schema).clone()))
+                    .expect("valid output schema");
+            if let Err(e) = validator.validate(&content) {
+                panic!("{tool} structured content does not match its schema: {e}");
+            }
+        }
+        content
+    }
+
+    #[test]
+    fn report_tools_return_structured_reports() {
+        let server = ProjectServer::new();
+        let text = "In order to win, we made a decision. The plan was reviewed by the team.";
+
+        let result = server
+            .count_tokens(Parameters(CountTokensParams {
+                text: text.to_string(),
+                budget: Some(5),
+                tokenizer: None,
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: tokens::TokenReport =
+            serde_json::from_value(structured(&server, "count_tokens", &result)).unwrap();
+        assert!(report.over_budget);
+
+        let result = server
+            .check_readability(Parameters(CheckReadabilityParams {
+                text: text.to_string(),
+                max_grade: None,
+                strip_markdown: false,
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        structured(&server, "check_readability", &result);
+
+        let result = server
+            .check_grammar(Parameters(CheckGrammarParams {
+                text: text.to_string(),
+                strip_markdown: false,
+                passive_max: None,
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: grammar::GrammarReport =
+            serde_json::from_value(structured(&server, "check_grammar", &result)).unwrap();
+        assert!(report.passive_count > 0);
+
+        let result = server
+            .check_completeness(Parameters(CheckCompletenessParams {
+                text: "# Title\n\n## Context\n\nSome context.\n".to_string(),
+                template: "adr".to_string(),
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: completeness::CompletenessReport =
+            serde_json::from_value(structured(&server, "check_completeness", &result)).unwrap();
+        assert!(!report.pass);
+
+        let result = server
+            .suggest_edits(Parameters(SuggestEditsParams {
+                text: text.to_string(),
+                strip_markdown: false,
+                dialect: None,
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: edits::EditsReport =
+            serde_json::from_value(structured(&server, "suggest_edits", &result)).unwrap();
+        assert_eq!(report.total_edits, 2);
+
+        let result = server
+            .list_checks(Parameters(VerbosityParams {
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let checks = structured(&server, "list_checks", &result);
+        assert_eq!(checks["total"], analysis::ALL_CHECKS.len());
+    }
+
+    #[test]
+    fn structured_content_follows_verbosity() {
+        let server = ProjectServer::new();
+        let run = |verbosity| {
+            let params = Parameters(AnalyzeWritingParams {
+                text: "We basically made a decision. It was reviewed by the team.".to_string(),
+                strip_markdown: false,
+                checks: None,
+                max_grade: Some(4.0),
+                passive_max: None,
+                dialect: None,
+                known_acronyms: None,
+                verbosity,
+            });
+            server.analyze_writing(params).unwrap()
+        };
+
+        for verbosity in [Verbosity::Full, Verbosity::Summary] {
+            let result = run(verbosity);
+            let mut text: serde_json::Value =
+                serde_json::from_str(extract_text(&result).unwrap()).unwrap();
+            text.as_object_mut().unwrap().remove("response_tokens");
+            assert_eq!(structured(&server, "analyze_writing", &result), text);
+        }
+        let summary = structured(&server, "analyze_writing", &run(Verbosity::Summary));
+        assert!(summary.get("readability").is_none());
+        assert!(summary["findings"].is_array());
+
+        let dir = tempfile::tempdir().unwrap();
+        let server = lint_paths_server(&dir);
+        let result = server
+            .lint_paths(Parameters(LintPathsParams {
+                paths: vec!["docs/*.md".to_string()],
+                verbosity: Verbosity::Summary,
+            }))
+            .unwrap();
+        let summary = structured(&server, "lint_paths", &result);
+        assert_eq!(summary["checked"], 2);
+        assert!(summary.get("files").is_none());
+    }
+
+    #[test]
+    fn lint_tools_return_structured_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = lint_paths_server(&dir);
+
+        let result = server
+            .lint_paths(Parameters(LintPathsParams {
+                paths: vec!["docs/*.md".to_string(), "notes.txt".to_string()],
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: LintPathsReport =
+            serde_json::from_value(structured(&server, "lint_paths", &result)).unwrap();
+        assert_eq!((report.checked, report.failed), (2, 1));
+        assert!(report.files[0].reports.contains_key("completeness"));
+        assert_eq!(report.files[2].matched, Some(false));
+
+        let result = server
+            .lint_file(Parameters(LintFileParams {
+                file_path: "docs/guide.md".to_string(),
+                text: "The cat sat on the mat.".to_string(),
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: LintReport =
+            serde_json::from_value(structured(&server, "lint_file", &result)).unwrap();
+        assert!(report.pass && report.readability.is_some());
+
+        let result = server
+            .lint_file(Parameters(LintFileParams {
+                file_path: "src/main.rs".to_string(),
+                text: "fn main() {}".to_string(),
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let report: LintReport =
+            serde_json::from_value(structured(&server, "lint_file", &result)).unwrap();
+        assert!(report.pass && report.readability.is_none());
+        assert_eq!(report.matched, Some(false));
+        let text: serde_json::Value = serde_json::from_str(extract_text(&result).unwrap()).unwrap();
+        assert_eq!(text["matched"], false);
+
+        let result = server
+            .explain_rules(Parameters(ExplainRulesParams {
+                file_path: "docs/adr.md".to_string(),
+                verbosity: Verbosity::default(),
+            }))
+            .unwrap();
+        let explanation = structured(&server, "explain_rules", &result);
+        assert_eq!(explanation["file"], "docs/adr.md");
+        assert_eq!(explanation["winners"]["completeness"]["rule"], 1);
+    }
+
+    #[test]
+    fn every_tool_declares_an_output_schema() {
+        let server = ProjectServer::new();
+        let without: Vec<String> = server
+            .tool_router
+            .list_all()
+            .into_iter()
+            .filter(|t| t.output_schema.is_none())
+            .map(|t| t.name.to_string())
+            .collect();
+        assert!(without.is_empty(), "{without:?}");
+    }
+
     /// Measure the token cost of MCP tool schemas.
     ///
-    /// This test ensures the full tool listing (names, descriptions, input
-    /// schemas) stays within a reasonable token budget when loaded into an
-    /// agent's context.
+    /// This test ensures the full tool listing stays within a reasonable
+    /// token budget when loaded into an agent's context. Names, descriptions,
+    /// and input schemas, which every client shows the model, have their own
+    /// budget; output schemas, which clients use to validate results, have
+    /// a separate one. Both count toward the total.
     #[test]
     fn mcp_tool_schemas_fit_token_budget() {
+        const INPUT_BUDGET: usize = 4500;
+        const OUTPUT_BUDGET: usize = 27_000;
+
         let server = ProjectServer::new();
         let tools = server.tool_router.list_all();
+        let count = |json: &str| {
+            bito_lint_core::tokens::count_tokens(json, None, Backend::default())
+                .expect("token counting should work")
+                .count
+        };
 
         // Serialize tool list to JSON (same format agents receive)
         let json = serde_json::to_string_pretty(&tools).expect("serialization should work");
-
-        // Count tokens using our own tokenizer
-        let report = bito_lint_core::tokens::count_tokens(&json, None, Backend::default())
-            .expect("token counting should work");
+        let total = count(&json);
+        let inputs: Vec<_> = tools
+            .iter()
+            .cloned()
+            .map(|mut tool| {
+                tool.output_schema = None;
+                tool
+            })
+            .collect();
+        let input = count(&serde_json::to_string_pretty(&inputs).expect("serialize tools"));
+        let output = total.saturating_sub(input);
 
         // Print breakdown for manual inspection
-        println!("MCP tool schema token count: {}", report.count);
+        println!("MCP tool schema token count: {total} ({input} input, {output} output)");
         println!("Tool count: {}", tools.len());
-        println!(
-            "Avg tokens per tool: {:.0}",
-            report.count as f64 / tools.len() as f64
-        );
         for tool in &tools {
             let tool_json = serde_json::to_string_pretty(&tool).expect("serialize tool");
-            let tool_report =
-                bito_lint_core::tokens::count_tokens(&tool_json, None, Backend::default())
-                    .expect("count");
-            println!("  {} — {} tokens", tool.name, tool_report.count);
+            println!("  {} — {} tokens", tool.name, count(&tool_json));
         }
 
-        // Budget: 4500 tokens for the full listing
         assert!(
-            report.count <= 4500,
-            "MCP tool schemas use {} tokens, exceeding the 4500-token budget. \
-             Consider trimming descriptions or consolidating tools.",
-            report.count
+            input <= INPUT_BUDGET,
+            "MCP tool names, descriptions, and input schemas use {input} tokens, exceeding \
+             the {INPUT_BUDGET}-token budget. Consider trimming descriptions or consolidating \
+             tools."
         );
+        assert!(
+            output <= OUTPUT_BUDGET,
+            "MCP output schemas use {output} tokens, exceeding the {OUTPUT_BUDGET}-token \
+             budget. Consider trimming report types."
+        );
+        assert!(total <= INPUT_BUDGET + OUTPUT_BUDGET);
     }
 
     #[test]
@@ -741,6 +1866,7 @@ mod tests {
         let params = Parameters(CheckCompletenessParams {
             text: "## Where things stand\n\nDone.".to_string(),
             template: "handoff".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -753,12 +1879,41 @@ mod tests {
         assert!(!json["pass"].as_bool().unwrap());
     }
 
+    #[test]
+    fn check_completeness_tool_uses_config_templates() {
+        use bito_lint_core::config::{TemplateConfig, TemplateSectionEntry};
+        use std::collections::HashMap;
+        let sections = ["Goal", "Plan"]
+            .map(|s| TemplateSectionEntry::Name(s.to_string()))
+            .to_vec();
+        let server = ProjectServer::new().with_config(bito_lint_core::Config {
+            templates: Some(HashMap::from([(
+                "mytemplate".to_string(),
+                TemplateConfig::Sections(sections),
+            )])),
+            ..Default::default()
+        });
+        let params = Parameters(CheckCompletenessParams {
+            text: "## Goal\n\nShip it.\n\n## Plan\n\nOne step.".to_string(),
+            template: "mytemplate".to_string(),
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server
+            .check_completeness(params)
+            .expect("config template should resolve");
+        let text = extract_text(&result).expect("should have text content");
+        let json: serde_json::Value = serde_json::from_str(text).expect("valid JSON");
+        assert!(json["pass"].as_bool().unwrap());
+    }
+
     #[test]
     fn lint_file_no_rules_returns_no_match() {
         let server = ProjectServer::new();
         let params = Parameters(LintFileParams {
             file_path: "docs/guide.md".to_string(),
             text: "The cat sat on the mat.".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server.lint_file(params).expect("lint_file should succeed");
@@ -767,6 +1922,7 @@ mod tests {
         let text = extract_text(&result).expect("should have text content");
         let json: serde_json::Value = serde_json::from_str(text).expect("valid JSON");
         assert_eq!(json["matched"], false);
+        assert_eq!(structured(&server, "lint_file", &result)["matched"], false);
     }
 
     #[test]
@@ -790,6 +1946,7 @@ mod tests {
         let params = Parameters(LintFileParams {
             file_path: "docs/guide.md".to_string(),
             text: "The cat sat on the mat. The dog ran fast.".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server.lint_file(params).expect("lint_file should succeed");
@@ -801,6 +1958,119 @@ mod tests {
         assert!(json["readability"].is_object());
     }
 
+    fn lint_paths_server(dir: &tempfile::TempDir) -> ProjectServer {
+        use bito_lint_core::config::{
+            CompletenessRuleConfig, ReadabilityRuleConfig, Rule, RuleChecks,
+        };
+
+        let root = camino::Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/good.md"), "The cat sat on the mat.\n").unwrap();
+        std::fs::write(root.join("docs/adr.md"), "# Decision\n\nWe chose it.\n").unwrap();
+        std::fs::write(root.join("notes.txt"), "Loose notes.\n").unwrap();
+
+        let config = bito_lint_core::Config {
+            rules: Some(vec![
+                Rule {
+                    paths: vec!["docs/**/*.md".to_string()],
+                    checks: RuleChecks {
+                        readability: Some(ReadabilityRuleConfig {
+                            max_grade: Some(20.0),
+                        }),
+                        ..Default::default()
+                    },
+                },
+                Rule {
+                    paths: vec!["docs/adr.md".to_string()],
+                    checks: RuleChecks {
+                        completeness: Some(CompletenessRuleConfig {
+                            template: "adr".to_string(),
+                        }),
+                        ..Default::default()
+                    },
+                },
+            ]),
+            ..Default::default()
+        };
+        ProjectServer::new()
+            .with_config(config)
+            .with_config_dir(root)
+    }
+
+    #[test]
+    fn lint_paths_reports_compact_per_file_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = lint_paths_server(&dir);
+        let params = Parameters(LintPathsParams {
+            paths: vec![
+                "docs/*.md".to_string(),
+                "notes.txt".to_string(),
+                "missing/**".to_string(),
+            ],
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server
+            .lint_paths(params)
+            .expect("lint_paths should succeed");
+        let text = extract_text(&result).expect("should have text content");
+        let json: serde_json::Value = serde_json::from_str(text).expect("valid JSON");
+
+        assert_eq!(json["checked"], 2);
+        assert_eq!(json["failed"], 1);
+        assert_eq!(json["pass"], false);
+        assert_eq!(json["unmatched"], serde_json::json!(["missing/**"]));
+
+        let files = json["files"].as_array().unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[0]["file"], "docs/adr.md");
+        assert_eq!(files[0]["failed"], serde_json::json!(["completeness"]));
+        assert!(files[0]["completeness"].is_object());
+        assert!(files[0].get("readability").is_none());
+        assert_eq!(
+            files[1],
+            serde_json::json!({ "file": "docs/good.md", "pass": true })
+        );
+        assert_eq!(
+            files[2],
+            serde_json::json!({ "file": "notes.txt", "matched": false })
+        );
+    }
+
+    #[test]
+    fn lint_paths_rejects_paths_outside_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = lint_paths_server(&dir);
+        let params = Parameters(LintPathsParams {
+            paths: vec!["../outside.md".to_string()],
+            verbosity: Verbosity::default(),
+        });
+        assert!(server.lint_paths(params).is_err());
+    }
+
+    #[test]
+    fn lint_paths_reports_oversized_file_as_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let server = lint_paths_server(&dir).with_max_input_bytes(Some(10));
+        let params = Parameters(LintPathsParams {
+            paths: vec!["docs/good.md".to_string()],
+            verbosity: Verbosity::default(),
+        });
+
+        let result = server
+            .lint_paths(params)
+            .expect("lint_paths should succeed");
+        let text = extract_text(&result).expect("should have text content");
+        let json: serde_json::Value = serde_json::from_str(text).expect("valid JSON");
+        assert_eq!(json["pass"], false);
+        assert!(
+            json["files"][0]["error"]
+                .as_str()
+                .unwrap()
+                .contains("too large")
+        );
+    }
+
     #[test]
     fn get_custom_returns_inline_content() {
         use std::collections::HashMap;
@@ -819,6 +2089,7 @@ mod tests {
         let server = ProjectServer::new().with_config(config);
         let params = Parameters(GetCustomParams {
             name: "voice".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server
@@ -829,6 +2100,7 @@ mod tests {
         let json: serde_json::Value = serde_json::from_str(text).expect("valid JSON");
         assert_eq!(json["name"], "voice");
         assert!(json["content"].as_str().unwrap().contains("concise"));
+        assert_eq!(structured(&server, "get_custom", &result)["name"], "voice");
     }
 
     #[test]
@@ -836,9 +2108,181 @@ mod tests {
         let server = ProjectServer::new();
         let params = Parameters(GetCustomParams {
             name: "nonexistent".to_string(),
+            verbosity: Verbosity::default(),
         });
 
         let result = server.get_custom(params);
         assert!(result.is_err());
     }
+
+    fn custom_server() -> ProjectServer {
+        use std::collections::HashMap;
+        let mut custom = HashMap::new();
+        for (name, text) in [
+            ("voice", "Be direct."),
+            ("glossary", "ADR: decision record."),
+        ] {
+            custom.insert(
+                name.to_string(),
+                bito_lint_core::config::CustomEntry {
+                    instructions: Some(text.to_string()),
+                    file: None,
+                },
+            );
+        }
+        ProjectServer::new().with_config(bito_lint_core::Config {
+            custom: Some(custom),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn server_has_resources_and_prompts_capabilities() {
+        let info = ServerHandler::get_info(&ProjectServer::new());
+        let resources = info.capabilities.resources.expect("resources capability");
+        assert_eq!(resources.list_changed, Some(true));
+        let prompts = info.capabilities.prompts.expect("prompts capability");
+        assert_eq!(prompts.list_changed, Some(true));
+    }
+
+    #[test]
+    fn custom_entries_listed_as_resources() {
+        let resources = custom_server().custom_resources();
+        let uris: Vec<&str> = resources.iter().map(|r| r.uri.as_str()).collect();
+        assert_eq!(uris, vec!["bito://custom/glossary", "bito://custom/voice"]);
+        assert!(ProjectServer::new().custom_resources().is_empty());
+    }
+
+    #[test]
+    fn custom_resource_read_by_uri() {
+        let server = custom_server();
+        let result = server
+            .read_custom_resource("bito://custom/voice")
+            .expect("resource should resolve");
+        match &result.contents[0] {
+            ResourceContents::TextResourceContents { uri, text, .. } => {
+                assert_eq!(uri, "bito://custom/voice");
+                assert_eq!(text, "Be direct.");
+            }
+            ResourceContents::BlobResourceContents { .. } => panic!("expected text"),
+        }
+        assert!(
+            server
+                .read_custom_resource("bito://custom/missing")
+                .is_err()
+        );
+        assert!(server.read_custom_resource("file:///etc/passwd").is_err());
+    }
+
+    #[test]
+    fn templates_listed_as_prompts() {
+        let prompts = ProjectServer::new().template_prompts();
+        let names: Vec<&str> = prompts.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["draft-adr", "draft-design-doc", "draft-handoff"]
+        );
+    }
+
+    #[test]
+    fn template_prompt_lists_required_sections() {
+        let server = ProjectServer::new();
+        let mut args = JsonObject::new();
+        args.insert("topic".to_string(), serde_json::json!("Switch to Postgres"));
+
+        let result = server
+            .template_prompt("draft-adr", Some(&args))
+            .expect("prompt should build");
+        let text = serde_json::to_string(&result.messages[0]).unwrap();
+        assert!(text.contains("## Decision Drivers"));
+        assert!(text.contains("Switch to Postgres"));
+
+        assert!(server.template_prompt("draft-nope", None).is_err());
+        assert!(server.template_prompt("adr", None).is_err());
+    }
+
+    fn loaded(dir: &tempfile::TempDir, toml: &str) -> (ProjectServer, ConfigSources) {
+        let root = camino::Utf8PathBuf::try_from(dir.path().to_path_buf()).unwrap();
+        std::fs::write(root.join("bito-lint.toml"), toml).unwrap();
+        let (config, sources) = bito_lint_core::ConfigLoader::new()
+            .with_user_config(false)
+            .without_boundary_marker()
+            .with_project_search(&root)
+            .load()
+            .unwrap();
+        let server = ProjectServer::new()
+            .with_config(config)
+            .with_config_dir(root);
+        (server, sources)
+    }
+
+    #[test]
+    fn sessions_keep_their_own_log_level() {
+        let server = ProjectServer::new();
+        let session = server.for_session();
+        *session.log_level.lock().unwrap() = LoggingLevel::Error;
+        assert_eq!(*server.log_level.lock().unwrap(), LoggingLevel::Debug);
+        assert!(Arc::ptr_eq(&server.peers, &session.peers));
+        assert!(Arc::ptr_eq(&server.config, &session.config));
+    }
+
+    #[tokio::test]
+    async fn reload_applies_valid_config_to_all_clones() {
+        let dir = tempfile::tempdir().unwrap();
+        let (server, sources) = loaded(&dir, "[custom.voice]\ninstructions = \"Old.\"\n");
+        let session = server.clone();
+
+        std::fs::write(
+            dir.path().join("bito-lint.toml"),
+            "[custom.voice]\ninstructions = \"New.\"\n\n[custom.tone]\ninstructions = \"Warm.\"\n",
+        )
+        .unwrap();
+        assert!(server.reload_config(&sources).await);
+        assert_eq!(session.custom_resources().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reload_keeps_previous_config_when_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let (server, sources) = loaded(&dir, "[custom.voice]\ninstructions = \"Old.\"\n");
+
+        std::fs::write(
+            dir.path().join("bito-lint.toml"),
+            "[[rules]]\npaths = [\"*.md\"]\n[rules.checks.completeness]\ntemplate = \"nope\"\n",
+        )
+        .unwrap();
+        assert!(!server.reload_config(&sources).await);
+
+        std::fs::write(dir.path().join("bito-lint.toml"), "max_grade = \"high\"\n").unwrap();
+        assert!(!server.reload_config(&sources).await);
+
+        let uris: Vec<String> = server
+            .custom_resources()
+            .into_iter()
+            .map(|r| r.uri.clone())
+            .collect();
+        assert_eq!(uris, vec!["bito://custom/voice"]);
+    }
+
+    #[tokio::test]
+    async fn watcher_reloads_on_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let (server, sources) = loaded(&dir, "");
+        let watcher = server.watch_config(sources);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        std::fs::write(
+            dir.path().join("bito-lint.toml"),
+            "# edited\n[custom.voice]\ninstructions = \"Be direct.\"\n",
+        )
+        .unwrap();
+        for _ in 0..50 {
+            if !server.custom_resources().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        watcher.abort();
+        assert_eq!(server.custom_resources().len(), 1);
+    }
 }
@@ -434,6 +434,43 @@ rules:
     assert!(json["readability"].is_object());
 }
 
+#[test]
+fn lint_reports_unused_directives_when_asked() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let config_path = dir.path().join(".bito-lint.yaml");
+    std::fs::write(
+        &config_path,
+        r#"
+rules:
+  - paths: ["**/*.md"]
+    checks:
+      grammar: {}
+"#,
+    )
+    .unwrap();
+
+    let file_path = dir.path().join("test.md");
+    std::fs::write(
+        &file_path,
+        "<!-- bito-lint disable-next-line grammar -->\nThe team wrote the report.\n",
+    )
+    .unwrap();
+
+    let lint = |extra: &[&str]| {
+        let mut args = vec!["--config", config_path.to_str().unwrap(), "lint"];
+        args.extend_from_slice(extra);
+        args.push(file_path.to_str().unwrap());
+        cmd().args(args).assert()
+    };
+    lint(&[]).success();
+    lint(&["--report-unused-directives"])
+        .failure()
+        .stdout(predicate::str::contains(
+            "line 1: `disable-next-line grammar` suppressed nothing",
+        ));
+}
+
 #[test]
 fn lint_with_tokens_budget() {
     let dir = tempfile::tempdir().unwrap();
@@ -466,6 +503,171 @@ rules:
         .stdout(predicate::str::contains("tokens"));
 }
 
+#[test]
+fn lint_frontmatter_schema_reports_invalid_value() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join(".bito-lint.yaml");
+    std::fs::write(
+        &config_path,
+        r#"frontmatter_schemas:
+  adr:
+    fields:
+      status:
+        enum: [proposed, accepted, superseded]
+rules:
+  - paths: ["*.md"]
+    checks:
+      frontmatter:
+        schema: adr
+"#,
+    )
+    .unwrap();
+
+    let doc = dir.path().join("0001-use-postgres.md");
+    std::fs::write(&doc, "---\nstatus: done\n---\n\n# Use Postgres\n").unwrap();
+
+    cmd()
+        .args([
+            "-C",
+            dir.path().to_str().unwrap(),
+            "--config",
+            config_path.to_str().unwrap(),
+            "lint",
+            "0001-use-postgres.md",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("frontmatter:"))
+        .stdout(predicate::str::contains("FAIL"));
+}
+
+// =============================================================================
+// Completeness Command
+// =============================================================================
+
+#[test]
+fn completeness_uses_skeleton_template_from_config() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let templates_dir = dir.path().join("templates");
+    std::fs::create_dir_all(&templates_dir).unwrap();
+    std::fs::write(
+        templates_dir.join("rfc.md"),
+        "---\nstatus: draft\n---\n\n# RFC\n\n## Summary\n\n## Drawbacks (optional)\n",
+    )
+    .unwrap();
+
+    let config_path = dir.path().join(".bito-lint.yaml");
+    std::fs::write(
+        &config_path,
+        "templates:\n  rfc:\n    file: templates/rfc.md\n",
+    )
+    .unwrap();
+
+    let doc = dir.path().join("doc.md");
+    std::fs::write(&doc, "# RFC 1\n\n## Summary\n\nShip it.\n").unwrap();
+
+    cmd()
+        .args([
+            "--config",
+            config_path.to_str().unwrap(),
+            "completeness",
+            "--template",
+            "rfc",
+            doc.to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("frontmatter key 'status'"));
+}
+
+// =============================================================================
+// New Command
+// =============================================================================
+
+#[test]
+fn new_adr_numbers_after_existing_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let decisions = dir.path().join("docs/decisions");
+    std::fs::create_dir_all(&decisions).unwrap();
+    std::fs::write(decisions.join("0001-first.md"), "").unwrap();
+    std::fs::write(decisions.join("0002-second.md"), "").unwrap();
+
+    cmd()
+        .args([
+            "-C",
+            dir.path().to_str().unwrap(),
+            "new",
+            "adr",
+            "docs/decisions/use-postgres.md",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0003-use-postgres.md"));
+
+    let created = std::fs::read_to_string(decisions.join("0003-use-postgres.md")).unwrap();
+    assert!(created.contains("# ADR-0003: Use postgres"));
+    assert!(created.contains("status: proposed"));
+
+    // Refuses to overwrite without --force.
+    cmd()
+        .args([
+            "-C",
+            dir.path().to_str().unwrap(),
+            "new",
+            "adr",
+            "docs/decisions/0003-use-postgres.md",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+}
+
+#[test]
+fn adr_reports_collection_problems_from_config_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let decisions = dir.path().join("docs/decisions");
+    std::fs::create_dir_all(&decisions).unwrap();
+    std::fs::write(
+        dir.path().join(".bito-lint.yaml"),
+        "adr:\n  dir: docs/decisions\n",
+    )
+    .unwrap();
+    std::fs::write(decisions.join("0001-first.md"), "Status: accepted\n").unwrap();
+    std::fs::write(
+        decisions.join("0003-third.md"),
+        "Status: accepted\n\nSupersedes ADR-0001\n",
+    )
+    .unwrap();
+    std::fs::write(decisions.join("README.md"), "# Decisions\n").unwrap();
+
+    cmd()
+        .args(["-C", dir.path().to_str().unwrap(), "adr"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("PASS:").not())
+        .stdout(predicate::str::contains("ADR 0002 is missing"))
+        .stdout(predicate::str::contains("no 'Superseded by' link back"))
+        .stderr(predicate::str::contains("2 of 2 ADRs"));
+
+    std::fs::write(
+        decisions.join("0001-first.md"),
+        "Status: superseded\nSuperseded by ADR-0003\n",
+    )
+    .unwrap();
+    std::fs::rename(
+        decisions.join("0003-third.md"),
+        decisions.join("0002-third.md"),
+    )
+    .unwrap();
+
+    cmd()
+        .args(["-C", dir.path().to_str().unwrap(), "adr"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("ADR 0003, which does not exist"));
+}
+
 // =============================================================================
 // Chdir Flag
 // =============================================================================
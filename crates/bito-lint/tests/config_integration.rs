@@ -292,6 +292,21 @@ fn unknown_config_field_is_ignored() {
     assert_eq!(json["config"]["log_level"], "info");
 }
 
+#[test]
+fn config_failing_server_validation_still_loads() {
+    // Only `serve` validates; other commands keep accepting these configs.
+    let tmp = TempDir::new().unwrap();
+    fs::write(
+        tmp.path().join(".bito-lint.toml"),
+        "log_level = \"warn\"\n[[rules]]\npaths = [\"*.md\"]\n\
+         [rules.checks.completeness]\ntemplate = \"nope\"\n",
+    )
+    .unwrap();
+
+    let json = info_json(tmp.path());
+    assert_eq!(json["config"]["log_level"], "warn");
+}
+
 // =============================================================================
 // Boundary Marker Tests
 // =============================================================================